@@ -0,0 +1,462 @@
+//! Generates the request structs of `src/bot.rs` and the union types of the library from `spec/bot_api.json`, a
+//! machine-readable description of the methods and types of the Bot API in the terms of its documentation.
+
+use serde::Deserialize;
+
+/// Everything after this line in `src/bot.rs` is generated
+pub const MARKER: &str =
+    "// The request structs below are generated from spec/bot_api.json by `cargo run -p rutel_codegen`, edit the spec instead.";
+
+/// Everything after this line in a module with types in the spec is generated
+pub const TYPES_MARKER: &str =
+    "// The types below are generated from spec/bot_api.json by `cargo run -p rutel_codegen`, edit the spec instead.";
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct Spec {
+    /// Version of the Bot API the spec describes
+    pub version: String,
+    pub methods: Vec<Method>,
+    #[serde(default)]
+    pub types: Vec<Type>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct Method {
+    /// Name of the method, e.g. `sendMessage`
+    pub name: String,
+    /// Type of the result, e.g. `Message`, `True`, `Message or True`, `Array of Update`
+    pub returns: String,
+    pub description: String,
+    #[serde(default)]
+    pub params: Vec<Param>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct Param {
+    pub name: String,
+    /// Type of the parameter, e.g. `Integer or String`, `Array of MessageEntity`
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub required: bool,
+    pub description: String,
+    /// Length of a text in UTF-16 code units after entities parsing, inclusive
+    pub length: Option<[usize; 2]>,
+    /// Number of items of an array, inclusive
+    pub items: Option<[usize; 2]>,
+    /// Range of an integer, inclusive
+    pub range: Option<[i64; 2]>,
+    /// Items of an array must be strictly increasing
+    #[serde(default)]
+    pub increasing: bool,
+}
+
+/// An object or a union of objects.
+#[derive(Clone, Debug, Deserialize)]
+pub struct Type {
+    /// Name of the type, e.g. `ChatMember`
+    pub name: String,
+    /// Module of the library the type is generated in, e.g. `types` for `src/types.rs`
+    pub module: String,
+    pub description: String,
+    /// Fields of an object
+    #[serde(default)]
+    pub fields: Vec<Param>,
+    /// Field of a union telling its objects apart, None if they are told apart by their other fields
+    #[serde(default)]
+    pub tag: Option<String>,
+    /// Objects of a union, empty for an object
+    #[serde(default)]
+    pub variants: Vec<Variant>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct Variant {
+    /// Name of the object
+    #[serde(rename = "type")]
+    pub kind: String,
+    /// Value of the tag field of the object
+    pub value: Option<String>,
+}
+
+/// Rust types of parameters and fields with a given name and Bot API type, where the library has a more specific type
+const PARAM_TYPES: &[(&str, &str, &str)] = &[
+    ("chat_id", "Integer", "ChatId"),
+    ("sender_chat_id", "Integer", "ChatId"),
+    ("user_id", "Integer", "UserId"),
+    ("message_id", "Integer", "MessageId"),
+    ("message_ids", "Array of Integer", "Vec<MessageId>"),
+    ("file_id", "String", "FileId"),
+    ("parse_mode", "String", "ParseMode"),
+    ("question_parse_mode", "String", "ParseMode"),
+    ("explanation_parse_mode", "String", "ParseMode"),
+    ("allowed_updates", "Array of String", "Vec<AllowedUpdate>"),
+    ("action", "String", "ChatAction"),
+    ("sticker_type", "String", "StickerType"),
+    ("format", "String", "StickerFormat"),
+    ("sticker_format", "String", "StickerFormat"),
+    ("giveaway_message_id", "Integer", "MessageId"),
+    ("audio_file_id", "String", "FileId"),
+    ("document_file_id", "String", "FileId"),
+    ("gif_file_id", "String", "FileId"),
+    ("mpeg4_file_id", "String", "FileId"),
+    ("photo_file_id", "String", "FileId"),
+    ("sticker_file_id", "String", "FileId"),
+    ("video_file_id", "String", "FileId"),
+    ("voice_file_id", "String", "FileId"),
+];
+
+/// Rust types of parameters of a given method, where the library has a more specific type
+const METHOD_PARAM_TYPES: &[(&str, &str, &str)] = &[
+    ("sendDice", "emoji", "DiceEmoji"),
+    ("sendPoll", "type", "PollType"),
+];
+
+/// Rust types of Bot API types that aren't a single type of the library
+const TYPES: &[(&str, &str)] = &[
+    ("True", "Boolean"),
+    ("Integer or String", "ChatID"),
+    ("InputFile or String", "InputFileString"),
+    (
+        "InlineKeyboardMarkup or ReplyKeyboardMarkup or ReplyKeyboardRemove or ForceReply",
+        "ReplyMarkup",
+    ),
+    ("Message or True", "TrueMessage"),
+];
+
+/// Unions of objects the Bot API sends, which keep objects added in later API versions as `Unknown`
+const RECEIVED_UNIONS: &[&str] = &[
+    "BackgroundFill",
+    "BackgroundType",
+    "ChatBoostSource",
+    "ChatMember",
+    "MenuButton",
+    "MessageOrigin",
+    "PaidMedia",
+    "ReactionType",
+    "RevenueWithdrawalState",
+    "TransactionPartner",
+];
+
+/// Paragraphs added to the documentation of a method
+const METHOD_NOTES: &[(&str, &str)] = &[(
+    "getUpdates",
+    "The batch is decoded at once, so one update that can't be decoded fails it and polling with this method gets \
+     stuck on that update. Use [`Bot::get_updates_lenient`] to decode each update on its own, as \
+     `Dispatcher::polling` does.",
+)];
+
+fn rust_type(api_type: &str) -> String {
+    if let Some(item) = api_type.strip_prefix("Array of ") {
+        return format!("Vec<{}>", rust_type(item));
+    }
+    TYPES
+        .iter()
+        .find(|(api, _)| *api == api_type)
+        .map_or(api_type, |(_, rust)| rust)
+        .to_string()
+}
+
+/// Rust type of a parameter of a method, or of a field of an object
+fn param_type(owner: &str, param: &Param) -> String {
+    let by_method = METHOD_PARAM_TYPES
+        .iter()
+        .find(|(name, param_name, _)| *name == owner && *param_name == param.name);
+    let by_name = PARAM_TYPES
+        .iter()
+        .find(|(name, api_type, _)| *name == param.name && *api_type == param.kind);
+    match by_method.or(by_name) {
+        Some((_, _, rust)) => rust.to_string(),
+        None => rust_type(&param.kind),
+    }
+}
+
+fn struct_name(method: &str) -> String {
+    let mut chars = method.chars();
+    chars
+        .next()
+        .map(|first| first.to_ascii_uppercase().to_string() + chars.as_str())
+        .unwrap_or_default()
+}
+
+fn push_doc(out: &mut String, indent: &str, doc: &str) {
+    for line in doc.lines() {
+        match line {
+            "" => out.push_str(&format!("{indent}///\n")),
+            line => out.push_str(&format!("{indent}/// {line}\n")),
+        }
+    }
+}
+
+fn push_param(out: &mut String, owner: &str, param: &Param) {
+    let prefix = if param.required { "" } else { "Optional. " };
+    if !param.description.is_empty() {
+        push_doc(out, "    ", &format!("{prefix}{}", param.description));
+    }
+    let mut attrs = Vec::new();
+    let field = if param.name == "type" {
+        attrs.push("rename = \"type\"");
+        "kind"
+    } else {
+        &param.name
+    };
+    let mut ty = param_type(owner, param);
+    if !param.required {
+        attrs.push("skip_serializing_if = \"Option::is_none\"");
+        ty = format!("Option<{ty}>");
+    }
+    if !attrs.is_empty() {
+        out.push_str(&format!("    #[serde({})]\n", attrs.join(", ")));
+    }
+    out.push_str(&format!("    pub {field}: {ty},\n"));
+}
+
+fn push_method(out: &mut String, method: &Method) {
+    out.push_str(&format!("/// {}\n", method.description));
+    if let Some((_, note)) = METHOD_NOTES.iter().find(|(name, _)| *name == method.name) {
+        out.push_str("///\n");
+        push_doc(out, "", note);
+    }
+    out.push_str("#[derive(Serialize, Debug, Response)]\n");
+    out.push_str(&format!(
+        "#[response = \"{}\"]\n",
+        rust_type(&method.returns)
+    ));
+    let name = struct_name(&method.name);
+    if method.params.is_empty() {
+        out.push_str(&format!("pub struct {name} {{}}\n"));
+        return;
+    }
+    out.push_str(&format!("pub struct {name} {{\n"));
+    for param in &method.params {
+        push_param(out, &method.name, param);
+    }
+    out.push_str("}\n");
+}
+
+fn push_validate(out: &mut String, method: &Method) {
+    out.push_str(&format!(
+        "\nimpl Validate for {} {{\n    const METHOD: &'static str = \"{}\";\n}}\n",
+        struct_name(&method.name),
+        method.name
+    ));
+}
+
+/// The limits of a parameter as `Limit` expressions
+fn limits(param: &Param) -> Vec<String> {
+    let mut limits = Vec::new();
+    if let Some([min, max]) = param.length {
+        limits.push(format!("Limit::Length({min}, {max})"));
+    }
+    if let Some([min, max]) = param.items {
+        limits.push(format!("Limit::Items({min}, {max})"));
+    }
+    if let Some([min, max]) = param.range {
+        limits.push(format!("Limit::Range({min}, {max})"));
+    }
+    if param.increasing {
+        limits.push("Limit::Increasing".to_string());
+    }
+    if param.kind.contains("InlineKeyboardMarkup") {
+        limits.push("Limit::InlineKeyboard".to_string());
+    }
+    if param.kind == "Array of BotCommand" {
+        limits.push("Limit::Commands".to_string());
+    }
+    limits
+}
+
+fn push_limits(out: &mut String, spec: &Spec) {
+    out.push_str(
+        "\n/// Limits of the parameters of the methods, checked before a request is sent\n",
+    );
+    out.push_str("#[rustfmt::skip]\n");
+    out.push_str("pub(crate) const LIMITS: &[(&str, &str, Limit)] = &[\n");
+    for method in &spec.methods {
+        for param in &method.params {
+            for limit in limits(param) {
+                out.push_str(&format!(
+                    "    (\"{}\", \"{}\", {limit}),\n",
+                    method.name, param.name
+                ));
+            }
+        }
+    }
+    out.push_str("];\n");
+}
+
+fn push_union(out: &mut String, union: &Type) {
+    push_doc(out, "", &union.description);
+    let values: Vec<&str> = union
+        .variants
+        .iter()
+        .filter_map(|variant| variant.value.as_deref())
+        .collect();
+    let shared = values
+        .iter()
+        .enumerate()
+        .any(|(i, value)| values[..i].contains(value));
+    if shared {
+        out.push_str("///\n/// Some objects share a tag value, so the type is only serialized.\n");
+        out.push_str("#[derive(Clone, Serialize, Debug)]\n");
+    } else {
+        out.push_str("#[derive(Clone, Serialize, Deserialize, Debug)]\n");
+    }
+    match &union.tag {
+        Some(tag) => out.push_str(&format!("#[serde(tag = \"{tag}\")]\n")),
+        None => out.push_str("#[serde(untagged)]\n"),
+    }
+    out.push_str(&format!("pub enum {} {{\n", union.name));
+    for variant in &union.variants {
+        if let Some(value) = &variant.value {
+            out.push_str(&format!("    #[serde(rename = \"{value}\")]\n"));
+        }
+        out.push_str(&format!("    {0}({0}),\n", variant.kind));
+    }
+    if RECEIVED_UNIONS.contains(&union.name.as_str()) {
+        out.push_str("    /// An object not known to this version of the library, or one that can't be decoded\n");
+        out.push_str("    #[serde(untagged)]\n");
+        out.push_str("    Unknown(Value),\n");
+    }
+    out.push_str("}\n");
+}
+
+/// An object, without the tag field if it is in a tagged union
+fn push_object(out: &mut String, object: &Type, tag: Option<&str>) {
+    push_doc(out, "", &object.description);
+    out.push_str("#[derive(Clone, Serialize, Deserialize, Debug)]\n");
+    let fields: Vec<&Param> = object
+        .fields
+        .iter()
+        .filter(|field| Some(field.name.as_str()) != tag)
+        .collect();
+    if fields.is_empty() {
+        out.push_str(&format!("pub struct {} {{}}\n", object.name));
+        return;
+    }
+    out.push_str(&format!("pub struct {} {{\n", object.name));
+    for field in fields {
+        push_param(out, &object.name, field);
+    }
+    out.push_str("}\n");
+}
+
+/// Modules with types in the spec, in the order of their first type
+pub fn modules(spec: &Spec) -> Vec<&str> {
+    let mut modules: Vec<&str> = Vec::new();
+    for ty in &spec.types {
+        if !modules.contains(&ty.module.as_str()) {
+            modules.push(&ty.module);
+        }
+    }
+    modules
+}
+
+/// The generated part of a module, starting with `TYPES_MARKER`
+pub fn generate_types(spec: &Spec, module: &str) -> String {
+    let mut out = format!("{TYPES_MARKER}\n");
+    for ty in spec.types.iter().filter(|ty| ty.module == module) {
+        out.push('\n');
+        if !ty.variants.is_empty() {
+            push_union(&mut out, ty);
+            continue;
+        }
+        let tag = spec
+            .types
+            .iter()
+            .find(|union| union.variants.iter().any(|variant| variant.kind == ty.name))
+            .and_then(|union| union.tag.as_deref());
+        push_object(&mut out, ty, tag);
+    }
+    out
+}
+
+/// A module with the part after `TYPES_MARKER` regenerated, None if the marker is missing
+pub fn update_types(source: &str, spec: &Spec, module: &str) -> Option<String> {
+    let start = source.find(TYPES_MARKER)?;
+    Some(format!(
+        "{}{}",
+        &source[..start],
+        generate_types(spec, module)
+    ))
+}
+
+/// The generated part of `src/bot.rs`, starting with `MARKER`
+pub fn generate(spec: &Spec) -> String {
+    let mut out = format!("{MARKER}\n");
+    for method in &spec.methods {
+        out.push('\n');
+        push_method(&mut out, method);
+        push_validate(&mut out, method);
+    }
+    push_limits(&mut out, spec);
+    out
+}
+
+/// `src/bot.rs` with the part after `MARKER` regenerated, None if the marker is missing
+pub fn update(source: &str, spec: &Spec) -> Option<String> {
+    let start = source.find(MARKER)?;
+    Some(format!("{}{}", &source[..start], generate(spec)))
+}
+
+/// The first line that differs between two versions of a file, with both versions of the line
+pub fn first_difference(old: &str, new: &str) -> Option<(usize, String, String)> {
+    let mut old_lines = old.lines();
+    let mut new_lines = new.lines();
+    let mut number = 0;
+    loop {
+        number += 1;
+        match (old_lines.next(), new_lines.next()) {
+            (None, None) => return None,
+            (old, new) if old != new => {
+                return Some((
+                    number,
+                    old.unwrap_or_default().to_string(),
+                    new.unwrap_or_default().to_string(),
+                ))
+            }
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, path::PathBuf};
+
+    use super::*;
+
+    fn root() -> PathBuf {
+        PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("..")
+    }
+
+    fn spec() -> Spec {
+        serde_json::from_str(&fs::read_to_string(root().join("spec/bot_api.json")).unwrap())
+            .unwrap()
+    }
+
+    fn assert_up_to_date(file: &str, source: &str, generated: Option<String>) {
+        let generated = generated.unwrap_or_else(|| panic!("{file} has no generated part"));
+        if let Some((line, old, new)) = first_difference(source, &generated) {
+            panic!(
+                "{file} is out of date with spec/bot_api.json, run `cargo run -p rutel_codegen`\n\
+                 line {line}:\n  checked in: {old}\n  generated:  {new}"
+            );
+        }
+    }
+
+    #[test]
+    fn requests_match_spec() {
+        let source = fs::read_to_string(root().join("src/bot.rs")).unwrap();
+        assert_up_to_date("src/bot.rs", &source, update(&source, &spec()));
+    }
+
+    #[test]
+    fn types_match_spec() {
+        let spec = spec();
+        for module in modules(&spec) {
+            let file = format!("src/{module}.rs");
+            let source = fs::read_to_string(root().join(&file)).unwrap();
+            assert_up_to_date(&file, &source, update_types(&source, &spec, module));
+        }
+    }
+}
@@ -0,0 +1,55 @@
+use std::{fs, path::Path, process};
+
+use rutel_codegen::{modules, update, update_types, Spec, MARKER, TYPES_MARKER};
+
+fn read(path: &Path) -> String {
+    fs::read_to_string(path).unwrap_or_else(|err| {
+        eprintln!("can't read {}: {err}", path.display());
+        process::exit(1);
+    })
+}
+
+/// Writes a regenerated file, returns true if it changed
+fn write(root: &Path, file: &str, source: &str, generated: Option<String>, marker: &str) -> bool {
+    let path = root.join(file);
+    let Some(generated) = generated else {
+        eprintln!("{file} has no line {marker:?}");
+        process::exit(1);
+    };
+    if generated == source {
+        return false;
+    }
+    if let Err(err) = fs::write(&path, generated) {
+        eprintln!("can't write {file}: {err}");
+        process::exit(1);
+    }
+    println!("{file} updated");
+    true
+}
+
+fn main() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+    let spec_path = root.join("spec/bot_api.json");
+
+    let spec: Spec = match serde_json::from_str(&read(&spec_path)) {
+        Ok(spec) => spec,
+        Err(err) => {
+            eprintln!("can't read {}: {err}", spec_path.display());
+            process::exit(1);
+        }
+    };
+    let source = read(&root.join("src/bot.rs"));
+    let generated = update(&source, &spec);
+    let mut changed = write(&root, "src/bot.rs", &source, generated, MARKER);
+    for module in modules(&spec) {
+        let file = format!("src/{module}.rs");
+        let source = read(&root.join(&file));
+        let generated = update_types(&source, &spec, module);
+        changed |= write(&root, &file, &source, generated, TYPES_MARKER);
+    }
+    if changed {
+        println!("updated to Bot API {}", spec.version);
+    } else {
+        println!("up to date with Bot API {}", spec.version);
+    }
+}
@@ -0,0 +1,133 @@
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::{Attribute, Data, DeriveInput, Error, Fields, LitStr};
+
+use crate::commands::{is_option, syn_err, to_snake_case};
+
+fn rename(attrs: &[Attribute]) -> Result<Option<String>, Error> {
+    let mut rename = None;
+    for attr in attrs {
+        if !attr.path().is_ident("callback_data") {
+            continue;
+        }
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("rename") {
+                rename = Some(meta.value()?.parse::<LitStr>()?.value());
+                Ok(())
+            } else {
+                Err(meta.error("unsupported callback_data attribute"))
+            }
+        })?;
+    }
+    Ok(rename)
+}
+
+/// The pattern binding the fields, the code pushing them to `data` and the expression building the value from `fields`
+fn fields_code(path: TokenStream, fields: &Fields) -> (TokenStream, TokenStream, TokenStream) {
+    let bindings: Vec<Ident> = match fields {
+        Fields::Named(named) => named.named.iter().filter_map(|f| f.ident.clone()).collect(),
+        _ => (0..fields.len()).map(|i| format_ident!("f{}", i)).collect(),
+    };
+    let pushes = fields.iter().zip(&bindings).map(|(field, binding)| {
+        if is_option(&field.ty) {
+            quote! { ::rutel::callback_data::push_optional_field(&mut data, #binding.as_ref()); }
+        } else {
+            quote! { ::rutel::callback_data::push_field(&mut data, #binding); }
+        }
+    });
+    let values: Vec<TokenStream> = fields
+        .iter()
+        .enumerate()
+        .map(|(i, field)| {
+            if is_option(&field.ty) {
+                quote! { ::rutel::callback_data::parse_optional_field(&fields[#i])? }
+            } else {
+                quote! { ::rutel::callback_data::parse_field(&fields[#i])? }
+            }
+        })
+        .collect();
+    let (pattern, construct) = match fields {
+        Fields::Unit => (quote! { #path }, quote! { #path }),
+        Fields::Unnamed(_) => (
+            quote! { #path(#(#bindings,)*) },
+            quote! { #path(#(#values,)*) },
+        ),
+        Fields::Named(_) => (
+            quote! { #path { #(#bindings,)* } },
+            quote! { #path { #(#bindings: #values,)* } },
+        ),
+    };
+    (pattern, quote! { #(#pushes)* }, construct)
+}
+
+pub fn parse(ast: &DeriveInput) -> Result<TokenStream, Error> {
+    let name = &ast.ident;
+    let mut cases = Vec::new();
+    match &ast.data {
+        Data::Enum(data) => {
+            for variant in &data.variants {
+                let ident = &variant.ident;
+                let tag =
+                    rename(&variant.attrs)?.unwrap_or_else(|| to_snake_case(&ident.to_string()));
+                cases.push((tag, quote! { Self::#ident }, &variant.fields));
+            }
+        }
+        Data::Struct(data) => {
+            let tag = rename(&ast.attrs)?.unwrap_or_else(|| to_snake_case(&name.to_string()));
+            cases.push((tag, quote! { Self }, &data.fields));
+        }
+        Data::Union(_) => return Err(syn_err("CallbackData can't be derived for unions")),
+    }
+    for (tag, _, _) in &cases {
+        if tag.is_empty() || tag.contains([':', '\\']) {
+            return Err(Error::new(
+                Span::call_site(),
+                format!("callback data tag {tag:?} must be non-empty and contain no ':' or '\\\\'"),
+            ));
+        }
+    }
+
+    let mut encode_arms = Vec::new();
+    let mut decode_arms = Vec::new();
+    for (tag, path, fields) in &cases {
+        let (pattern, pushes, construct) = fields_code(path.clone(), fields);
+        let count = fields.len();
+        encode_arms.push(quote! {
+            #pattern => {
+                data.push_str(#tag);
+                #pushes
+            }
+        });
+        decode_arms.push(quote! {
+            #tag => {
+                if fields.len() != #count {
+                    return Err(::rutel::callback_data::CallbackDataError::WrongFieldCount {
+                        expected: #count,
+                        found: fields.len(),
+                    });
+                }
+                Ok(#construct)
+            }
+        });
+    }
+
+    Ok(quote! {
+        impl ::rutel::callback_data::CallbackData for #name {
+            fn encode(&self) -> String {
+                let mut data = String::new();
+                match self {
+                    #(#encode_arms)*
+                }
+                data
+            }
+
+            fn decode(data: &str) -> ::std::result::Result<Self, ::rutel::callback_data::CallbackDataError> {
+                let (tag, fields) = ::rutel::callback_data::split_fields(data);
+                match tag.as_str() {
+                    #(#decode_arms)*
+                    _ => Err(::rutel::callback_data::CallbackDataError::UnknownTag(tag)),
+                }
+            }
+        }
+    })
+}
@@ -0,0 +1,237 @@
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{Attribute, Data, DeriveInput, Error, Expr, Fields, Lit, LitStr, Meta, Type, Variant};
+
+pub(crate) fn syn_err(message: &str) -> Error {
+    Error::new(Span::call_site(), message)
+}
+
+pub(crate) fn to_snake_case(input: &str) -> String {
+    input
+        .chars()
+        .enumerate()
+        .fold(String::new(), |mut acc, (i, x)| {
+            if x.is_uppercase() {
+                if i != 0 {
+                    acc.push('_')
+                };
+                acc.push(x.to_ascii_lowercase());
+            } else {
+                acc.push(x);
+            }
+            acc
+        })
+}
+
+pub(crate) fn is_option(ty: &Type) -> bool {
+    match ty {
+        Type::Path(p) => p
+            .path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == "Option"),
+        _ => false,
+    }
+}
+
+#[derive(Default)]
+struct CommandAttrs {
+    rename: Option<String>,
+    rename_all: Option<String>,
+    description: Option<String>,
+    translations: Vec<(String, String)>,
+    hide: bool,
+}
+
+fn command_attrs(attrs: &[Attribute]) -> Result<CommandAttrs, Error> {
+    let mut result = CommandAttrs::default();
+    let mut doc = Vec::new();
+    for attr in attrs {
+        if attr.path().is_ident("doc") {
+            if let Meta::NameValue(name_value) = &attr.meta {
+                if let Expr::Lit(expr_lit) = &name_value.value {
+                    if let Lit::Str(lit_str) = &expr_lit.lit {
+                        doc.push(lit_str.value().trim().to_string());
+                    }
+                }
+            }
+        }
+        if !attr.path().is_ident("command") {
+            continue;
+        }
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("rename") {
+                result.rename = Some(meta.value()?.parse::<LitStr>()?.value());
+            } else if meta.path.is_ident("rename_all") {
+                result.rename_all = Some(meta.value()?.parse::<LitStr>()?.value());
+            } else if meta.path.is_ident("hide") {
+                result.hide = true;
+            } else if meta.path.is_ident("description") {
+                if meta.input.peek(syn::Token![=]) {
+                    result.description = Some(meta.value()?.parse::<LitStr>()?.value());
+                } else {
+                    meta.parse_nested_meta(|lang| {
+                        let code = lang
+                            .path
+                            .get_ident()
+                            .ok_or_else(|| lang.error("expected a language code"))?
+                            .to_string();
+                        let text = lang.value()?.parse::<LitStr>()?.value();
+                        result.translations.push((code, text));
+                        Ok(())
+                    })?;
+                }
+            } else {
+                return Err(meta.error("unsupported command attribute"));
+            }
+            Ok(())
+        })?;
+    }
+    if result.description.is_none() && !doc.is_empty() {
+        result.description = Some(doc.join(" ").trim().to_string());
+    }
+    Ok(result)
+}
+
+fn command_name(
+    variant: &Variant,
+    attrs: &CommandAttrs,
+    rename_all: &str,
+) -> Result<String, Error> {
+    if let Some(name) = &attrs.rename {
+        return Ok(name.clone());
+    }
+    let ident = variant.ident.to_string();
+    match rename_all {
+        "snake_case" => Ok(to_snake_case(&ident)),
+        "lowercase" => Ok(ident.to_lowercase()),
+        _ => Err(Error::new_spanned(
+            &variant.ident,
+            "rename_all must be \"snake_case\" or \"lowercase\"",
+        )),
+    }
+}
+
+fn parse_variant(variant: &Variant) -> TokenStream {
+    let ident = &variant.ident;
+    let types: Vec<&Type> = variant.fields.iter().map(|f| &f.ty).collect();
+    let count = types.len();
+    let required = types.iter().filter(|ty| !is_option(ty)).count();
+    let values: Vec<TokenStream> = types
+        .iter()
+        .enumerate()
+        .map(|(i, ty)| {
+            if is_option(ty) {
+                quote! {
+                    match arguments.get(#i) {
+                        Some(argument) => Some(::rutel::bot_command::parse_argument(argument)?),
+                        None => None,
+                    }
+                }
+            } else {
+                quote! {
+                    ::rutel::bot_command::parse_argument::<#ty>(arguments.get(#i).ok_or(
+                        ::rutel::bot_command::CommandParseError::TooFewArguments {
+                            expected: #required,
+                            found: arguments.len(),
+                        },
+                    )?)?
+                }
+            }
+        })
+        .collect();
+    let construct = match &variant.fields {
+        Fields::Unit => quote! { Self::#ident },
+        Fields::Unnamed(_) => quote! { Self::#ident(#(#values,)*) },
+        Fields::Named(fields) => {
+            let names = fields.named.iter().filter_map(|f| f.ident.as_ref());
+            quote! { Self::#ident { #(#names: #values,)* } }
+        }
+    };
+    if count == 0 {
+        quote! {
+            {
+                let found = args.split_whitespace().count();
+                if found > 0 {
+                    return Err(::rutel::bot_command::CommandParseError::TooManyArguments {
+                        expected: 0,
+                        found,
+                    });
+                }
+                Ok(#construct)
+            }
+        }
+    } else {
+        quote! {
+            {
+                let arguments = ::rutel::bot_command::split_arguments(args, #count);
+                Ok(#construct)
+            }
+        }
+    }
+}
+
+pub fn parse(ast: &DeriveInput) -> Result<TokenStream, Error> {
+    let name = &ast.ident;
+    let variants = match &ast.data {
+        Data::Enum(data) => Ok(&data.variants),
+        _ => Err(syn_err("BotCommands can only be derived for enums")),
+    }?;
+    let enum_attrs = command_attrs(&ast.attrs)?;
+    let rename_all = enum_attrs.rename_all.as_deref().unwrap_or("snake_case");
+
+    let mut arms = Vec::new();
+    let mut commands = Vec::new();
+    let mut languages = Vec::new();
+    for variant in variants {
+        let attrs = command_attrs(&variant.attrs)?;
+        let command = command_name(variant, &attrs, rename_all)?;
+        let pattern = command.to_lowercase();
+        let parse = parse_variant(variant);
+        arms.push(quote! { #pattern => #parse, });
+        if attrs.hide {
+            continue;
+        }
+        let description = attrs.description.clone().ok_or_else(|| {
+            Error::new_spanned(
+                &variant.ident,
+                "command needs a doc comment or a #[command(description = \"...\")]",
+            )
+        })?;
+        let codes: Vec<&String> = attrs.translations.iter().map(|(code, _)| code).collect();
+        let texts: Vec<&String> = attrs.translations.iter().map(|(_, text)| text).collect();
+        languages.extend(codes.iter().map(|code| code.to_string()));
+        commands.push(quote! {
+            ::rutel::bot_command::BotCommand {
+                command: #command.to_string(),
+                description: match language_code {
+                    #(Some(#codes) => #texts,)*
+                    _ => #description,
+                }
+                .to_string(),
+            }
+        });
+    }
+    languages.sort();
+    languages.dedup();
+
+    Ok(quote! {
+        impl ::rutel::bot_command::BotCommands for #name {
+            fn parse(text: &str, bot_username: &str) -> ::std::result::Result<Self, ::rutel::bot_command::CommandParseError> {
+                let (command, args) = ::rutel::bot_command::split_command(text, bot_username)?;
+                match command.to_lowercase().as_str() {
+                    #(#arms)*
+                    _ => Err(::rutel::bot_command::CommandParseError::UnknownCommand(command.to_string())),
+                }
+            }
+
+            fn bot_commands_for(language_code: Option<&str>) -> Vec<::rutel::bot_command::BotCommand> {
+                vec![#(#commands,)*]
+            }
+
+            fn languages() -> Vec<&'static str> {
+                vec![#(#languages,)*]
+            }
+        }
+    })
+}
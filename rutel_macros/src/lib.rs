@@ -0,0 +1,30 @@
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, DeriveInput};
+
+mod callback_data;
+mod commands;
+mod response;
+
+#[proc_macro_derive(BotCommands, attributes(command))]
+pub fn bot_commands(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    commands::parse(&input)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
+
+#[proc_macro_derive(CallbackData, attributes(callback_data))]
+pub fn callback_data(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    callback_data::parse(&input)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
+
+#[proc_macro_derive(Response, attributes(response))]
+pub fn response(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    response::parse(&input)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
@@ -0,0 +1,109 @@
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::{Attribute, Data, DeriveInput, Error, Expr, Field, Fields, Lit, Meta, Type};
+
+use crate::commands::{is_option, syn_err, to_snake_case};
+
+/// The type of the result of the method, from `#[response = "Type"]`
+fn response_type(attrs: &[Attribute]) -> Result<Type, Error> {
+    let attr = attrs
+        .iter()
+        .find(|attr| attr.path().is_ident("response"))
+        .ok_or_else(|| syn_err("cannot find `response` attribute in target struct."))?;
+    let Meta::NameValue(name_value) = &attr.meta else {
+        return Err(Error::new_spanned(attr, "expected #[response = \"Type\"]"));
+    };
+    let Expr::Lit(expr) = &name_value.value else {
+        return Err(Error::new_spanned(attr, "expected #[response = \"Type\"]"));
+    };
+    let Lit::Str(lit) = &expr.lit else {
+        return Err(Error::new_spanned(attr, "expected #[response = \"Type\"]"));
+    };
+    lit.parse()
+}
+
+fn named_fields(ast: &DeriveInput) -> Result<Vec<&Field>, Error> {
+    match &ast.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => Ok(fields.named.iter().collect()),
+            Fields::Unit | Fields::Unnamed(_) => Err(syn_err("wrong fields")),
+        },
+        _ => Err(syn_err("data not struct")),
+    }
+}
+
+/// The method of `Bot` sending the request, named after the struct in snake case and documented like it
+fn bot_method(name: &Ident, response: &Type, attrs: &[Attribute]) -> TokenStream {
+    let method = Ident::new(&to_snake_case(&name.to_string()), Span::call_site());
+    let docs = attrs.iter().filter(|attr| attr.path().is_ident("doc"));
+    quote! {
+        impl Bot {
+            #(#docs)*
+            pub async fn #method(&mut self, v: &#name) -> Result<#response> {
+                let resp = self.send(v).await?;
+                Ok(serde_json::from_value(resp)?)
+            }
+        }
+    }
+}
+
+/// `new` taking the required fields, getters and setters of the fields and `to_string`
+fn request_impl(name: &Ident, fields: &[&Field]) -> TokenStream {
+    let (optional, required): (Vec<&Field>, Vec<&Field>) =
+        fields.iter().partition(|field| is_option(&field.ty));
+    let required_names = required.iter().map(|field| &field.ident);
+    let required_types = required.iter().map(|field| &field.ty);
+    let required_fields = required.iter().map(|field| &field.ident);
+    let optional_fields = optional.iter().map(|field| &field.ident);
+    let names: Vec<&Option<Ident>> = fields.iter().map(|field| &field.ident).collect();
+    let types: Vec<&Type> = fields.iter().map(|field| &field.ty).collect();
+    let getters = fields
+        .iter()
+        .filter_map(|field| field.ident.as_ref())
+        .map(|ident| format_ident!("get_{}", ident));
+    quote! {
+        impl #name {
+            pub fn new(#(#required_names: #required_types,)*) -> Self {
+                #name {
+                    #(#required_fields,)*
+                    #(#optional_fields: None,)*
+                }
+            }
+
+            #(
+                pub fn #getters(&self) -> &#types {
+                    &self.#names
+                }
+            )*
+
+            #(
+                pub fn #names(&mut self, x: #types) -> &mut Self {
+                    self.#names = x;
+                    self
+                }
+            )*
+
+            pub fn to_string(&self) -> String {
+                serde_json::to_string(self).unwrap_or_default()
+            }
+        }
+
+        impl std::fmt::Display for #name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "{}", self.to_string())
+            }
+        }
+    }
+}
+
+pub fn parse(ast: &DeriveInput) -> Result<TokenStream, Error> {
+    let response = response_type(&ast.attrs)?;
+    let fields = named_fields(ast)?;
+    let bot_method = bot_method(&ast.ident, &response, &ast.attrs);
+    let request_impl = request_impl(&ast.ident, &fields);
+    Ok(quote! {
+        #bot_method
+
+        #request_impl
+    })
+}
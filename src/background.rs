@@ -1,24 +1,63 @@
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 
 use crate::{
     files::Document,
     types::{Boolean, Integer},
 };
 
+/// This object represents a chat background.
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct ChatBackground {
+    /// Type of the background
+    #[serde(rename = "type")]
+    pub kind: BackgroundType,
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn nested_unions() {
+        let background: BackgroundType = serde_json::from_value(json!({
+            "type": "fill",
+            "fill": {"type": "solid", "color": 255},
+            "dark_theme_dimming": 10
+        }))
+        .unwrap();
+        assert!(matches!(
+            background,
+            BackgroundType::BackgroundTypeFill(BackgroundTypeFill {
+                fill: BackgroundFill::BackgroundFillSolid(_),
+                ..
+            })
+        ));
+    }
+}
+
+// The types below are generated from spec/bot_api.json by `cargo run -p rutel_codegen`, edit the spec instead.
+
 /// This object describes the way a background is filled based on the selected colors. Currently, it can be one of
 #[derive(Clone, Serialize, Deserialize, Debug)]
+#[serde(tag = "type")]
 pub enum BackgroundFill {
-    BackgroundFillSolid,
-    BackgroundFillGradient,
-    BackgroundFillFreeformGradient,
+    #[serde(rename = "solid")]
+    BackgroundFillSolid(BackgroundFillSolid),
+    #[serde(rename = "gradient")]
+    BackgroundFillGradient(BackgroundFillGradient),
+    #[serde(rename = "freeform_gradient")]
+    BackgroundFillFreeformGradient(BackgroundFillFreeformGradient),
+    /// An object not known to this version of the library, or one that can't be decoded
+    #[serde(untagged)]
+    Unknown(Value),
 }
 
 /// The background is filled using the selected color.
 #[derive(Clone, Serialize, Deserialize, Debug)]
 pub struct BackgroundFillSolid {
-    /// Type of the background fill, always “solid”
-    #[serde(rename = "type")]
-    pub kind: String,
     /// The color of the background fill in the RGB24 format
     pub color: Integer,
 }
@@ -26,9 +65,6 @@ pub struct BackgroundFillSolid {
 /// The background is a gradient fill.
 #[derive(Clone, Serialize, Deserialize, Debug)]
 pub struct BackgroundFillGradient {
-    /// Type of the background fill, always “gradient”
-    #[serde(rename = "type")]
-    pub kind: String,
     /// Top color of the gradient in the RGB24 format
     pub top_color: Integer,
     /// Bottom color of the gradient in the RGB24 format
@@ -40,28 +76,30 @@ pub struct BackgroundFillGradient {
 /// The background is a freeform gradient that rotates after every message in the chat.
 #[derive(Clone, Serialize, Deserialize, Debug)]
 pub struct BackgroundFillFreeformGradient {
-    /// Type of the background fill, always “freeform_gradient”
-    #[serde(rename = "type")]
-    pub kind: String,
     /// A list of the 3 or 4 base colors that are used to generate the freeform gradient in the RGB24 format
     pub colors: Vec<Integer>,
 }
 
 /// This object describes the type of a background. Currently, it can be one of
 #[derive(Clone, Serialize, Deserialize, Debug)]
+#[serde(tag = "type")]
 pub enum BackgroundType {
-    BackgroundTypeFill,
-    BackgroundTypeWallpaper,
-    BackgroundTypePattern,
-    BackgroundTypeChatTheme,
+    #[serde(rename = "fill")]
+    BackgroundTypeFill(BackgroundTypeFill),
+    #[serde(rename = "wallpaper")]
+    BackgroundTypeWallpaper(BackgroundTypeWallpaper),
+    #[serde(rename = "pattern")]
+    BackgroundTypePattern(BackgroundTypePattern),
+    #[serde(rename = "chat_theme")]
+    BackgroundTypeChatTheme(BackgroundTypeChatTheme),
+    /// An object not known to this version of the library, or one that can't be decoded
+    #[serde(untagged)]
+    Unknown(Value),
 }
 
 /// The background is automatically filled based on the selected colors.
 #[derive(Clone, Serialize, Deserialize, Debug)]
 pub struct BackgroundTypeFill {
-    /// Type of the background, always “fill”
-    #[serde(rename = "type")]
-    pub kind: String,
     /// The background fill
     pub fill: BackgroundFill,
     /// Dimming of the background in dark themes, as a percentage; 0-100
@@ -71,9 +109,6 @@ pub struct BackgroundTypeFill {
 /// The background is a wallpaper in the JPEG format.
 #[derive(Clone, Serialize, Deserialize, Debug)]
 pub struct BackgroundTypeWallpaper {
-    /// Type of the background, always “wallpaper”
-    #[serde(rename = "type")]
-    pub kind: String,
     /// Document with the wallpaper
     pub document: Document,
     /// Dimming of the background in dark themes, as a percentage; 0-100
@@ -89,9 +124,6 @@ pub struct BackgroundTypeWallpaper {
 /// The background is a PNG or TGV (gzipped subset of SVG with MIME type “application/x-tgwallpattern”) pattern to be combined with the background fill chosen by the user.
 #[derive(Clone, Serialize, Deserialize, Debug)]
 pub struct BackgroundTypePattern {
-    /// Type of the background, always “pattern”
-    #[serde(rename = "type")]
-    pub kind: String,
     /// Document with the pattern
     pub document: Document,
     /// The background fill that is combined with the pattern
@@ -109,17 +141,6 @@ pub struct BackgroundTypePattern {
 /// The background is taken directly from a built-in chat theme.
 #[derive(Clone, Serialize, Deserialize, Debug)]
 pub struct BackgroundTypeChatTheme {
-    /// Type of the background, always “chat_theme”
-    #[serde(rename = "type")]
-    pub kind: String,
     /// Name of the chat theme, which is usually an emoji
     pub theme_name: String,
 }
-
-/// This object represents a chat background.
-#[derive(Clone, Serialize, Deserialize, Debug)]
-pub struct ChatBackground {
-    /// Type of the background
-    #[serde(rename = "type")]
-    pub kind: BackgroundType,
-}
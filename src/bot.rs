@@ -1,5 +1,5 @@
 use netc::client::Client;
-use rutel_derive::Response;
+use rutel_macros::Response;
 use serde::Serialize;
 use serde_json::{from_slice, from_value, Value};
 
@@ -8,38 +8,48 @@ use crate::{
     chat_boost::UserChatBoosts,
     error::{Error, Result},
     files::File,
+    formatting::ParseMode,
     games::GameHighScore,
+    ids::{ChatId, FileId, MessageId, UserId},
     inline_mode::{InlineQueryResult, InlineQueryResultsButton, SentWebAppMessage},
     input_media::InputMedia,
-    message::{Message, MessageEntity, MessageId, ReplyParameters},
+    message::{Message, MessageEntity, ReplyParameters},
     passport::PassportElementError,
     payments::{LabeledPrice, ShippingOption, StarTransactions},
-    poll::{InputPollOption, Poll},
+    poll::{InputPollOption, Poll, PollType},
     reactions::ReactionType,
-    stickers::{InputSticker, MaskPosition, Sticker, StickerSet},
+    stickers::{InputSticker, MaskPosition, Sticker, StickerFormat, StickerSet, StickerType},
+    token::Token,
     types::{
-        Boolean, BotDescription, BotName, BotShortDescription, ChatAdministratorRights,
-        ChatFullInfo, ChatID, ChatInviteLink, ChatMember, ChatPermissions, Float, ForumTopic,
-        InlineKeyboardMarkup, InputFile, InputFileString, InputPaidMedia, Integer,
+        Boolean, BotDescription, BotName, BotShortDescription, ChatAction, ChatAdministratorRights,
+        ChatFullInfo, ChatID, ChatInviteLink, ChatMember, ChatPermissions, DiceEmoji, Float,
+        ForumTopic, InlineKeyboardMarkup, InputFile, InputFileString, InputPaidMedia, Integer,
         LinkPreviewOptions, MenuButton, ReplyMarkup, Response, TrueMessage, Update, User,
-        UserProfilePhotos,
+        UserProfilePhotos, WebhookInfo,
     },
+    update::AllowedUpdate,
+    validate::{self, Limit, Validate},
 };
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct Bot {
-    pub token: String,
+    pub token: Token,
     pub proxy: Option<String>,
     // pub user: Option<User>,
+    /// Answers the requests instead of the Bot API in tests
+    #[cfg(test)]
+    pub(crate) mock: Option<std::sync::Arc<crate::test_util::Mock>>,
 }
 
 impl Bot {
-    pub fn new(token: &str) -> Self {
-        Bot {
-            token: token.to_string(),
+    pub fn new(token: &str) -> Result<Self> {
+        Ok(Bot {
+            token: Token::new(token)?,
             proxy: None,
             // user: None,
-        }
+            #[cfg(test)]
+            mock: None,
+        })
     }
 
     pub fn proxy(&mut self, proxy: String) {
@@ -47,10 +57,60 @@ impl Bot {
     }
 
     pub fn build_uri(&self, method: &'static str) -> String {
-        format!("https://api.telegram.org/bot{}/{}", self.token, method)
+        format!(
+            "https://api.telegram.org/bot{}/{}",
+            self.token.expose_secret(),
+            method
+        )
+    }
+
+    /// Sends a request to the Bot API after checking its parameters against the documented limits
+    pub async fn send<R: Validate>(&mut self, request: &R) -> Result<Value> {
+        self.send_value(R::METHOD, &serde_json::to_value(request)?)
+            .await
+    }
+
+    /// Sends the parameters of a method after checking them against the documented limits
+    pub async fn send_value(&mut self, method: &'static str, params: &Value) -> Result<Value> {
+        validate::check(method, params)?;
+        self.create_request(method, params.to_string()).await
     }
 
+    /// Sends the parameters of a method like `send_value`, repeating the request up to `max_retries` times when flood
+    /// control is exceeded, each time after waiting as long as the Bot API asks
+    pub async fn send_value_retrying(
+        &mut self,
+        method: &'static str,
+        params: &Value,
+        max_retries: u32,
+    ) -> Result<Value> {
+        let mut retries = 0;
+        loop {
+            match self.send_value(method, params).await {
+                Err(err) if retries < max_retries => match err.retry_after() {
+                    Some(wait) => {
+                        retries += 1;
+                        tokio::time::sleep(wait).await;
+                    }
+                    None => return Err(err),
+                },
+                result => return result,
+            }
+        }
+    }
+
+    /// Sends a request to the Bot API as it is, without checking its parameters
     pub async fn create_request(&mut self, method: &'static str, values: String) -> Result<Value> {
+        self.send_request(method, values)
+            .await
+            .map_err(|err| self.token.scrub_error(err))
+    }
+
+    async fn send_request(&self, method: &'static str, values: String) -> Result<Value> {
+        #[cfg(test)]
+        if let Some(mock) = &self.mock {
+            return mock.respond(method, &values);
+        }
         let uri = self.build_uri(method);
 
         let client_builder = if let Some(proxy) = &self.proxy {
@@ -76,31 +136,102 @@ impl Bot {
             Ok(res)
         } else {
             let description = r.description.ok_or(Error::NoDescription)?;
-            Err(Error::Description(description))
+            match r.error_code {
+                Some(code) => Err(Error::Api {
+                    code,
+                    description,
+                    parameters: r.parameters.and_then(|p| from_value(p).ok()),
+                }),
+                None => Err(Error::Description(description)),
+            }
         }
     }
 }
 
+// The request structs below are generated from spec/bot_api.json by `cargo run -p rutel_codegen`, edit the spec instead.
+
 /// A simple method for testing your bot's auth token. Requires no parameters. Returns basic information about the bot in form of a User object.
 #[derive(Serialize, Debug, Response)]
 #[response = "User"]
 pub struct GetMe {}
 
+impl Validate for GetMe {
+    const METHOD: &'static str = "getMe";
+}
+
 /// Use this method to log out from the cloud Bot API server before launching the bot locally. You must log out the bot before running it locally, otherwise there is no guarantee that the bot will receive updates. After a successful call, you can immediately log in on a local server, but will not be able to log in back to the cloud Bot API server for 10 minutes. Returns True on success. Requires no parameters.
 #[derive(Serialize, Debug, Response)]
 #[response = "Boolean"]
 pub struct LogOut {}
 
+impl Validate for LogOut {
+    const METHOD: &'static str = "logOut";
+}
+
 /// Use this method to close the bot instance before moving it from one local server to another. You need to delete the webhook before calling this method to ensure that the bot isn't launched again after server restart. The method will return error 429 in the first 10 minutes after the bot is launched. Returns True on success. Requires no parameters.
 #[derive(Serialize, Debug, Response)]
 #[response = "Boolean"]
 pub struct Close {}
 
-// // setwebhook
-// // deleteWebhook
-// // getWebhookInfo
+impl Validate for Close {
+    const METHOD: &'static str = "close";
+}
+
+/// Use this method to specify a URL and receive incoming updates via an outgoing webhook. Whenever there is an update for the bot, we will send an HTTPS POST request to the specified URL, containing a JSON-serialized Update. In case of an unsuccessful request, we will give up after a reasonable amount of attempts. Returns True on success.
+#[derive(Serialize, Debug, Response)]
+#[response = "Boolean"]
+pub struct SetWebhook {
+    /// HTTPS URL to send updates to. Use an empty string to remove webhook integration
+    pub url: String,
+    /// Optional. Upload your public key certificate so that the root certificate in use can be checked. See our self-signed guide for details.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub certificate: Option<InputFile>,
+    /// Optional. The fixed IP address which will be used to send webhook requests instead of the IP address resolved through DNS
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ip_address: Option<String>,
+    /// Optional. The maximum allowed number of simultaneous HTTPS connections to the webhook for update delivery, 1-100. Defaults to 40. Use lower values to limit the load on your bot's server, and higher values to increase your bot's throughput.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_connections: Option<Integer>,
+    /// Optional. A JSON-serialized list of the update types you want your bot to receive. For example, specify ["message", "edited_channel_post", "callback_query"] to only receive updates of these types. See Update for a complete list of available update types. Specify an empty list to receive all update types except chat_member, message_reaction, and message_reaction_count (default). If not specified, the previous setting will be used.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_updates: Option<Vec<AllowedUpdate>>,
+    /// Optional. Pass True to drop all pending updates
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub drop_pending_updates: Option<Boolean>,
+    /// Optional. A secret token to be sent in a header “X-Telegram-Bot-Api-Secret-Token” in every webhook request, 1-256 characters. Only characters A-Z, a-z, 0-9, _ and - are allowed. The header is useful to ensure that the request comes from a webhook set by you.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secret_token: Option<String>,
+}
+
+impl Validate for SetWebhook {
+    const METHOD: &'static str = "setWebhook";
+}
+
+/// Use this method to remove webhook integration if you decide to switch back to getUpdates. Returns True on success.
+#[derive(Serialize, Debug, Response)]
+#[response = "Boolean"]
+pub struct DeleteWebhook {
+    /// Optional. Pass True to drop all pending updates
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub drop_pending_updates: Option<Boolean>,
+}
+
+impl Validate for DeleteWebhook {
+    const METHOD: &'static str = "deleteWebhook";
+}
+
+/// Use this method to get current webhook status. Requires no parameters. On success, returns a WebhookInfo object. If the bot is using getUpdates, will return an object with the url field empty.
+#[derive(Serialize, Debug, Response)]
+#[response = "WebhookInfo"]
+pub struct GetWebhookInfo {}
+
+impl Validate for GetWebhookInfo {
+    const METHOD: &'static str = "getWebhookInfo";
+}
 
 /// Use this method to receive incoming updates using long polling (wiki). An Array of Update objects is returned.
+///
+/// The batch is decoded at once, so one update that can't be decoded fails it and polling with this method gets stuck on that update. Use [`Bot::get_updates_lenient`] to decode each update on its own, as `Dispatcher::polling` does.
 #[derive(Serialize, Debug, Response)]
 #[response = "Vec<Update>"]
 pub struct GetUpdates {
@@ -115,14 +246,18 @@ pub struct GetUpdates {
     pub timeout: Option<Integer>,
     /// Optional. List the types of updates you want your bot to receive. For example, specify [“message”, “edited_channel_post”, “callback_query”] to only receive updates of these types. See Update for a complete list of available update types. Specify an empty list to receive all updates regardless of type (default). If not specified, the previous setting will be used.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub allowed_updates: Option<Vec<String>>,
+    pub allowed_updates: Option<Vec<AllowedUpdate>>,
+}
+
+impl Validate for GetUpdates {
+    const METHOD: &'static str = "getUpdates";
 }
 
 /// Use this method to send text messages. On success, the sent Message is returned.
 #[derive(Serialize, Debug, Response)]
 #[response = "Message"]
 pub struct SendMessage {
-    /// Optional Unique identifier of the business connection on behalf of which the message will be sent
+    /// Optional. Unique identifier of the business connection on behalf of which the message will be sent
     #[serde(skip_serializing_if = "Option::is_none")]
     pub business_connection_id: Option<String>,
     /// Unique identifier for the target chat or username of the target channel (in the format @channelusername)
@@ -134,8 +269,8 @@ pub struct SendMessage {
     pub text: String,
     /// Optional. Send Markdown or HTML, if you want Telegram apps to show bold, italic, fixed-width text or inline URLs in your bot's message.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub parse_mode: Option<String>,
-    /// Optional. List of special entities that appear in message text, which can be specified instead of parse_mode
+    pub parse_mode: Option<ParseMode>,
+    /// Optional. A JSON-serialized list of special entities that appear in the message text, which can be specified instead of parse_mode
     #[serde(skip_serializing_if = "Option::is_none")]
     pub entities: Option<Vec<MessageEntity>>,
     /// Optional. Link preview generation options for the message
@@ -158,6 +293,10 @@ pub struct SendMessage {
     pub reply_markup: Option<ReplyMarkup>,
 }
 
+impl Validate for SendMessage {
+    const METHOD: &'static str = "sendMessage";
+}
+
 /// Use this method to forward messages of any kind. On success, the sent Message is returned.
 #[derive(Serialize, Debug, Response)]
 #[response = "Message"]
@@ -176,7 +315,11 @@ pub struct ForwardMessage {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub protect_content: Option<Boolean>,
     /// Message identifier in the chat specified in from_chat_id
-    pub message_id: Integer,
+    pub message_id: MessageId,
+}
+
+impl Validate for ForwardMessage {
+    const METHOD: &'static str = "forwardMessage";
 }
 
 /// Use this method to forward multiple messages of any kind. If some of the specified messages can't be found or forwarded, they are skipped. Service messages and messages with protected content can't be forwarded. Album grouping is kept for forwarded messages. On success, an array of MessageId of the sent messages is returned.
@@ -191,7 +334,7 @@ pub struct ForwardMessages {
     /// Unique identifier for the chat where the original messages were sent (or channel username in the format @channelusername)
     pub from_chat_id: ChatID,
     /// Identifiers of 1-100 messages in the chat from_chat_id to forward. The identifiers must be specified in a strictly increasing order.
-    pub message_ids: Vec<Integer>,
+    pub message_ids: Vec<MessageId>,
     /// Optional. Sends the messages silently. Users will receive a notification with no sound.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub disable_notification: Option<Boolean>,
@@ -200,6 +343,10 @@ pub struct ForwardMessages {
     pub protect_content: Option<Boolean>,
 }
 
+impl Validate for ForwardMessages {
+    const METHOD: &'static str = "forwardMessages";
+}
+
 /// Use this method to copy messages of any kind. Service messages, paid media messages, giveaway messages, giveaway winners messages, and invoice messages can't be copied. A quiz poll can be copied only if the value of the field correct_option_id is known to the bot. The method is analogous to the method forwardMessage, but the copied message doesn't have a link to the original message. Returns the MessageId of the sent message on success.
 #[derive(Serialize, Debug, Response)]
 #[response = "MessageId"]
@@ -212,14 +359,14 @@ pub struct CopyMessage {
     /// Unique identifier for the chat where the original message was sent (or channel username in the format @channelusername)
     pub from_chat_id: ChatID,
     /// Message identifier in the chat specified in from_chat_id
-    pub message_id: Integer,
+    pub message_id: MessageId,
     /// Optional. New caption for media, 0-1024 characters after entities parsing. If not specified, the original caption is kept
     #[serde(skip_serializing_if = "Option::is_none")]
     pub caption: Option<String>,
     /// Optional. Mode for parsing entities in the new caption. See formatting options for more details.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub parse_mode: Option<String>,
-    /// Optional. List of special entities that appear in the new caption, which can be specified instead of parse_mode
+    pub parse_mode: Option<ParseMode>,
+    /// Optional. A JSON-serialized list of special entities that appear in the caption, which can be specified instead of parse_mode
     #[serde(skip_serializing_if = "Option::is_none")]
     pub caption_entities: Option<Vec<MessageEntity>>,
     /// Optional. True, if the caption must be shown above the message media
@@ -239,6 +386,10 @@ pub struct CopyMessage {
     pub reply_markup: Option<ReplyMarkup>,
 }
 
+impl Validate for CopyMessage {
+    const METHOD: &'static str = "copyMessage";
+}
+
 /// Use this method to copy messages of any kind. If some of the specified messages can't be found or copied, they are skipped. Service messages, paid media messages, giveaway messages, giveaway winners messages, and invoice messages can't be copied. A quiz poll can be copied only if the value of the field correct_option_id is known to the bot. The method is analogous to the method forwardMessages, but the copied messages don't have a link to the original message. Album grouping is kept for copied messages. On success, an array of MessageId of the sent messages is returned.
 #[derive(Serialize, Debug, Response)]
 #[response = "Vec<MessageId>"]
@@ -251,7 +402,7 @@ pub struct CopyMessages {
     /// Unique identifier for the chat where the original messages were sent (or channel username in the format @channelusername)
     pub from_chat_id: ChatID,
     /// Identifiers of 1-100 messages in the chat from_chat_id to copy. The identifiers must be specified in a strictly increasing order.
-    pub message_ids: Vec<Integer>,
+    pub message_ids: Vec<MessageId>,
     /// Optional. Sends the messages silently. Users will receive a notification with no sound.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub disable_notification: Option<Boolean>,
@@ -263,11 +414,15 @@ pub struct CopyMessages {
     pub remove_caption: Option<Boolean>,
 }
 
+impl Validate for CopyMessages {
+    const METHOD: &'static str = "copyMessages";
+}
+
 /// Use this method to send photos. On success, the sent Message is returned.
 #[derive(Serialize, Debug, Response)]
 #[response = "Message"]
 pub struct SendPhoto {
-    /// Optional Unique identifier of the business connection on behalf of which the message will be sent
+    /// Optional. Unique identifier of the business connection on behalf of which the message will be sent
     #[serde(skip_serializing_if = "Option::is_none")]
     pub business_connection_id: Option<String>,
     /// Unique identifier for the target chat or username of the target channel (in the format @channelusername)
@@ -282,8 +437,8 @@ pub struct SendPhoto {
     pub caption: Option<String>,
     /// Optional. Send Markdown or HTML, if you want Telegram apps to show bold, italic, fixed-width text or inline URLs in the media caption.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub parse_mode: Option<String>,
-    // List of special entities that appear in the caption, which can be specified instead of parse_mode
+    pub parse_mode: Option<ParseMode>,
+    /// Optional. A JSON-serialized list of special entities that appear in the caption, which can be specified instead of parse_mode
     #[serde(skip_serializing_if = "Option::is_none")]
     pub caption_entities: Option<Vec<MessageEntity>>,
     /// Optional. True, if the caption must be shown above the message media
@@ -309,12 +464,15 @@ pub struct SendPhoto {
     pub reply_markup: Option<ReplyMarkup>,
 }
 
-/// Use this method to send audio files, if you want Telegram clients to display them in the music player. Your audio must be in the .MP3 or .M4A format. On success, the sent Message is returned. Bots can currently send audio files of up to 50 MB in size, this limit may be changed in the future.
-/// For sending voice messages, use the sendVoice method instead.
+impl Validate for SendPhoto {
+    const METHOD: &'static str = "sendPhoto";
+}
+
+/// Use this method to send audio files, if you want Telegram clients to display them in the music player. Your audio must be in the .MP3 or .M4A format. On success, the sent Message is returned. Bots can currently send audio files of up to 50 MB in size, this limit may be changed in the future. For sending voice messages, use the sendVoice method instead.
 #[derive(Serialize, Debug, Response)]
 #[response = "Message"]
 pub struct SendAudio {
-    /// Optional Unique identifier of the business connection on behalf of which the message will be sent
+    /// Optional. Unique identifier of the business connection on behalf of which the message will be sent
     #[serde(skip_serializing_if = "Option::is_none")]
     pub business_connection_id: Option<String>,
     /// Unique identifier for the target chat or username of the target channel (in the format @channelusername)
@@ -329,8 +487,8 @@ pub struct SendAudio {
     pub caption: Option<String>,
     /// Optional. Send Markdown or HTML, if you want Telegram apps to show bold, italic, fixed-width text or inline URLs in the media caption.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub parse_mode: Option<String>,
-    // List of special entities that appear in the caption, which can be specified instead of parse_mode
+    pub parse_mode: Option<ParseMode>,
+    /// Optional. A JSON-serialized list of special entities that appear in the caption, which can be specified instead of parse_mode
     #[serde(skip_serializing_if = "Option::is_none")]
     pub caption_entities: Option<Vec<MessageEntity>>,
     /// Optional. True, if the caption must be shown above the message media
@@ -365,11 +523,15 @@ pub struct SendAudio {
     pub reply_markup: Option<ReplyMarkup>,
 }
 
+impl Validate for SendAudio {
+    const METHOD: &'static str = "sendAudio";
+}
+
 /// Use this method to send general files. On success, the sent Message is returned. Bots can currently send files of any type of up to 50 MB in size, this limit may be changed in the future.
 #[derive(Serialize, Debug, Response)]
 #[response = "Message"]
 pub struct SendDocument {
-    /// Optional Unique identifier of the business connection on behalf of which the message will be sent
+    /// Optional. Unique identifier of the business connection on behalf of which the message will be sent
     #[serde(skip_serializing_if = "Option::is_none")]
     pub business_connection_id: Option<String>,
     /// Unique identifier for the target chat or username of the target channel (in the format @channelusername)
@@ -387,8 +549,8 @@ pub struct SendDocument {
     pub caption: Option<String>,
     /// Optional. Send Markdown or HTML, if you want Telegram apps to show bold, italic, fixed-width text or inline URLs in the media caption.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub parse_mode: Option<String>,
-    /// Optional. List of special entities that appear in the caption, which can be specified instead of parse_mode
+    pub parse_mode: Option<ParseMode>,
+    /// Optional. A JSON-serialized list of special entities that appear in the caption, which can be specified instead of parse_mode
     #[serde(skip_serializing_if = "Option::is_none")]
     pub caption_entities: Option<Vec<MessageEntity>>,
     /// Optional. True, if the caption must be shown above the message media
@@ -414,11 +576,15 @@ pub struct SendDocument {
     pub reply_markup: Option<ReplyMarkup>,
 }
 
+impl Validate for SendDocument {
+    const METHOD: &'static str = "sendDocument";
+}
+
 /// Use this method to send video files, Telegram clients support mp4 videos (other formats may be sent as Document). On success, the sent Message is returned. Bots can currently send video files of up to 50 MB in size, this limit may be changed in the future.
 #[derive(Serialize, Debug, Response)]
 #[response = "Message"]
 pub struct SendVideo {
-    /// Optional Unique identifier of the business connection on behalf of which the message will be sent
+    /// Optional. Unique identifier of the business connection on behalf of which the message will be sent
     #[serde(skip_serializing_if = "Option::is_none")]
     pub business_connection_id: Option<String>,
     /// Unique identifier for the target chat or username of the target channel (in the format @channelusername)
@@ -445,8 +611,8 @@ pub struct SendVideo {
     pub caption: Option<String>,
     /// Optional. Send Markdown or HTML, if you want Telegram apps to show bold, italic, fixed-width text or inline URLs in the media caption.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub parse_mode: Option<String>,
-    /// Optional. List of special entities that appear in the caption, which can be specified instead of parse_mode
+    pub parse_mode: Option<ParseMode>,
+    /// Optional. A JSON-serialized list of special entities that appear in the caption, which can be specified instead of parse_mode
     #[serde(skip_serializing_if = "Option::is_none")]
     pub caption_entities: Option<Vec<MessageEntity>>,
     /// Optional. True, if the caption must be shown above the message media
@@ -475,11 +641,15 @@ pub struct SendVideo {
     pub reply_markup: Option<ReplyMarkup>,
 }
 
+impl Validate for SendVideo {
+    const METHOD: &'static str = "sendVideo";
+}
+
 /// Use this method to send animation files (GIF or H.264/MPEG-4 AVC video without sound). On success, the sent Message is returned. Bots can currently send animation files of up to 50 MB in size, this limit may be changed in the future.
 #[derive(Serialize, Debug, Response)]
 #[response = "Message"]
 pub struct SendAnimation {
-    /// Optional Unique identifier of the business connection on behalf of which the message will be sent
+    /// Optional. Unique identifier of the business connection on behalf of which the message will be sent
     #[serde(skip_serializing_if = "Option::is_none")]
     pub business_connection_id: Option<String>,
     /// Unique identifier for the target chat or username of the target channel (in the format @channelusername)
@@ -506,8 +676,8 @@ pub struct SendAnimation {
     pub caption: Option<String>,
     /// Optional. Send Markdown or HTML, if you want Telegram apps to show bold, italic, fixed-width text or inline URLs in the media caption.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub parse_mode: Option<String>,
-    // List of special entities that appear in the caption, which can be specified instead of parse_mode
+    pub parse_mode: Option<ParseMode>,
+    /// Optional. A JSON-serialized list of special entities that appear in the caption, which can be specified instead of parse_mode
     #[serde(skip_serializing_if = "Option::is_none")]
     pub caption_entities: Option<Vec<MessageEntity>>,
     /// Optional. True, if the caption must be shown above the message media
@@ -533,11 +703,15 @@ pub struct SendAnimation {
     pub reply_markup: Option<ReplyMarkup>,
 }
 
+impl Validate for SendAnimation {
+    const METHOD: &'static str = "sendAnimation";
+}
+
 /// Use this method to send audio files, if you want Telegram clients to display the file as a playable voice message. For this to work, your audio must be in an .ogg file encoded with OPUS (other formats may be sent as Audio or Document). On success, the sent Message is returned. Bots can currently send voice messages of up to 50 MB in size, this limit may be changed in the future.
 #[derive(Serialize, Debug, Response)]
 #[response = "Message"]
 pub struct SendVoice {
-    /// Optional Unique identifier of the business connection on behalf of which the message will be sent
+    /// Optional. Unique identifier of the business connection on behalf of which the message will be sent
     #[serde(skip_serializing_if = "Option::is_none")]
     pub business_connection_id: Option<String>,
     /// Unique identifier for the target chat or username of the target channel (in the format @channelusername)
@@ -552,8 +726,8 @@ pub struct SendVoice {
     pub caption: Option<String>,
     /// Optional. Send Markdown or HTML, if you want Telegram apps to show bold, italic, fixed-width text or inline URLs in the media caption.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub parse_mode: Option<String>,
-    // List of special entities that appear in the caption, which can be specified instead of parse_mode
+    pub parse_mode: Option<ParseMode>,
+    /// Optional. A JSON-serialized list of special entities that appear in the caption, which can be specified instead of parse_mode
     #[serde(skip_serializing_if = "Option::is_none")]
     pub caption_entities: Option<Vec<MessageEntity>>,
     /// Optional. True, if the caption must be shown above the message media
@@ -579,11 +753,15 @@ pub struct SendVoice {
     pub reply_markup: Option<ReplyMarkup>,
 }
 
+impl Validate for SendVoice {
+    const METHOD: &'static str = "sendVoice";
+}
+
 /// As of v.4.0, Telegram clients support rounded square mp4 videos of up to 1 minute long. Use this method to send video messages. On success, the sent Message is returned.
 #[derive(Serialize, Debug, Response)]
 #[response = "Message"]
 pub struct SendVideoNote {
-    /// Optional Unique identifier of the business connection on behalf of which the message will be sent
+    /// Optional. Unique identifier of the business connection on behalf of which the message will be sent
     #[serde(skip_serializing_if = "Option::is_none")]
     pub business_connection_id: Option<String>,
     /// Unique identifier for the target chat or username of the target channel (in the format @channelusername)
@@ -619,22 +797,26 @@ pub struct SendVideoNote {
     pub reply_markup: Option<ReplyMarkup>,
 }
 
+impl Validate for SendVideoNote {
+    const METHOD: &'static str = "sendVideoNote";
+}
+
 /// Use this method to send paid media to channel chats. On success, the sent Message is returned.
 #[derive(Serialize, Debug, Response)]
 #[response = "Message"]
 pub struct SendPaidMedia {
-    /// Yes Unique identifier for the target chat or username of the target channel (in the format @channelusername)
+    /// Unique identifier for the target chat or username of the target channel (in the format @channelusername)
     pub chat_id: ChatID,
-    /// Yes The number of Telegram Stars that must be paid to buy access to the media
+    /// The number of Telegram Stars that must be paid to buy access to the media
     pub star_count: Integer,
-    /// Yes A JSON-serialized array describing the media to be sent; up to 10 items
+    /// A JSON-serialized array describing the media to be sent; up to 10 items
     pub media: Vec<InputPaidMedia>,
     /// Optional. Media caption, 0-1024 characters after entities parsing
     #[serde(skip_serializing_if = "Option::is_none")]
     pub caption: Option<String>,
     /// Optional. Mode for parsing entities in the media caption. See formatting options for more details.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub parse_mode: Option<String>,
+    pub parse_mode: Option<ParseMode>,
     /// Optional. A JSON-serialized list of special entities that appear in the caption, which can be specified instead of parse_mode
     #[serde(skip_serializing_if = "Option::is_none")]
     pub caption_entities: Option<Vec<MessageEntity>>,
@@ -655,11 +837,15 @@ pub struct SendPaidMedia {
     pub reply_markup: Option<ReplyMarkup>,
 }
 
+impl Validate for SendPaidMedia {
+    const METHOD: &'static str = "sendPaidMedia";
+}
+
 /// Use this method to send a group of photos or videos as an album. On success, an array of the sent Messages is returned.
 #[derive(Serialize, Debug, Response)]
 #[response = "Vec<Message>"]
 pub struct SendMediaGroup {
-    /// Optional Unique identifier of the business connection on behalf of which the message will be sent
+    /// Optional. Unique identifier of the business connection on behalf of which the message will be sent
     #[serde(skip_serializing_if = "Option::is_none")]
     pub business_connection_id: Option<String>,
     /// Unique identifier for the target chat or username of the target channel (in the format @channelusername)
@@ -683,11 +869,15 @@ pub struct SendMediaGroup {
     pub reply_parameters: Option<ReplyParameters>,
 }
 
+impl Validate for SendMediaGroup {
+    const METHOD: &'static str = "sendMediaGroup";
+}
+
 /// Use this method to send point on the map. On success, the sent Message is returned.
 #[derive(Serialize, Debug, Response)]
 #[response = "Message"]
 pub struct SendLocation {
-    /// Optional Unique identifier of the business connection on behalf of which the message will be sent
+    /// Optional. Unique identifier of the business connection on behalf of which the message will be sent
     #[serde(skip_serializing_if = "Option::is_none")]
     pub business_connection_id: Option<String>,
     /// Unique identifier for the target chat or username of the target channel (in the format @channelusername)
@@ -728,6 +918,10 @@ pub struct SendLocation {
     pub reply_markup: Option<ReplyMarkup>,
 }
 
+impl Validate for SendLocation {
+    const METHOD: &'static str = "sendLocation";
+}
+
 /// Use this method to edit live location messages sent by the bot or via the bot (for inline bots). A location can be edited until its live_period expires or editing is explicitly disabled by a call to stopMessageLiveLocation. On success, if the edited message was sent by the bot, the edited Message is returned, otherwise True is returned.
 #[derive(Serialize, Debug, Response)]
 #[response = "TrueMessage"]
@@ -735,11 +929,12 @@ pub struct EditMessageLiveLocation {
     /// Optional. Unique identifier of the business connection on behalf of which the message to be edited was sent
     #[serde(skip_serializing_if = "Option::is_none")]
     pub business_connection_id: Option<String>,
-    /// Required if inline_message_id is not specified. Unique identifier for the target chat or username of the target channel (in the format @channelusername)
-    pub chat_id: ChatID,
+    /// Optional. Required if inline_message_id is not specified. Unique identifier for the target chat or username of the target channel (in the format @channelusername)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chat_id: Option<ChatID>,
     /// Optional. Required if inline_message_id is not specified. Identifier of the sent message
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub message_id: Option<Integer>,
+    pub message_id: Option<MessageId>,
     /// Optional. Required if chat_id and message_id are not specified. Identifier of the inline message
     #[serde(skip_serializing_if = "Option::is_none")]
     pub inline_message_id: Option<String>,
@@ -764,6 +959,10 @@ pub struct EditMessageLiveLocation {
     pub reply_markup: Option<InlineKeyboardMarkup>,
 }
 
+impl Validate for EditMessageLiveLocation {
+    const METHOD: &'static str = "editMessageLiveLocation";
+}
+
 /// Use this method to stop updating a live location message sent by the bot or via the bot (for inline bots) before live_period expires. On success, if the message was sent by the bot, the sent Message is returned, otherwise True is returned.
 #[derive(Serialize, Debug, Response)]
 #[response = "TrueMessage"]
@@ -771,11 +970,12 @@ pub struct StopMessageLiveLocation {
     /// Optional. Unique identifier of the business connection on behalf of which the message to be edited was sent
     #[serde(skip_serializing_if = "Option::is_none")]
     pub business_connection_id: Option<String>,
-    /// Required if inline_message_id is not specified. Unique identifier for the target chat or username of the target channel (in the format @channelusername)
-    pub chat_id: ChatID,
+    /// Optional. Required if inline_message_id is not specified. Unique identifier for the target chat or username of the target channel (in the format @channelusername)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chat_id: Option<ChatID>,
     /// Optional. Required if inline_message_id is not specified. Identifier of the sent message
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub message_id: Option<Integer>,
+    pub message_id: Option<MessageId>,
     /// Optional. Required if chat_id and message_id are not specified. Identifier of the inline message
     #[serde(skip_serializing_if = "Option::is_none")]
     pub inline_message_id: Option<String>,
@@ -784,11 +984,15 @@ pub struct StopMessageLiveLocation {
     pub reply_markup: Option<InlineKeyboardMarkup>,
 }
 
+impl Validate for StopMessageLiveLocation {
+    const METHOD: &'static str = "stopMessageLiveLocation";
+}
+
 /// Use this method to send information about a venue. On success, the sent Message is returned.
 #[derive(Serialize, Debug, Response)]
 #[response = "Message"]
 pub struct SendVenue {
-    /// Optional Unique identifier of the business connection on behalf of which the message will be sent
+    /// Optional. Unique identifier of the business connection on behalf of which the message will be sent
     #[serde(skip_serializing_if = "Option::is_none")]
     pub business_connection_id: Option<String>,
     /// Unique identifier for the target chat or username of the target channel (in the format @channelusername)
@@ -833,11 +1037,15 @@ pub struct SendVenue {
     pub reply_markup: Option<ReplyMarkup>,
 }
 
+impl Validate for SendVenue {
+    const METHOD: &'static str = "sendVenue";
+}
+
 /// Use this method to send phone contacts. On success, the sent Message is returned.
 #[derive(Serialize, Debug, Response)]
 #[response = "Message"]
 pub struct SendContact {
-    /// Optional Unique identifier of the business connection on behalf of which the message will be sent
+    /// Optional. Unique identifier of the business connection on behalf of which the message will be sent
     #[serde(skip_serializing_if = "Option::is_none")]
     pub business_connection_id: Option<String>,
     /// Unique identifier for the target chat or username of the target channel (in the format @channelusername)
@@ -872,11 +1080,15 @@ pub struct SendContact {
     pub reply_markup: Option<ReplyMarkup>,
 }
 
+impl Validate for SendContact {
+    const METHOD: &'static str = "sendContact";
+}
+
 /// Use this method to send a native poll. On success, the sent Message is returned.
 #[derive(Serialize, Debug, Response)]
 #[response = "Message"]
 pub struct SendPoll {
-    /// Optional Unique identifier of the business connection on behalf of which the message will be sent
+    /// Optional. Unique identifier of the business connection on behalf of which the message will be sent
     #[serde(skip_serializing_if = "Option::is_none")]
     pub business_connection_id: Option<String>,
     /// Unique identifier for the target chat or username of the target channel (in the format @channelusername)
@@ -888,15 +1100,15 @@ pub struct SendPoll {
     pub question: String,
     /// Optional. Mode for parsing entities in the question. See formatting options for more details. Currently, only custom emoji entities are allowed
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub question_parse_mode: Option<String>,
+    pub question_parse_mode: Option<ParseMode>,
     /// A JSON-serialized list of 2-10 answer options
     pub options: Vec<InputPollOption>,
     /// Optional. True, if the poll needs to be anonymous, defaults to True
     #[serde(skip_serializing_if = "Option::is_none")]
     pub is_anonymous: Option<Boolean>,
-    /// Poll type, “quiz” or “regular”, defaults to “regular”
+    /// Optional. Poll type, “quiz” or “regular”, defaults to “regular”
     #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
-    pub kind: Option<String>,
+    pub kind: Option<PollType>,
     /// Optional. True, if the poll allows multiple answers, ignored for polls in quiz mode, defaults to False
     #[serde(skip_serializing_if = "Option::is_none")]
     pub allows_multiple_answers: Option<Boolean>,
@@ -908,8 +1120,8 @@ pub struct SendPoll {
     pub explanation: Option<String>,
     /// Optional. Mode for parsing entities in the explanation. See formatting options for more details.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub explanation_parse_mode: Option<String>,
-    // List of special entities that appear in the caption, which can be specified instead of parse_mode
+    pub explanation_parse_mode: Option<ParseMode>,
+    /// Optional. A JSON-serialized list of special entities that appear in the quiz explanation, which can be specified instead of explanation_parse_mode
     #[serde(skip_serializing_if = "Option::is_none")]
     pub explanation_entities: Option<Vec<MessageEntity>>,
     /// Optional. Amount of time in seconds the poll will be active after creation, 5-600. Can't be used together with close_date.
@@ -938,11 +1150,15 @@ pub struct SendPoll {
     pub reply_markup: Option<ReplyMarkup>,
 }
 
+impl Validate for SendPoll {
+    const METHOD: &'static str = "sendPoll";
+}
+
 /// Use this method to send an animated emoji that will display a random value. On success, the sent Message is returned.
 #[derive(Serialize, Debug, Response)]
 #[response = "Message"]
 pub struct SendDice {
-    /// Optional Unique identifier of the business connection on behalf of which the message will be sent
+    /// Optional. Unique identifier of the business connection on behalf of which the message will be sent
     #[serde(skip_serializing_if = "Option::is_none")]
     pub business_connection_id: Option<String>,
     /// Unique identifier for the target chat or username of the target channel (in the format @channelusername)
@@ -952,7 +1168,7 @@ pub struct SendDice {
     pub message_thread_id: Option<Integer>,
     /// Optional. Emoji on which the dice throw animation is based. Currently, must be one of “🎲”, “🎯”, “🏀”, “⚽”, “🎳”, or “🎰”. Dice can have values 1-6 for “🎲”, “🎯” and “🎳”, values 1-5 for “🏀” and “⚽”, and values 1-64 for “🎰”. Defaults to “🎲”
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub emoji: Option<String>,
+    pub emoji: Option<DiceEmoji>,
     /// Optional. Sends the message silently. Users will receive a notification with no sound.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub disable_notification: Option<Boolean>,
@@ -970,12 +1186,15 @@ pub struct SendDice {
     pub reply_markup: Option<ReplyMarkup>,
 }
 
-/// Use this method when you need to tell the user that something is happening on the bot's side. The status is set for 5 seconds or less (when a message arrives from your bot, Telegram clients clear its typing status). Returns True on success.
-///    Example: The ImageBot needs some time to process a request and upload the image. Instead of sending a text message along the lines of “Retrieving image, please wait…”, the bot may use sendChatAction with action = upload_photo. The user will see a “sending photo” status for the bot. We only recommend using this method when a response from the bot will take a noticeable amount of time to arrive.
+impl Validate for SendDice {
+    const METHOD: &'static str = "sendDice";
+}
+
+/// Use this method when you need to tell the user that something is happening on the bot's side. The status is set for 5 seconds or less (when a message arrives from your bot, Telegram clients clear its typing status). Returns True on success. Example: The ImageBot needs some time to process a request and upload the image. Instead of sending a text message along the lines of “Retrieving image, please wait…”, the bot may use sendChatAction with action = upload_photo. The user will see a “sending photo” status for the bot. We only recommend using this method when a response from the bot will take a noticeable amount of time to arrive.
 #[derive(Serialize, Debug, Response)]
 #[response = "Boolean"]
 pub struct SendChatAction {
-    /// Optional Unique identifier of the business connection on behalf of which the message will be sent
+    /// Optional. Unique identifier of the business connection on behalf of which the message will be sent
     #[serde(skip_serializing_if = "Option::is_none")]
     pub business_connection_id: Option<String>,
     /// Unique identifier for the target chat or username of the target channel (in the format @channelusername)
@@ -984,7 +1203,11 @@ pub struct SendChatAction {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub message_thread_id: Option<Integer>,
     /// Type of action to broadcast. Choose one, depending on what the user is about to receive: typing for text messages, upload_photo for photos, record_video or upload_video for videos, record_voice or upload_voice for voice notes, upload_document for general files, choose_sticker for stickers, find_location for location data, record_video_note or upload_video_note for video notes.
-    pub action: String,
+    pub action: ChatAction,
+}
+
+impl Validate for SendChatAction {
+    const METHOD: &'static str = "sendChatAction";
 }
 
 /// Use this method to change the chosen reactions on a message. Service messages can't be reacted to. Automatically forwarded messages from a channel to its discussion group have the same available reactions as messages in the channel. Returns True on success.
@@ -994,7 +1217,7 @@ pub struct SetMessageReaction {
     /// Unique identifier for the target chat or username of the target channel (in the format @channelusername)
     pub chat_id: ChatID,
     /// Identifier of the target message. If the message belongs to a media group, the reaction is set to the first non-deleted message in the group instead.
-    pub message_id: Integer,
+    pub message_id: MessageId,
     /// Optional. New list of reaction types to set on the message. Currently, as non-premium users, bots can set up to one reaction per message. A custom emoji reaction can be used if it is either already present on the message or explicitly allowed by chat administrators.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reaction: Option<Vec<ReactionType>>,
@@ -1003,12 +1226,16 @@ pub struct SetMessageReaction {
     pub is_big: Option<Boolean>,
 }
 
+impl Validate for SetMessageReaction {
+    const METHOD: &'static str = "setMessageReaction";
+}
+
 /// Use this method to get a list of profile pictures for a user. Returns a UserProfilePhotos object.
 #[derive(Serialize, Debug, Response)]
 #[response = "UserProfilePhotos"]
 pub struct GetUserProfilePhotos {
     /// Unique identifier of the target user
-    pub user_id: Integer,
+    pub user_id: UserId,
     /// Optional. Sequential number of the first photo to be returned. By default, all photos are returned.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub offset: Option<Integer>,
@@ -1017,12 +1244,20 @@ pub struct GetUserProfilePhotos {
     pub limit: Option<Integer>,
 }
 
+impl Validate for GetUserProfilePhotos {
+    const METHOD: &'static str = "getUserProfilePhotos";
+}
+
 /// Use this method to get basic info about a file and prepare it for downloading. For the moment, bots can download files of up to 20MB in size. On success, a File object is returned. The file can then be downloaded via the link [https://api.telegram.org/file/bot<token>/<file_path>](https://api.telegram.org/file/bot<token>/<file_path>), where <file_path> is taken from the response. It is guaranteed that the link will be valid for at least 1 hour. When the link expires, a new one can be requested by calling getFile again.
 #[derive(Serialize, Debug, Response)]
 #[response = "File"]
 pub struct GetFile {
     /// File identifier to get info about
-    pub file_id: String,
+    pub file_id: FileId,
+}
+
+impl Validate for GetFile {
+    const METHOD: &'static str = "getFile";
 }
 
 /// Use this method to ban a user in a group, a supergroup or a channel. In the case of supergroups and channels, the user will not be able to return to the chat on their own using invite links, etc., unless unbanned first. The bot must be an administrator in the chat for this to work and must have the appropriate admin rights. Returns True on success.
@@ -1032,7 +1267,7 @@ pub struct BanChatMember {
     /// Unique identifier for the target group or username of the target supergroup or channel (in the format @channelusername)
     pub chat_id: ChatID,
     /// Unique identifier of the target user
-    pub user_id: Integer,
+    pub user_id: UserId,
     /// Optional. Date when the user will be unbanned, unix time. If user is banned for more than 366 days or less than 30 seconds from the current time they are considered to be banned forever
     #[serde(skip_serializing_if = "Option::is_none")]
     pub until_date: Option<Integer>,
@@ -1041,6 +1276,10 @@ pub struct BanChatMember {
     pub revoke_messages: Option<Boolean>,
 }
 
+impl Validate for BanChatMember {
+    const METHOD: &'static str = "banChatMember";
+}
+
 /// Use this method to unban a previously kicked user in a supergroup or channel. The user will not return to the group or channel automatically, but will be able to join via link, etc. The bot must be an administrator for this to work. By default, this method guarantees that after the call the user is not a member of the chat, but will be able to join it. So if the user is a member of the chat they will also be removed from the chat. If you don't want this, use the parameter only_if_banned. Returns True on success.
 #[derive(Serialize, Debug, Response)]
 #[response = "Boolean"]
@@ -1048,12 +1287,16 @@ pub struct UnbanChatMember {
     /// Unique identifier for the target group or username of the target supergroup or channel (in the format @username)
     pub chat_id: ChatID,
     /// Unique identifier of the target user
-    pub user_id: Integer,
+    pub user_id: UserId,
     /// Optional. Do nothing if the user is not banned
     #[serde(skip_serializing_if = "Option::is_none")]
     pub only_if_banned: Option<Boolean>,
 }
 
+impl Validate for UnbanChatMember {
+    const METHOD: &'static str = "unbanChatMember";
+}
+
 /// Use this method to restrict a user in a supergroup. The bot must be an administrator in the supergroup for this to work and must have the appropriate admin rights. Pass True for all boolean parameters to lift restrictions from a user. Returns True on success.
 #[derive(Serialize, Debug, Response)]
 #[response = "Boolean"]
@@ -1061,7 +1304,7 @@ pub struct RestrictChatMember {
     /// Unique identifier for the target chat or username of the target supergroup (in the format @supergroupusername)
     pub chat_id: ChatID,
     /// Unique identifier of the target user
-    pub user_id: Integer,
+    pub user_id: UserId,
     /// A JSON-serialized object for new user permissions
     pub permissions: ChatPermissions,
     /// Optional. Pass True if chat permissions are set independently. Otherwise, the can_send_other_messages and can_add_web_page_previews permissions will imply the can_send_messages, can_send_audios, can_send_documents, can_send_photos, can_send_videos, can_send_video_notes, and can_send_voice_notes permissions; the can_send_polls permission will imply the can_send_messages permission.
@@ -1072,6 +1315,10 @@ pub struct RestrictChatMember {
     pub until_date: Option<Integer>,
 }
 
+impl Validate for RestrictChatMember {
+    const METHOD: &'static str = "restrictChatMember";
+}
+
 /// Use this method to promote or demote a user in a supergroup or a channel. The bot must be an administrator in the chat for this to work and must have the appropriate admin rights. Pass False for all boolean parameters to demote a user. Returns True on success.
 #[derive(Serialize, Debug, Response)]
 #[response = "Boolean"]
@@ -1079,7 +1326,7 @@ pub struct PromoteChatMember {
     /// Unique identifier for the target chat or username of the target channel (in the format @channelusername)
     pub chat_id: ChatID,
     /// Unique identifier of the target user
-    pub user_id: Integer,
+    pub user_id: UserId,
     /// Optional. Pass True, if the administrator's presence in the chat is hidden
     #[serde(skip_serializing_if = "Option::is_none")]
     pub is_anonymous: Option<Boolean>,
@@ -1127,6 +1374,10 @@ pub struct PromoteChatMember {
     pub can_manage_topics: Option<Boolean>,
 }
 
+impl Validate for PromoteChatMember {
+    const METHOD: &'static str = "promoteChatMember";
+}
+
 /// Use this method to set a custom title for an administrator in a supergroup promoted by the bot. Returns True on success.
 #[derive(Serialize, Debug, Response)]
 #[response = "Boolean"]
@@ -1134,11 +1385,15 @@ pub struct SetChatAdministratorCustomTitle {
     /// Unique identifier for the target chat or username of the target supergroup (in the format @supergroupusername)
     pub chat_id: ChatID,
     /// Unique identifier of the target user
-    pub user_id: Integer,
+    pub user_id: UserId,
     /// New custom title for the administrator; 0-16 characters, emoji are not allowed
     pub custom_title: String,
 }
 
+impl Validate for SetChatAdministratorCustomTitle {
+    const METHOD: &'static str = "setChatAdministratorCustomTitle";
+}
+
 /// Use this method to ban a channel chat in a supergroup or a channel. Until the chat is unbanned, the owner of the banned chat won't be able to send messages on behalf of any of their channels. The bot must be an administrator in the supergroup or channel for this to work and must have the appropriate administrator rights. Returns True on success.
 #[derive(Serialize, Debug, Response)]
 #[response = "Boolean"]
@@ -1146,7 +1401,11 @@ pub struct BanChatSenderChat {
     /// Unique identifier for the target chat or username of the target channel (in the format @channelusername)
     pub chat_id: ChatID,
     /// Unique identifier of the target sender chat
-    pub sender_chat_id: Integer,
+    pub sender_chat_id: ChatId,
+}
+
+impl Validate for BanChatSenderChat {
+    const METHOD: &'static str = "banChatSenderChat";
 }
 
 /// Use this method to unban a previously banned channel chat in a supergroup or channel. The bot must be an administrator for this to work and must have the appropriate administrator rights. Returns True on success.
@@ -1156,7 +1415,11 @@ pub struct UnbanChatSenderChat {
     /// Unique identifier for the target chat or username of the target channel (in the format @channelusername)
     pub chat_id: ChatID,
     /// Unique identifier of the target sender chat
-    pub sender_chat_id: Integer,
+    pub sender_chat_id: ChatId,
+}
+
+impl Validate for UnbanChatSenderChat {
+    const METHOD: &'static str = "unbanChatSenderChat";
 }
 
 /// Use this method to set default chat permissions for all members. The bot must be an administrator in the group or a supergroup for this to work and must have the can_restrict_members admin rights. Returns True on success.
@@ -1172,6 +1435,10 @@ pub struct SetChatPermissions {
     pub use_independent_chat_permissions: Option<Boolean>,
 }
 
+impl Validate for SetChatPermissions {
+    const METHOD: &'static str = "setChatPermissions";
+}
+
 /// Use this method to generate a new invite link for a chat; any previously generated link is revoked. The bot must be an administrator in the chat for this to work and must have the appropriate admin rights. Returns the new invite link as String on success.
 #[derive(Serialize, Debug, Response)]
 #[response = "String"]
@@ -1180,6 +1447,10 @@ pub struct ExportChatInviteLink {
     pub chat_id: ChatID,
 }
 
+impl Validate for ExportChatInviteLink {
+    const METHOD: &'static str = "exportChatInviteLink";
+}
+
 /// Use this method to create an additional invite link for a chat. The bot must be an administrator in the chat for this to work and must have the appropriate admin rights. The link can be revoked using the method revokeChatInviteLink. Returns the new invite link as ChatInviteLink object.
 #[derive(Serialize, Debug, Response)]
 #[response = "ChatInviteLink"]
@@ -1200,6 +1471,10 @@ pub struct CreateChatInviteLink {
     pub creates_join_request: Option<Boolean>,
 }
 
+impl Validate for CreateChatInviteLink {
+    const METHOD: &'static str = "createChatInviteLink";
+}
+
 /// Use this method to edit a non-primary invite link created by the bot. The bot must be an administrator in the chat for this to work and must have the appropriate admin rights. Returns the edited invite link as a ChatInviteLink object.
 #[derive(Serialize, Debug, Response)]
 #[response = "ChatInviteLink"]
@@ -1222,6 +1497,10 @@ pub struct EditChatInviteLink {
     pub creates_join_request: Option<Boolean>,
 }
 
+impl Validate for EditChatInviteLink {
+    const METHOD: &'static str = "editChatInviteLink";
+}
+
 /// Use this method to revoke an invite link created by the bot. If the primary link is revoked, a new link is automatically generated. The bot must be an administrator in the chat for this to work and must have the appropriate admin rights. Returns the revoked invite link as ChatInviteLink object.
 #[derive(Serialize, Debug, Response)]
 #[response = "ChatInviteLink"]
@@ -1232,6 +1511,10 @@ pub struct RevokeChatInviteLink {
     pub invite_link: String,
 }
 
+impl Validate for RevokeChatInviteLink {
+    const METHOD: &'static str = "revokeChatInviteLink";
+}
+
 /// Use this method to approve a chat join request. The bot must be an administrator in the chat for this to work and must have the can_invite_users administrator right. Returns True on success.
 #[derive(Serialize, Debug, Response)]
 #[response = "Boolean"]
@@ -1239,7 +1522,11 @@ pub struct ApproveChatJoinRequest {
     /// Unique identifier for the target chat or username of the target channel (in the format @channelusername)
     pub chat_id: ChatID,
     /// Unique identifier of the target user
-    pub user_id: Integer,
+    pub user_id: UserId,
+}
+
+impl Validate for ApproveChatJoinRequest {
+    const METHOD: &'static str = "approveChatJoinRequest";
 }
 
 /// Use this method to decline a chat join request. The bot must be an administrator in the chat for this to work and must have the can_invite_users administrator right. Returns True on success.
@@ -1249,7 +1536,11 @@ pub struct DeclineChatJoinRequest {
     /// Unique identifier for the target chat or username of the target channel (in the format @channelusername)
     pub chat_id: ChatID,
     /// Unique identifier of the target user
-    pub user_id: Integer,
+    pub user_id: UserId,
+}
+
+impl Validate for DeclineChatJoinRequest {
+    const METHOD: &'static str = "declineChatJoinRequest";
 }
 
 /// Use this method to set a new profile photo for the chat. Photos can't be changed for private chats. The bot must be an administrator in the chat for this to work and must have the appropriate admin rights. Returns True on success.
@@ -1262,6 +1553,10 @@ pub struct SetChatPhoto {
     pub photo: InputFile,
 }
 
+impl Validate for SetChatPhoto {
+    const METHOD: &'static str = "setChatPhoto";
+}
+
 /// Use this method to delete a chat photo. Photos can't be changed for private chats. The bot must be an administrator in the chat for this to work and must have the appropriate admin rights. Returns True on success.
 #[derive(Serialize, Debug, Response)]
 #[response = "Boolean"]
@@ -1270,6 +1565,10 @@ pub struct DeleteChatPhoto {
     pub chat_id: ChatID,
 }
 
+impl Validate for DeleteChatPhoto {
+    const METHOD: &'static str = "deleteChatPhoto";
+}
+
 /// Use this method to change the title of a chat. Titles can't be changed for private chats. The bot must be an administrator in the chat for this to work and must have the appropriate admin rights. Returns True on success.
 #[derive(Serialize, Debug, Response)]
 #[response = "Boolean"]
@@ -1280,6 +1579,10 @@ pub struct SetChatTitle {
     pub title: String,
 }
 
+impl Validate for SetChatTitle {
+    const METHOD: &'static str = "setChatTitle";
+}
+
 /// Use this method to change the description of a supergroup or a channel. The bot must be an administrator in the chat for this to work and must have the appropriate admin rights. Returns True on success.
 #[derive(Serialize, Debug, Response)]
 #[response = "Boolean"]
@@ -1291,6 +1594,10 @@ pub struct SetChatDescription {
     pub description: Option<String>,
 }
 
+impl Validate for SetChatDescription {
+    const METHOD: &'static str = "setChatDescription";
+}
+
 /// Use this method to pin a message in a supergroup or a channel. The bot must be an administrator in the chat for this to work and must have the ‘can_pin_messages’ admin right in the supergroup or ‘can_edit_messages’ admin right in the channel. Returns True on success.
 #[derive(Serialize, Debug, Response)]
 #[response = "Boolean"]
@@ -1301,12 +1608,16 @@ pub struct PinChatMessage {
     /// Unique identifier for the target chat or username of the target channel (in the format @channelusername)
     pub chat_id: ChatID,
     /// Identifier of a message to pin
-    pub message_id: Integer,
+    pub message_id: MessageId,
     /// Optional. Pass True, if it is not necessary to send a notification to all chat members about the new pinned message. Notifications are always disabled in channels.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub disable_notification: Option<Boolean>,
 }
 
+impl Validate for PinChatMessage {
+    const METHOD: &'static str = "pinChatMessage";
+}
+
 /// Use this method to unpin a message in a supergroup or a channel. The bot must be an administrator in the chat for this to work and must have the ‘can_pin_messages’ admin right in the supergroup or ‘can_edit_messages’ admin right in the channel. Returns True on success.
 #[derive(Serialize, Debug, Response)]
 #[response = "Boolean"]
@@ -1318,7 +1629,11 @@ pub struct UnpinChatMessage {
     pub chat_id: ChatID,
     /// Optional. Identifier of a message to unpin. If not specified, the most recent pinned message (by sending date) will be unpinned.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub message_id: Option<Integer>,
+    pub message_id: Option<MessageId>,
+}
+
+impl Validate for UnpinChatMessage {
+    const METHOD: &'static str = "unpinChatMessage";
 }
 
 /// Use this method to clear the list of pinned messages in a chat. If the chat is not a private chat, the bot must be an administrator in the chat for this to work and must have the 'can_pin_messages' admin right in a supergroup or 'can_edit_messages' admin right in a channel. Returns True on success.
@@ -1329,6 +1644,10 @@ pub struct UnpinAllChatMessages {
     pub chat_id: ChatID,
 }
 
+impl Validate for UnpinAllChatMessages {
+    const METHOD: &'static str = "unpinAllChatMessages";
+}
+
 /// Use this method for your bot to leave a group, supergroup or channel. Returns True on success.
 #[derive(Serialize, Debug, Response)]
 #[response = "Boolean"]
@@ -1337,6 +1656,10 @@ pub struct LeaveChat {
     pub chat_id: ChatID,
 }
 
+impl Validate for LeaveChat {
+    const METHOD: &'static str = "leaveChat";
+}
+
 /// Use this method to get up-to-date information about the chat. Returns a ChatFullInfo object on success.
 #[derive(Serialize, Debug, Response)]
 #[response = "ChatFullInfo"]
@@ -1345,6 +1668,10 @@ pub struct GetChat {
     pub chat_id: ChatID,
 }
 
+impl Validate for GetChat {
+    const METHOD: &'static str = "getChat";
+}
+
 /// Use this method to get a list of administrators in a chat. On success, returns an Array of ChatMember objects that contains information about all chat administrators except other bots. If the chat is a group or a supergroup and no administrators were appointed, only the creator will be returned.
 #[derive(Serialize, Debug, Response)]
 #[response = "Vec<ChatMember>"]
@@ -1353,6 +1680,10 @@ pub struct GetChatAdministrators {
     pub chat_id: ChatID,
 }
 
+impl Validate for GetChatAdministrators {
+    const METHOD: &'static str = "getChatAdministrators";
+}
+
 /// Use this method to get the number of members in a chat. Returns Int on success.
 #[derive(Serialize, Debug, Response)]
 #[response = "Integer"]
@@ -1361,6 +1692,10 @@ pub struct GetChatMemberCount {
     pub chat_id: ChatID,
 }
 
+impl Validate for GetChatMemberCount {
+    const METHOD: &'static str = "getChatMemberCount";
+}
+
 /// Use this method to get information about a member of a chat. Returns a ChatMember object on success.
 #[derive(Serialize, Debug, Response)]
 #[response = "ChatMember"]
@@ -1368,7 +1703,11 @@ pub struct GetChatMember {
     /// Unique identifier for the target chat or username of the target supergroup or channel (in the format @channelusername)
     pub chat_id: ChatID,
     /// Unique identifier of the target user
-    pub user_id: Integer,
+    pub user_id: UserId,
+}
+
+impl Validate for GetChatMember {
+    const METHOD: &'static str = "getChatMember";
 }
 
 /// Use this method to set a new group sticker set for a supergroup. The bot must be an administrator in the chat for this to work and must have the appropriate admin rights. Use the field can_set_sticker_set optionally returned in getChat requests to check if the bot can use this method. Returns True on success.
@@ -1381,6 +1720,10 @@ pub struct SetChatStickerSet {
     pub sticker_set_name: String,
 }
 
+impl Validate for SetChatStickerSet {
+    const METHOD: &'static str = "setChatStickerSet";
+}
+
 /// Use this method to delete a group sticker set from a supergroup. The bot must be an administrator in the chat for this to work and must have the appropriate admin rights. Use the field can_set_sticker_set optionally returned in getChat requests to check if the bot can use this method. Returns True on success.
 #[derive(Serialize, Debug, Response)]
 #[response = "Boolean"]
@@ -1389,14 +1732,20 @@ pub struct DeleteChatStickerSet {
     pub chat_id: ChatID,
 }
 
-/// Use this method to get custom emoji stickers, which can be used as a forum topic icon by any user. Requires no parameters.
-///  Returns an Array of Sticker objects.
+impl Validate for DeleteChatStickerSet {
+    const METHOD: &'static str = "deleteChatStickerSet";
+}
+
+/// Use this method to get custom emoji stickers, which can be used as a forum topic icon by any user. Requires no parameters. Returns an Array of Sticker objects.
 #[derive(Serialize, Debug, Response)]
 #[response = "Vec<Sticker>"]
 pub struct GetForumTopicIconStickers {}
 
-/// Use this method to create a topic in a forum supergroup chat. The bot must be an administrator in the chat for this to work and must have the can_manage_topics administrator rights.
-///  Returns information about the created topic as a ForumTopic object.
+impl Validate for GetForumTopicIconStickers {
+    const METHOD: &'static str = "getForumTopicIconStickers";
+}
+
+/// Use this method to create a topic in a forum supergroup chat. The bot must be an administrator in the chat for this to work and must have the can_manage_topics administrator rights. Returns information about the created topic as a ForumTopic object.
 #[derive(Serialize, Debug, Response)]
 #[response = "ForumTopic"]
 pub struct CreateForumTopic {
@@ -1412,8 +1761,11 @@ pub struct CreateForumTopic {
     pub icon_custom_emoji_id: Option<String>,
 }
 
-/// Use this method to edit name and icon of a topic in a forum supergroup chat. The bot must be an administrator in the chat for this to work and must have can_manage_topics administrator rights, unless it is the creator of the topic.
-/// Returns True on success.
+impl Validate for CreateForumTopic {
+    const METHOD: &'static str = "createForumTopic";
+}
+
+/// Use this method to edit name and icon of a topic in a forum supergroup chat. The bot must be an administrator in the chat for this to work and must have can_manage_topics administrator rights, unless it is the creator of the topic. Returns True on success.
 #[derive(Serialize, Debug, Response)]
 #[response = "Boolean"]
 pub struct EditForumTopic {
@@ -1429,8 +1781,11 @@ pub struct EditForumTopic {
     pub icon_custom_emoji_id: Option<String>,
 }
 
-/// Use this method to close an open topic in a forum supergroup chat. The bot must be an administrator in the chat for this to work and must have the can_manage_topics administrator rights, unless it is the creator of the topic.
-/// Returns True on success.
+impl Validate for EditForumTopic {
+    const METHOD: &'static str = "editForumTopic";
+}
+
+/// Use this method to close an open topic in a forum supergroup chat. The bot must be an administrator in the chat for this to work and must have the can_manage_topics administrator rights, unless it is the creator of the topic. Returns True on success.
 #[derive(Serialize, Debug, Response)]
 #[response = "Boolean"]
 pub struct CloseForumTopic {
@@ -1440,8 +1795,11 @@ pub struct CloseForumTopic {
     pub message_thread_id: Integer,
 }
 
-/// Use this method to reopen a closed topic in a forum supergroup chat. The bot must be an administrator in the chat for this to work and must have the can_manage_topics administrator rights, unless it is the creator of the topic.
-/// Returns True on success.
+impl Validate for CloseForumTopic {
+    const METHOD: &'static str = "closeForumTopic";
+}
+
+/// Use this method to reopen a closed topic in a forum supergroup chat. The bot must be an administrator in the chat for this to work and must have the can_manage_topics administrator rights, unless it is the creator of the topic. Returns True on success.
 #[derive(Serialize, Debug, Response)]
 #[response = "Boolean"]
 pub struct ReopenForumTopic {
@@ -1451,8 +1809,11 @@ pub struct ReopenForumTopic {
     pub message_thread_id: Integer,
 }
 
-/// Use this method to delete a forum topic along with all its messages in a forum supergroup chat. The bot must be an administrator in the chat for this to work and must have the can_delete_messages administrator rights.
-/// Returns True on success.
+impl Validate for ReopenForumTopic {
+    const METHOD: &'static str = "reopenForumTopic";
+}
+
+/// Use this method to delete a forum topic along with all its messages in a forum supergroup chat. The bot must be an administrator in the chat for this to work and must have the can_delete_messages administrator rights. Returns True on success.
 #[derive(Serialize, Debug, Response)]
 #[response = "Boolean"]
 pub struct DeleteForumTopic {
@@ -1462,8 +1823,11 @@ pub struct DeleteForumTopic {
     pub message_thread_id: Integer,
 }
 
-/// Use this method to clear the list of pinned messages in a forum topic. The bot must be an administrator in the chat for this to work and must have the can_pin_messages administrator right in the supergroup.
-/// Returns True on success.
+impl Validate for DeleteForumTopic {
+    const METHOD: &'static str = "deleteForumTopic";
+}
+
+/// Use this method to clear the list of pinned messages in a forum topic. The bot must be an administrator in the chat for this to work and must have the can_pin_messages administrator right in the supergroup. Returns True on success.
 #[derive(Serialize, Debug, Response)]
 #[response = "Boolean"]
 pub struct UnpinAllForumTopicMessages {
@@ -1473,8 +1837,11 @@ pub struct UnpinAllForumTopicMessages {
     pub message_thread_id: Integer,
 }
 
-/// Use this method to edit the name of the 'General' topic in a forum supergroup chat. The bot must be an administrator in the chat for this to work and must have can_manage_topics administrator rights.
-/// Returns True on success.
+impl Validate for UnpinAllForumTopicMessages {
+    const METHOD: &'static str = "unpinAllForumTopicMessages";
+}
+
+/// Use this method to edit the name of the 'General' topic in a forum supergroup chat. The bot must be an administrator in the chat for this to work and must have can_manage_topics administrator rights. Returns True on success.
 #[derive(Serialize, Debug, Response)]
 #[response = "Boolean"]
 pub struct EditGeneralForumTopic {
@@ -1484,8 +1851,11 @@ pub struct EditGeneralForumTopic {
     pub name: String,
 }
 
-/// Use this method to close an open 'General' topic in a forum supergroup chat. The bot must be an administrator in the chat for this to work and must have the can_manage_topics administrator rights.
-/// Returns True on success.
+impl Validate for EditGeneralForumTopic {
+    const METHOD: &'static str = "editGeneralForumTopic";
+}
+
+/// Use this method to close an open 'General' topic in a forum supergroup chat. The bot must be an administrator in the chat for this to work and must have the can_manage_topics administrator rights. Returns True on success.
 #[derive(Serialize, Debug, Response)]
 #[response = "Boolean"]
 pub struct CloseGeneralForumTopic {
@@ -1493,8 +1863,11 @@ pub struct CloseGeneralForumTopic {
     pub chat_id: ChatID,
 }
 
-/// Use this method to reopen a closed 'General' topic in a forum supergroup chat. The bot must be an administrator in the chat for this to work and must have the can_manage_topics administrator rights. The topic will be automatically unhidden if it was hidden.
-/// Returns True on success.
+impl Validate for CloseGeneralForumTopic {
+    const METHOD: &'static str = "closeGeneralForumTopic";
+}
+
+/// Use this method to reopen a closed 'General' topic in a forum supergroup chat. The bot must be an administrator in the chat for this to work and must have the can_manage_topics administrator rights. The topic will be automatically unhidden if it was hidden. Returns True on success.
 #[derive(Serialize, Debug, Response)]
 #[response = "Boolean"]
 pub struct ReopenGeneralForumTopic {
@@ -1502,8 +1875,11 @@ pub struct ReopenGeneralForumTopic {
     pub chat_id: ChatID,
 }
 
-/// Use this method to hide the 'General' topic in a forum supergroup chat. The bot must be an administrator in the chat for this to work and must have the can_manage_topics administrator rights. The topic will be automatically closed if it was open.
-/// Returns True on success.
+impl Validate for ReopenGeneralForumTopic {
+    const METHOD: &'static str = "reopenGeneralForumTopic";
+}
+
+/// Use this method to hide the 'General' topic in a forum supergroup chat. The bot must be an administrator in the chat for this to work and must have the can_manage_topics administrator rights. The topic will be automatically closed if it was open. Returns True on success.
 #[derive(Serialize, Debug, Response)]
 #[response = "Boolean"]
 pub struct HideGeneralForumTopic {
@@ -1511,8 +1887,11 @@ pub struct HideGeneralForumTopic {
     pub chat_id: ChatID,
 }
 
-/// Use this method to unhide the 'General' topic in a forum supergroup chat. The bot must be an administrator in the chat for this to work and must have the can_manage_topics administrator rights.
-/// Returns True on success.
+impl Validate for HideGeneralForumTopic {
+    const METHOD: &'static str = "hideGeneralForumTopic";
+}
+
+/// Use this method to unhide the 'General' topic in a forum supergroup chat. The bot must be an administrator in the chat for this to work and must have the can_manage_topics administrator rights. Returns True on success.
 #[derive(Serialize, Debug, Response)]
 #[response = "Boolean"]
 pub struct UnhideGeneralForumTopic {
@@ -1520,8 +1899,11 @@ pub struct UnhideGeneralForumTopic {
     pub chat_id: ChatID,
 }
 
-/// Use this method to clear the list of pinned messages in a General forum topic. The bot must be an administrator in the chat for this to work and must have the can_pin_messages administrator right in the supergroup.
-/// Returns True on success.
+impl Validate for UnhideGeneralForumTopic {
+    const METHOD: &'static str = "unhideGeneralForumTopic";
+}
+
+/// Use this method to clear the list of pinned messages in a General forum topic. The bot must be an administrator in the chat for this to work and must have the can_pin_messages administrator right in the supergroup. Returns True on success.
 #[derive(Serialize, Debug, Response)]
 #[response = "Boolean"]
 pub struct UnpinAllGeneralForumTopicMessages {
@@ -1529,6 +1911,10 @@ pub struct UnpinAllGeneralForumTopicMessages {
     pub chat_id: ChatID,
 }
 
+impl Validate for UnpinAllGeneralForumTopicMessages {
+    const METHOD: &'static str = "unpinAllGeneralForumTopicMessages";
+}
+
 /// Use this method to send answers to callback queries sent from inline keyboards. The answer will be displayed to the user as a notification at the top of the chat screen or as an alert. On success, True is returned.
 #[derive(Serialize, Debug, Response)]
 #[response = "Boolean"]
@@ -1549,6 +1935,10 @@ pub struct AnswerCallbackQuery {
     pub cache_time: Option<Integer>,
 }
 
+impl Validate for AnswerCallbackQuery {
+    const METHOD: &'static str = "answerCallbackQuery";
+}
+
 /// Use this method to get the list of boosts added to a chat by a user. Requires administrator rights in the chat. Returns a UserChatBoosts object.
 #[derive(Serialize, Debug, Response)]
 #[response = "UserChatBoosts"]
@@ -1556,7 +1946,11 @@ pub struct GetUserChatBoosts {
     /// Unique identifier for the chat or username of the channel (in the format @channelusername)
     pub chat_id: ChatID,
     /// Unique identifier of the target user
-    pub user_id: Integer,
+    pub user_id: UserId,
+}
+
+impl Validate for GetUserChatBoosts {
+    const METHOD: &'static str = "getUserChatBoosts";
 }
 
 /// Use this method to change the list of the bot's commands. See [commands](https://core.telegram.org/bots#commands) for more details about bot commands. Returns True on success.
@@ -1573,6 +1967,10 @@ pub struct SetMyCommands {
     pub language_code: Option<String>,
 }
 
+impl Validate for SetMyCommands {
+    const METHOD: &'static str = "setMyCommands";
+}
+
 /// Use this method to delete the list of the bot's commands for the given scope and user language. After deletion, higher level commands will be shown to affected users. Returns True on success.
 #[derive(Serialize, Debug, Response)]
 #[response = "Boolean"]
@@ -1585,6 +1983,10 @@ pub struct DeleteMyCommands {
     pub language_code: Option<String>,
 }
 
+impl Validate for DeleteMyCommands {
+    const METHOD: &'static str = "deleteMyCommands";
+}
+
 /// Use this method to get the current list of the bot's commands for the given scope and user language. Returns Array of BotCommand on success. If commands aren't set, an empty list is returned.
 #[derive(Serialize, Debug, Response)]
 #[response = "Vec<BotCommand>"]
@@ -1597,6 +1999,10 @@ pub struct GetMyCommands {
     pub language_code: Option<String>,
 }
 
+impl Validate for GetMyCommands {
+    const METHOD: &'static str = "getMyCommands";
+}
+
 /// Use this method to change the bot's name. Returns True on success.
 #[derive(Serialize, Debug, Response)]
 #[response = "Boolean"]
@@ -1609,6 +2015,10 @@ pub struct SetMyName {
     pub language_code: Option<String>,
 }
 
+impl Validate for SetMyName {
+    const METHOD: &'static str = "setMyName";
+}
+
 /// Use this method to get the current bot name for the given user language. Returns BotName on success.
 #[derive(Serialize, Debug, Response)]
 #[response = "BotName"]
@@ -1618,9 +2028,13 @@ pub struct GetMyName {
     pub language_code: Option<String>,
 }
 
+impl Validate for GetMyName {
+    const METHOD: &'static str = "getMyName";
+}
+
 /// Use this method to change the bot's description, which is shown in the chat with the bot if the chat is empty. Returns True on success.
 #[derive(Serialize, Debug, Response)]
-#[response = "Vec<BotCommand>"]
+#[response = "Boolean"]
 pub struct SetMyDescription {
     /// Optional. New bot description; 0-512 characters. Pass an empty string to remove the dedicated description for the given language.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1630,6 +2044,10 @@ pub struct SetMyDescription {
     pub language_code: Option<String>,
 }
 
+impl Validate for SetMyDescription {
+    const METHOD: &'static str = "setMyDescription";
+}
+
 /// Use this method to get the current bot description for the given user language. Returns BotDescription on success.
 #[derive(Serialize, Debug, Response)]
 #[response = "BotDescription"]
@@ -1639,6 +2057,10 @@ pub struct GetMyDescription {
     pub language_code: Option<String>,
 }
 
+impl Validate for GetMyDescription {
+    const METHOD: &'static str = "getMyDescription";
+}
+
 /// Use this method to change the bot's short description, which is shown on the bot's profile page and is sent together with the link when users share the bot. Returns True on success.
 #[derive(Serialize, Debug, Response)]
 #[response = "Boolean"]
@@ -1651,6 +2073,10 @@ pub struct SetMyShortDescription {
     pub language_code: Option<String>,
 }
 
+impl Validate for SetMyShortDescription {
+    const METHOD: &'static str = "setMyShortDescription";
+}
+
 /// Use this method to get the current bot short description for the given user language. Returns BotShortDescription on success.
 #[derive(Serialize, Debug, Response)]
 #[response = "BotShortDescription"]
@@ -1660,28 +2086,40 @@ pub struct GetMyShortDescription {
     pub language_code: Option<String>,
 }
 
+impl Validate for GetMyShortDescription {
+    const METHOD: &'static str = "getMyShortDescription";
+}
+
 /// Use this method to change the bot's menu button in a private chat, or the default menu button. Returns True on success.
 #[derive(Serialize, Debug, Response)]
 #[response = "Boolean"]
 pub struct SetChatMenuButton {
     /// Optional. Unique identifier for the target private chat. If not specified, default bot's menu button will be changed
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub chat_id: Option<Integer>,
+    pub chat_id: Option<ChatId>,
     /// Optional. A JSON-serialized object for the bot's new menu button. Defaults to MenuButtonDefault
     #[serde(skip_serializing_if = "Option::is_none")]
     pub menu_button: Option<MenuButton>,
 }
 
+impl Validate for SetChatMenuButton {
+    const METHOD: &'static str = "setChatMenuButton";
+}
+
 /// Use this method to get the current value of the bot's menu button in a private chat, or the default menu button. Returns MenuButton on success.
 #[derive(Serialize, Debug, Response)]
 #[response = "MenuButton"]
 pub struct GetChatMenuButton {
     /// Optional. Unique identifier for the target private chat. If not specified, default bot's menu button will be returned
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub chat_id: Option<Integer>,
+    pub chat_id: Option<ChatId>,
+}
+
+impl Validate for GetChatMenuButton {
+    const METHOD: &'static str = "getChatMenuButton";
 }
 
-// Use this method to change the default administrator rights requested by the bot when it's added as an administrator to groups or channels. These rights will be suggested to users, but they are are free to modify the list before adding the bot. Returns True on success.
+/// Use this method to change the default administrator rights requested by the bot when it's added as an administrator to groups or channels. These rights will be suggested to users, but they are are free to modify the list before adding the bot. Returns True on success.
 #[derive(Serialize, Debug, Response)]
 #[response = "Boolean"]
 pub struct SetMyDefaultAdministratorRights {
@@ -1693,6 +2131,10 @@ pub struct SetMyDefaultAdministratorRights {
     pub for_channels: Option<Boolean>,
 }
 
+impl Validate for SetMyDefaultAdministratorRights {
+    const METHOD: &'static str = "setMyDefaultAdministratorRights";
+}
+
 /// Use this method to get the current default administrator rights of the bot. Returns ChatAdministratorRights on success.
 #[derive(Serialize, Debug, Response)]
 #[response = "ChatAdministratorRights"]
@@ -1702,6 +2144,10 @@ pub struct GetMyDefaultAdministratorRights {
     pub for_channels: Option<Boolean>,
 }
 
+impl Validate for GetMyDefaultAdministratorRights {
+    const METHOD: &'static str = "getMyDefaultAdministratorRights";
+}
+
 /// Use this method to edit text and game messages sent by the bot or via the bot (for inline bots). On success, if edited message is sent by the bot, the edited Message is returned, otherwise True is returned.
 #[derive(Serialize, Debug, Response)]
 #[response = "TrueMessage"]
@@ -1709,11 +2155,12 @@ pub struct EditMessageText {
     /// Optional. Unique identifier of the business connection on behalf of which the message to be edited was sent
     #[serde(skip_serializing_if = "Option::is_none")]
     pub business_connection_id: Option<String>,
-    /// Required if inline_message_id is not specified. Unique identifier for the target chat or username of the target channel (in the format @channelusername)
-    pub chat_id: ChatID,
+    /// Optional. Required if inline_message_id is not specified. Unique identifier for the target chat or username of the target channel (in the format @channelusername)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chat_id: Option<ChatID>,
     /// Optional. Required if inline_message_id is not specified. Identifier of the sent message
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub message_id: Option<Integer>,
+    pub message_id: Option<MessageId>,
     /// Optional. Required if chat_id and message_id are not specified. Identifier of the inline message
     #[serde(skip_serializing_if = "Option::is_none")]
     pub inline_message_id: Option<String>,
@@ -1721,8 +2168,8 @@ pub struct EditMessageText {
     pub text: String,
     /// Optional. Send Markdown or HTML, if you want Telegram apps to show bold, italic, fixed-width text or inline URLs in your bot's message.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub parse_mode: Option<String>,
-    // List of special entities that appear in the caption, which can be specified instead of parse_mode
+    pub parse_mode: Option<ParseMode>,
+    /// Optional. A JSON-serialized list of special entities that appear in the message text, which can be specified instead of parse_mode
     #[serde(skip_serializing_if = "Option::is_none")]
     pub entities: Option<Vec<MessageEntity>>,
     /// Optional. Link preview generation options for the message
@@ -1733,6 +2180,10 @@ pub struct EditMessageText {
     pub reply_markup: Option<InlineKeyboardMarkup>,
 }
 
+impl Validate for EditMessageText {
+    const METHOD: &'static str = "editMessageText";
+}
+
 /// Use this method to edit captions of messages sent by the bot or via the bot (for inline bots). On success, if edited message is sent by the bot, the edited Message is returned, otherwise True is returned.
 #[derive(Serialize, Debug, Response)]
 #[response = "TrueMessage"]
@@ -1745,7 +2196,7 @@ pub struct EditMessageCaption {
     pub chat_id: Option<ChatID>,
     /// Optional. Required if inline_message_id is not specified. Identifier of the sent message
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub message_id: Option<Integer>,
+    pub message_id: Option<MessageId>,
     /// Optional. Required if chat_id and message_id are not specified. Identifier of the inline message
     #[serde(skip_serializing_if = "Option::is_none")]
     pub inline_message_id: Option<String>,
@@ -1754,8 +2205,8 @@ pub struct EditMessageCaption {
     pub caption: Option<String>,
     /// Optional. Mode for parsing entities in the message caption. See formatting options for more details.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub parse_mode: Option<String>,
-    // List of special entities that appear in the caption, which can be specified instead of parse_mode
+    pub parse_mode: Option<ParseMode>,
+    /// Optional. A JSON-serialized list of special entities that appear in the caption, which can be specified instead of parse_mode
     #[serde(skip_serializing_if = "Option::is_none")]
     pub caption_entities: Option<Vec<MessageEntity>>,
     /// Optional. True, if the caption must be shown above the message media
@@ -1766,6 +2217,10 @@ pub struct EditMessageCaption {
     pub reply_markup: Option<InlineKeyboardMarkup>,
 }
 
+impl Validate for EditMessageCaption {
+    const METHOD: &'static str = "editMessageCaption";
+}
+
 /// Use this method to edit audio, document, photo, or video messages. If a message is a part of a message album, then it can be edited only to a photo or a video. Otherwise, message type can be changed arbitrarily. When inline message is edited, new file can't be uploaded. Use previously uploaded file via its file_id or specify a URL. On success, if the edited message was sent by the bot, the edited Message is returned, otherwise True is returned.
 #[derive(Serialize, Debug, Response)]
 #[response = "TrueMessage"]
@@ -1778,7 +2233,7 @@ pub struct EditMessageMedia {
     pub chat_id: Option<ChatID>,
     /// Optional. Required if inline_message_id is not specified. Identifier of the sent message
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub message_id: Option<Integer>,
+    pub message_id: Option<MessageId>,
     /// Optional. Required if chat_id and message_id are not specified. Identifier of the inline message
     #[serde(skip_serializing_if = "Option::is_none")]
     pub inline_message_id: Option<String>,
@@ -1789,6 +2244,10 @@ pub struct EditMessageMedia {
     pub reply_markup: Option<InlineKeyboardMarkup>,
 }
 
+impl Validate for EditMessageMedia {
+    const METHOD: &'static str = "editMessageMedia";
+}
+
 /// Use this method to edit only the reply markup of messages sent by the bot or via the bot (for inline bots). On success, if edited message is sent by the bot, the edited Message is returned, otherwise True is returned.
 #[derive(Serialize, Debug, Response)]
 #[response = "TrueMessage"]
@@ -1796,11 +2255,12 @@ pub struct EditMessageReplyMarkup {
     /// Optional. Unique identifier of the business connection on behalf of which the message to be edited was sent
     #[serde(skip_serializing_if = "Option::is_none")]
     pub business_connection_id: Option<String>,
-    /// Required if inline_message_id is not specified. Unique identifier for the target chat or username of the target channel (in the format @channelusername)
-    pub chat_id: ChatID,
+    /// Optional. Required if inline_message_id is not specified. Unique identifier for the target chat or username of the target channel (in the format @channelusername)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chat_id: Option<ChatID>,
     /// Optional. Required if inline_message_id is not specified. Identifier of the sent message
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub message_id: Option<Integer>,
+    pub message_id: Option<MessageId>,
     /// Optional. Required if chat_id and message_id are not specified. Identifier of the inline message
     #[serde(skip_serializing_if = "Option::is_none")]
     pub inline_message_id: Option<String>,
@@ -1809,6 +2269,10 @@ pub struct EditMessageReplyMarkup {
     pub reply_markup: Option<InlineKeyboardMarkup>,
 }
 
+impl Validate for EditMessageReplyMarkup {
+    const METHOD: &'static str = "editMessageReplyMarkup";
+}
+
 /// Use this method to stop a poll which was sent by the bot. On success, the stopped Poll with the final results is returned.
 #[derive(Serialize, Debug, Response)]
 #[response = "Poll"]
@@ -1819,29 +2283,28 @@ pub struct StopPoll {
     /// Unique identifier for the target chat or username of the target channel (in the format @channelusername)
     pub chat_id: ChatID,
     /// Identifier of the original message with the poll
-    pub message_id: Integer,
+    pub message_id: MessageId,
     /// Optional. A JSON-serialized object for an inline keyboard.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_markup: Option<InlineKeyboardMarkup>,
 }
 
-/// Use this method to delete a message, including service messages, with the following limitations:
-/// - A message can only be deleted if it was sent less than 48 hours ago.
-/// - Service messages about a supergroup, channel, or forum topic creation can't be deleted.
-/// - A dice message in a private chat can only be deleted if it was sent more than 24 hours ago.
-/// - Bots can delete outgoing messages in private chats, groups, and supergroups.
-/// - Bots can delete incoming messages in private chats.
-/// - Bots granted can_post_messages permissions can delete outgoing messages in channels.
-/// - If the bot is an administrator of a group, it can delete any message there.
-/// - If the bot has can_delete_messages permission in a supergroup or a channel, it can delete any message there.
-///   Returns True on success.
+impl Validate for StopPoll {
+    const METHOD: &'static str = "stopPoll";
+}
+
+/// Use this method to delete a message, including service messages, with the following limitations: - A message can only be deleted if it was sent less than 48 hours ago. - Service messages about a supergroup, channel, or forum topic creation can't be deleted. - A dice message in a private chat can only be deleted if it was sent more than 24 hours ago. - Bots can delete outgoing messages in private chats, groups, and supergroups. - Bots can delete incoming messages in private chats. - Bots granted can_post_messages permissions can delete outgoing messages in channels. - If the bot is an administrator of a group, it can delete any message there. - If the bot has can_delete_messages permission in a supergroup or a channel, it can delete any message there. Returns True on success.
 #[derive(Serialize, Debug, Response)]
 #[response = "Boolean"]
 pub struct DeleteMessage {
     /// Unique identifier for the target chat or username of the target channel (in the format @channelusername)
     pub chat_id: ChatID,
     /// Identifier of the message to delete
-    pub message_id: Integer,
+    pub message_id: MessageId,
+}
+
+impl Validate for DeleteMessage {
+    const METHOD: &'static str = "deleteMessage";
 }
 
 /// Use this method to delete multiple messages simultaneously. If some of the specified messages can't be found, they are skipped. Returns True on success.
@@ -1851,14 +2314,18 @@ pub struct DeleteMessages {
     /// Unique identifier for the target chat or username of the target channel (in the format @channelusername)
     pub chat_id: ChatID,
     /// Identifiers of 1-100 messages to delete. See deleteMessage for limitations on which messages can be deleted
-    pub message_ids: Vec<Integer>,
+    pub message_ids: Vec<MessageId>,
+}
+
+impl Validate for DeleteMessages {
+    const METHOD: &'static str = "deleteMessages";
 }
 
 /// Use this method to send .webp stickers. On success, the sent Message is returned.
 #[derive(Serialize, Debug, Response)]
 #[response = "Message"]
 pub struct SendSticker {
-    /// Optional Unique identifier of the business connection on behalf of which the message will be sent
+    /// Optional. Unique identifier of the business connection on behalf of which the message will be sent
     #[serde(skip_serializing_if = "Option::is_none")]
     pub business_connection_id: Option<String>,
     /// Unique identifier for the target chat or username of the target channel (in the format @channelusername)
@@ -1888,6 +2355,10 @@ pub struct SendSticker {
     pub reply_markup: Option<ReplyMarkup>,
 }
 
+impl Validate for SendSticker {
+    const METHOD: &'static str = "sendSticker";
+}
+
 /// Use this method to get a sticker set. On success, a StickerSet object is returned.
 #[derive(Serialize, Debug, Response)]
 #[response = "StickerSet"]
@@ -1896,6 +2367,10 @@ pub struct GetStickerSet {
     pub name: String,
 }
 
+impl Validate for GetStickerSet {
+    const METHOD: &'static str = "getStickerSet";
+}
+
 /// Use this method to get information about custom emoji stickers by their identifiers. Returns an Array of Sticker objects.
 #[derive(Serialize, Debug, Response)]
 #[response = "Vec<Sticker>"]
@@ -1904,16 +2379,24 @@ pub struct GetCustomEmojiStickers {
     pub custom_emoji_ids: Vec<String>,
 }
 
+impl Validate for GetCustomEmojiStickers {
+    const METHOD: &'static str = "getCustomEmojiStickers";
+}
+
 /// Use this method to upload a file with a sticker for later use in the createNewStickerSet and addStickerToSet methods (the file can be used multiple times). Returns the uploaded File on success.
 #[derive(Serialize, Debug, Response)]
 #[response = "File"]
 pub struct UploadStickerFile {
     /// User identifier of sticker file owner
-    pub user_id: Integer,
+    pub user_id: UserId,
     /// A file with the sticker in .WEBP, .PNG, .TGS, or .WEBM format. See https://core.telegram.org/stickers for technical requirements. More information on Sending Files »
     pub sticker: InputFile,
     /// Format of the sticker, must be one of “static”, “animated”, “video”
-    pub sticker_format: String,
+    pub sticker_format: StickerFormat,
+}
+
+impl Validate for UploadStickerFile {
+    const METHOD: &'static str = "uploadStickerFile";
 }
 
 /// Use this method to create a new sticker set owned by a user. The bot will be able to edit the sticker set thus created. Returns True on success.
@@ -1921,7 +2404,7 @@ pub struct UploadStickerFile {
 #[response = "Boolean"]
 pub struct CreateNewStickerSet {
     /// User identifier of created sticker set owner
-    pub user_id: Integer,
+    pub user_id: UserId,
     /// Short name of sticker set, to be used in t.me/addstickers/ URLs (e.g., animals). Can contain only English letters, digits and underscores. Must begin with a letter, can't contain consecutive underscores and must end in "_by_<bot_username>". <bot_username> is case insensitive. 1-64 characters.
     pub name: String,
     /// Sticker set title, 1-64 characters
@@ -1930,24 +2413,32 @@ pub struct CreateNewStickerSet {
     pub stickers: Vec<InputSticker>,
     /// Optional. Type of stickers in the set, pass “regular”, “mask”, or “custom_emoji”. By default, a regular sticker set is created.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub sticker_type: Option<String>,
+    pub sticker_type: Option<StickerType>,
     /// Optional. Pass True if stickers in the sticker set must be repainted to the color of text when used in messages, the accent color if used as emoji status, white on chat photos, or another appropriate color based on context; for custom emoji sticker sets only
     #[serde(skip_serializing_if = "Option::is_none")]
     pub needs_repainting: Option<Boolean>,
 }
 
+impl Validate for CreateNewStickerSet {
+    const METHOD: &'static str = "createNewStickerSet";
+}
+
 /// Use this method to add a new sticker to a set created by the bot. Returns True on success.
 #[derive(Serialize, Debug, Response)]
 #[response = "Boolean"]
 pub struct AddStickerToSet {
     /// User identifier of sticker set owner
-    pub user_id: Integer,
+    pub user_id: UserId,
     /// Sticker set name
     pub name: String,
     /// A JSON-serialized object with information about the added sticker. If exactly the same sticker had already been added to the set, then the set isn't changed.
     pub sticker: InputSticker,
 }
 
+impl Validate for AddStickerToSet {
+    const METHOD: &'static str = "addStickerToSet";
+}
+
 /// Use this method to move a sticker in a set created by the bot to a specific position . Returns True on success.
 #[derive(Serialize, Debug, Response)]
 #[response = "Boolean"]
@@ -1958,6 +2449,10 @@ pub struct SetStickerPositionInSet {
     pub position: Integer,
 }
 
+impl Validate for SetStickerPositionInSet {
+    const METHOD: &'static str = "setStickerPositionInSet";
+}
+
 /// Use this method to delete a sticker from a set created by the bot. Returns True on success.
 #[derive(Serialize, Debug, Response)]
 #[response = "Boolean"]
@@ -1966,12 +2461,16 @@ pub struct DeleteStickerFromSet {
     pub sticker: String,
 }
 
+impl Validate for DeleteStickerFromSet {
+    const METHOD: &'static str = "deleteStickerFromSet";
+}
+
 /// Use this method to replace an existing sticker in a sticker set with a new one. The method is equivalent to calling deleteStickerFromSet, then addStickerToSet, then setStickerPositionInSet. Returns True on success.
 #[derive(Serialize, Debug, Response)]
 #[response = "Boolean"]
 pub struct ReplaceStickerInSet {
     /// User identifier of the sticker set owner
-    pub user_id: Integer,
+    pub user_id: UserId,
     /// Sticker set name
     pub name: String,
     /// File identifier of the replaced sticker
@@ -1980,6 +2479,10 @@ pub struct ReplaceStickerInSet {
     pub sticker: InputSticker,
 }
 
+impl Validate for ReplaceStickerInSet {
+    const METHOD: &'static str = "replaceStickerInSet";
+}
+
 /// Use this method to change the list of emoji assigned to a regular or custom emoji sticker. The sticker must belong to a sticker set created by the bot. Returns True on success.
 #[derive(Serialize, Debug, Response)]
 #[response = "Boolean"]
@@ -1990,6 +2493,10 @@ pub struct SetStickerEmojiList {
     pub emoji_list: Vec<String>,
 }
 
+impl Validate for SetStickerEmojiList {
+    const METHOD: &'static str = "setStickerEmojiList";
+}
+
 /// Use this method to change search keywords assigned to a regular or custom emoji sticker. The sticker must belong to a sticker set created by the bot. Returns True on success.
 #[derive(Serialize, Debug, Response)]
 #[response = "Boolean"]
@@ -2001,6 +2508,10 @@ pub struct SetStickerKeywords {
     pub keywords: Option<Vec<String>>,
 }
 
+impl Validate for SetStickerKeywords {
+    const METHOD: &'static str = "setStickerKeywords";
+}
+
 /// Use this method to change the mask position of a mask sticker. The sticker must belong to a sticker set that was created by the bot. Returns True on success.
 #[derive(Serialize, Debug, Response)]
 #[response = "Boolean"]
@@ -2012,6 +2523,10 @@ pub struct SetStickerMaskPosition {
     pub mask_position: Option<MaskPosition>,
 }
 
+impl Validate for SetStickerMaskPosition {
+    const METHOD: &'static str = "setStickerMaskPosition";
+}
+
 /// Use this method to set the title of a created sticker set. Returns True on success.
 #[derive(Serialize, Debug, Response)]
 #[response = "Boolean"]
@@ -2022,6 +2537,10 @@ pub struct SetStickerSetTitle {
     pub title: String,
 }
 
+impl Validate for SetStickerSetTitle {
+    const METHOD: &'static str = "setStickerSetTitle";
+}
+
 /// Use this method to set the thumbnail of a sticker set. Animated thumbnails can be set for animated sticker sets only. Returns True on success.
 #[derive(Serialize, Debug, Response)]
 #[response = "Boolean"]
@@ -2029,12 +2548,16 @@ pub struct SetStickerSetThumbnail {
     /// Sticker set name
     pub name: String,
     /// User identifier of the sticker set owner
-    pub user_id: Integer,
+    pub user_id: UserId,
     /// Optional. A PNG image with the thumbnail, must be up to 128 kilobytes in size and have width and height exactly 100px, or a TGS animation with the thumbnail up to 32 kilobytes in size; see [technical-requirements](https://core.telegram.org/animated_stickers#technical-requirements) for animated sticker technical requirements. Pass a file_id as a String to send a file that already exists on the Telegram servers, pass an HTTP URL as a String for Telegram to get a file from the Internet, or upload a new one using multipart/form-data. More info on Sending Files ». Animated sticker set thumbnail can't be uploaded via HTTP URL.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub thumbnail: Option<InputFileString>,
-    /// Yes Format of the thumbnail, must be one of “static” for a .WEBP or .PNG image, “animated” for a .TGS animation, or “video” for a WEBM video
-    pub format: String,
+    /// Format of the thumbnail, must be one of “static” for a .WEBP or .PNG image, “animated” for a .TGS animation, or “video” for a WEBM video
+    pub format: StickerFormat,
+}
+
+impl Validate for SetStickerSetThumbnail {
+    const METHOD: &'static str = "setStickerSetThumbnail";
 }
 
 /// Use this method to set the thumbnail of a custom emoji sticker set. Returns True on success.
@@ -2048,6 +2571,10 @@ pub struct SetCustomEmojiStickerSetThumbnail {
     pub custom_emoji_id: Option<String>,
 }
 
+impl Validate for SetCustomEmojiStickerSetThumbnail {
+    const METHOD: &'static str = "setCustomEmojiStickerSetThumbnail";
+}
+
 /// Use this method to delete a sticker set that was created by the bot. Returns True on success.
 #[derive(Serialize, Debug, Response)]
 #[response = "Boolean"]
@@ -2056,6 +2583,10 @@ pub struct DeleteStickerSet {
     pub name: String,
 }
 
+impl Validate for DeleteStickerSet {
+    const METHOD: &'static str = "deleteStickerSet";
+}
+
 /// Use this method to send answers to an inline query. On success, True is returned. No more than 50 results per query are allowed.
 #[derive(Serialize, Debug, Response)]
 #[response = "Boolean"]
@@ -2078,6 +2609,10 @@ pub struct AnswerInlineQuery {
     pub button: Option<InlineQueryResultsButton>,
 }
 
+impl Validate for AnswerInlineQuery {
+    const METHOD: &'static str = "answerInlineQuery";
+}
+
 /// Use this method to set the result of an interaction with a [Web App](https://core.telegram.org/bots/webapps) and send a corresponding message on behalf of the user to the chat from which the query originated. On success, a SentWebAppMessage object is returned.
 #[derive(Serialize, Debug, Response)]
 #[response = "SentWebAppMessage"]
@@ -2088,12 +2623,16 @@ pub struct AnswerWebAppQuery {
     pub result: InlineQueryResult,
 }
 
+impl Validate for AnswerWebAppQuery {
+    const METHOD: &'static str = "answerWebAppQuery";
+}
+
 /// Use this method to send invoices. On success, the sent Message is returned.
 #[derive(Serialize, Debug, Response)]
 #[response = "Message"]
 pub struct SendInvoice {
     /// Unique identifier for the target private chat
-    pub chat_id: Integer,
+    pub chat_id: ChatId,
     /// Optional. Unique identifier for the target message thread (topic) of the forum; for forum supergroups only
     #[serde(skip_serializing_if = "Option::is_none")]
     pub message_thread_id: Option<Integer>,
@@ -2172,6 +2711,10 @@ pub struct SendInvoice {
     pub reply_markup: Option<InlineKeyboardMarkup>,
 }
 
+impl Validate for SendInvoice {
+    const METHOD: &'static str = "sendInvoice";
+}
+
 /// Use this method to create a link for an invoice. Returns the created invoice link as String on success.
 #[derive(Serialize, Debug, Response)]
 #[response = "String"]
@@ -2233,6 +2776,10 @@ pub struct CreateInvoiceLink {
     pub is_flexible: Option<Boolean>,
 }
 
+impl Validate for CreateInvoiceLink {
+    const METHOD: &'static str = "createInvoiceLink";
+}
+
 /// If you sent an invoice requesting a shipping address and the parameter is_flexible was specified, the Bot API will send an Update with a shipping_query field to the bot. Use this method to reply to shipping queries. On success, True is returned.
 #[derive(Serialize, Debug, Response)]
 #[response = "Boolean"]
@@ -2249,6 +2796,10 @@ pub struct AnswerShippingQuery {
     pub error_message: Option<String>,
 }
 
+impl Validate for AnswerShippingQuery {
+    const METHOD: &'static str = "answerShippingQuery";
+}
+
 /// Once the user has confirmed their payment and shipping details, the Bot API sends the final confirmation in the form of an Update with the field pre_checkout_query. Use this method to respond to such pre-checkout queries. On success, True is returned. Note: The Bot API must receive an answer within 10 seconds after the pre-checkout query was sent.
 #[derive(Serialize, Debug, Response)]
 #[response = "Boolean"]
@@ -2262,6 +2813,10 @@ pub struct AnswerPreCheckoutQuery {
     pub error_message: Option<String>,
 }
 
+impl Validate for AnswerPreCheckoutQuery {
+    const METHOD: &'static str = "answerPreCheckoutQuery";
+}
+
 /// Returns the bot's Telegram Star transactions in chronological order. On success, returns a StarTransactions object.
 #[derive(Serialize, Debug, Response)]
 #[response = "StarTransactions"]
@@ -2274,39 +2829,47 @@ pub struct GetStarTransactions {
     pub limit: Option<Integer>,
 }
 
+impl Validate for GetStarTransactions {
+    const METHOD: &'static str = "getStarTransactions";
+}
+
 /// Refunds a successful payment in Telegram Stars. Returns True on success.
 #[derive(Serialize, Debug, Response)]
 #[response = "Boolean"]
 pub struct RefundStarPayment {
-    /// Yes Identifier of the user whose payment will be refunded
-    pub user_id: Integer,
-    /// Yes Telegram payment identifier
+    /// Identifier of the user whose payment will be refunded
+    pub user_id: UserId,
+    /// Telegram payment identifier
     pub telegram_payment_charge_id: String,
 }
 
-// ------------------ PASSPORT -----------------
+impl Validate for RefundStarPayment {
+    const METHOD: &'static str = "refundStarPayment";
+}
 
 /// Informs a user that some of the Telegram Passport elements they provided contains errors. The user will not be able to re-submit their Passport to you until the errors are fixed (the contents of the field for which you returned the error must change). Returns True on success. Use this if the data submitted by the user doesn't satisfy the standards your service requires for any reason. For example, if a birthday date seems invalid, a submitted document is blurry, a scan shows evidence of tampering, etc. Supply some details in the error message to make sure the user knows how to correct the issues.
 #[derive(Serialize, Debug, Response)]
 #[response = "Boolean"]
 pub struct SetPassportDataErrors {
     /// User identifier
-    pub user_id: Integer,
+    pub user_id: UserId,
     /// A JSON-serialized array describing the errors
     pub errors: Vec<PassportElementError>,
 }
 
-// ------------------ GAMES -------------------
+impl Validate for SetPassportDataErrors {
+    const METHOD: &'static str = "setPassportDataErrors";
+}
 
 /// Use this method to send a game. On success, the sent Message is returned.
 #[derive(Serialize, Debug, Response)]
 #[response = "Message"]
 pub struct SendGame {
-    /// Optional Unique identifier of the business connection on behalf of which the message will be sent
+    /// Optional. Unique identifier of the business connection on behalf of which the message will be sent
     #[serde(skip_serializing_if = "Option::is_none")]
     pub business_connection_id: Option<String>,
     /// Unique identifier for the target chat
-    pub chat_id: Integer,
+    pub chat_id: ChatId,
     /// Optional. Unique identifier for the target message thread (topic) of the forum; for forum supergroups only
     #[serde(skip_serializing_if = "Option::is_none")]
     pub message_thread_id: Option<Integer>,
@@ -2329,14 +2892,18 @@ pub struct SendGame {
     pub reply_markup: Option<InlineKeyboardMarkup>,
 }
 
+impl Validate for SendGame {
+    const METHOD: &'static str = "sendGame";
+}
+
 /// Use this method to set the score of the specified user in a game. On success, if the message was sent by the bot, returns the edited Message, otherwise returns True. Returns an error, if the new score is not greater than the user's current score in the chat and force is False.
 #[derive(Serialize, Debug, Response)]
 #[response = "TrueMessage"]
 pub struct SetGameScore {
     /// User identifier
-    user_id: Integer,
+    pub user_id: UserId,
     /// New score, must be non-negative
-    score: Integer,
+    pub score: Integer,
     /// Optional. Pass True, if the high score is allowed to decrease. This can be useful when fixing mistakes or banning cheaters
     #[serde(skip_serializing_if = "Option::is_none")]
     pub force: Option<Boolean>,
@@ -2345,28 +2912,102 @@ pub struct SetGameScore {
     pub disable_edit_message: Option<Boolean>,
     /// Optional. Required if inline_message_id is not specified. Unique identifier for the target chat
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub chat_id: Option<Integer>,
+    pub chat_id: Option<ChatId>,
     /// Optional. Required if inline_message_id is not specified. Identifier of the sent message
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub message_id: Option<Integer>,
+    pub message_id: Option<MessageId>,
     /// Optional. Required if chat_id and message_id are not specified. Identifier of the inline message
     #[serde(skip_serializing_if = "Option::is_none")]
     pub inline_message_id: Option<String>,
 }
 
+impl Validate for SetGameScore {
+    const METHOD: &'static str = "setGameScore";
+}
+
 /// Use this method to get data for high score tables. Will return the score of the specified user and several of their neighbors in a game. On success, returns an Array of GameHighScore objects. This method will currently return scores for the target user, plus two of their closest neighbors on each side. Will also return the top three users if the user and his neighbors are not among them. Please note that this behavior is subject to change.
 #[derive(Serialize, Debug, Response)]
 #[response = "Vec<GameHighScore>"]
 pub struct GetGameHighScores {
     /// Target user id
-    user_id: Integer,
+    pub user_id: UserId,
     /// Optional. Required if inline_message_id is not specified. Unique identifier for the target chat
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub chat_id: Option<Integer>,
+    pub chat_id: Option<ChatId>,
     /// Optional. Required if inline_message_id is not specified. Identifier of the sent message
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub message_id: Option<Integer>,
+    pub message_id: Option<MessageId>,
     /// Optional. Required if chat_id and message_id are not specified. Identifier of the inline message
     #[serde(skip_serializing_if = "Option::is_none")]
     pub inline_message_id: Option<String>,
 }
+
+impl Validate for GetGameHighScores {
+    const METHOD: &'static str = "getGameHighScores";
+}
+
+/// Limits of the parameters of the methods, checked before a request is sent
+#[rustfmt::skip]
+pub(crate) const LIMITS: &[(&str, &str, Limit)] = &[
+    ("getUpdates", "limit", Limit::Range(1, 100)),
+    ("sendMessage", "text", Limit::Length(1, 4096)),
+    ("sendMessage", "reply_markup", Limit::InlineKeyboard),
+    ("forwardMessages", "message_ids", Limit::Items(1, 100)),
+    ("forwardMessages", "message_ids", Limit::Increasing),
+    ("copyMessage", "caption", Limit::Length(0, 1024)),
+    ("copyMessage", "reply_markup", Limit::InlineKeyboard),
+    ("copyMessages", "message_ids", Limit::Items(1, 100)),
+    ("copyMessages", "message_ids", Limit::Increasing),
+    ("sendPhoto", "caption", Limit::Length(0, 1024)),
+    ("sendPhoto", "reply_markup", Limit::InlineKeyboard),
+    ("sendAudio", "caption", Limit::Length(0, 1024)),
+    ("sendAudio", "reply_markup", Limit::InlineKeyboard),
+    ("sendDocument", "caption", Limit::Length(0, 1024)),
+    ("sendDocument", "reply_markup", Limit::InlineKeyboard),
+    ("sendVideo", "caption", Limit::Length(0, 1024)),
+    ("sendVideo", "reply_markup", Limit::InlineKeyboard),
+    ("sendAnimation", "caption", Limit::Length(0, 1024)),
+    ("sendAnimation", "reply_markup", Limit::InlineKeyboard),
+    ("sendVoice", "caption", Limit::Length(0, 1024)),
+    ("sendVoice", "reply_markup", Limit::InlineKeyboard),
+    ("sendVideoNote", "reply_markup", Limit::InlineKeyboard),
+    ("sendPaidMedia", "media", Limit::Items(1, 10)),
+    ("sendPaidMedia", "caption", Limit::Length(0, 1024)),
+    ("sendPaidMedia", "reply_markup", Limit::InlineKeyboard),
+    ("sendMediaGroup", "media", Limit::Items(2, 10)),
+    ("sendLocation", "reply_markup", Limit::InlineKeyboard),
+    ("editMessageLiveLocation", "reply_markup", Limit::InlineKeyboard),
+    ("stopMessageLiveLocation", "reply_markup", Limit::InlineKeyboard),
+    ("sendVenue", "reply_markup", Limit::InlineKeyboard),
+    ("sendContact", "reply_markup", Limit::InlineKeyboard),
+    ("sendPoll", "question", Limit::Length(1, 300)),
+    ("sendPoll", "options", Limit::Items(2, 10)),
+    ("sendPoll", "explanation", Limit::Length(0, 200)),
+    ("sendPoll", "open_period", Limit::Range(5, 600)),
+    ("sendPoll", "reply_markup", Limit::InlineKeyboard),
+    ("sendDice", "reply_markup", Limit::InlineKeyboard),
+    ("createChatInviteLink", "name", Limit::Length(0, 32)),
+    ("createChatInviteLink", "member_limit", Limit::Range(1, 99999)),
+    ("editChatInviteLink", "name", Limit::Length(0, 32)),
+    ("editChatInviteLink", "member_limit", Limit::Range(1, 99999)),
+    ("setChatTitle", "title", Limit::Length(1, 128)),
+    ("setChatDescription", "description", Limit::Length(0, 255)),
+    ("answerCallbackQuery", "text", Limit::Length(0, 200)),
+    ("setMyCommands", "commands", Limit::Items(0, 100)),
+    ("setMyCommands", "commands", Limit::Commands),
+    ("setMyName", "name", Limit::Length(0, 64)),
+    ("setMyDescription", "description", Limit::Length(0, 512)),
+    ("setMyShortDescription", "short_description", Limit::Length(0, 120)),
+    ("editMessageText", "text", Limit::Length(1, 4096)),
+    ("editMessageText", "reply_markup", Limit::InlineKeyboard),
+    ("editMessageCaption", "caption", Limit::Length(0, 1024)),
+    ("editMessageCaption", "reply_markup", Limit::InlineKeyboard),
+    ("editMessageMedia", "reply_markup", Limit::InlineKeyboard),
+    ("editMessageReplyMarkup", "reply_markup", Limit::InlineKeyboard),
+    ("stopPoll", "reply_markup", Limit::InlineKeyboard),
+    ("deleteMessages", "message_ids", Limit::Items(1, 100)),
+    ("sendSticker", "reply_markup", Limit::InlineKeyboard),
+    ("answerInlineQuery", "results", Limit::Items(0, 50)),
+    ("sendInvoice", "reply_markup", Limit::InlineKeyboard),
+    ("sendGame", "reply_markup", Limit::InlineKeyboard),
+];
@@ -1,6 +1,9 @@
+use std::{fmt::Display, str::FromStr};
+
 use serde::{Deserialize, Serialize};
+use thiserror::Error;
 
-use crate::types::{ChatID, Integer};
+use crate::{bot::SetMyCommands, ids::UserId, types::ChatID};
 
 /// This object represents a bot command.
 #[derive(Clone, Serialize, Deserialize, Debug)]
@@ -9,56 +12,257 @@ pub struct BotCommand {
     pub description: String,
 }
 
+pub use rutel_macros::BotCommands;
+
+/// An error returned when a message text can't be parsed as a command of a `BotCommands` enum.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum CommandParseError {
+    #[error("text is not a bot command")]
+    NotACommand,
+    #[error("command is addressed to another bot: @{0}")]
+    WrongBotName(String),
+    #[error("unknown command: /{0}")]
+    UnknownCommand(String),
+    #[error("too few arguments: expected {expected}, found {found}")]
+    TooFewArguments { expected: usize, found: usize },
+    #[error("too many arguments: expected {expected}, found {found}")]
+    TooManyArguments { expected: usize, found: usize },
+    #[error("invalid argument {argument:?}: {reason}")]
+    InvalidArgument { argument: String, reason: String },
+}
+
+/// A set of bot commands, usually derived with `#[derive(BotCommands)]` on an enum.
+///
+/// Every variant is a command named after the variant in snake_case (or as set with `#[command(rename = "...")]`). Its fields are the whitespace separated arguments of the command, the last field gets the rest of the line and `Option` fields may be omitted. The description shown in the command list is taken from the doc comment or from `#[command(description = "...")]`, translations are given as `#[command(description(ru = "...", de = "..."))]` and `#[command(hide)]` leaves the command out of the list.
+pub trait BotCommands: Sized {
+    /// Parses a message text like “/start@bot_username arguments”. Commands addressed to a bot other than `bot_username` are rejected.
+    fn parse(text: &str, bot_username: &str) -> Result<Self, CommandParseError>;
+
+    /// The command list with descriptions in the given language, falling back to the default descriptions
+    fn bot_commands_for(language_code: Option<&str>) -> Vec<BotCommand>;
+
+    /// Language codes with dedicated descriptions
+    fn languages() -> Vec<&'static str>;
+
+    /// The command list with the default descriptions
+    fn bot_commands() -> Vec<BotCommand> {
+        Self::bot_commands_for(None)
+    }
+
+    /// A SetMyCommands request for the given language, or for all users without dedicated commands if None
+    fn set_my_commands(language_code: Option<&str>) -> SetMyCommands {
+        let mut request = SetMyCommands::new(Self::bot_commands_for(language_code));
+        request.language_code(language_code.map(|code| code.to_string()));
+        request
+    }
+
+    /// SetMyCommands requests for the default language and for every language with dedicated descriptions
+    fn set_my_commands_all() -> Vec<SetMyCommands> {
+        std::iter::once(None)
+            .chain(Self::languages().into_iter().map(Some))
+            .map(Self::set_my_commands)
+            .collect()
+    }
+}
+
+/// Splits “/command@bot_username arguments” into the command name and the arguments.
+pub fn split_command<'a>(
+    text: &'a str,
+    bot_username: &str,
+) -> Result<(&'a str, &'a str), CommandParseError> {
+    let text = text.trim_start();
+    let rest = text
+        .strip_prefix('/')
+        .ok_or(CommandParseError::NotACommand)?;
+    let end = rest.find(char::is_whitespace).unwrap_or(rest.len());
+    let (command, args) = rest.split_at(end);
+    let command = match command.split_once('@') {
+        Some((command, bot)) => {
+            if !bot.eq_ignore_ascii_case(bot_username.trim_start_matches('@')) {
+                return Err(CommandParseError::WrongBotName(bot.to_string()));
+            }
+            command
+        }
+        None => command,
+    };
+    if command.is_empty() {
+        return Err(CommandParseError::NotACommand);
+    }
+    Ok((command, args.trim()))
+}
+
+/// Splits the arguments by whitespace into at most `count` parts, the last one keeps the rest of the line.
+pub fn split_arguments(args: &str, count: usize) -> Vec<&str> {
+    let mut parts = Vec::with_capacity(count);
+    let mut rest = args.trim();
+    while !rest.is_empty() {
+        if parts.len() + 1 == count {
+            parts.push(rest);
+            break;
+        }
+        let end = rest.find(char::is_whitespace).unwrap_or(rest.len());
+        parts.push(&rest[..end]);
+        rest = rest[end..].trim_start();
+    }
+    parts
+}
+
+pub fn parse_argument<T>(argument: &str) -> Result<T, CommandParseError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    argument
+        .parse()
+        .map_err(|err: T::Err| CommandParseError::InvalidArgument {
+            argument: argument.to_string(),
+            reason: err.to_string(),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(BotCommands, Debug, PartialEq)]
+    enum Command {
+        /// Start the bot
+        #[command(description(ru = "Запустить бота"))]
+        Start(Option<String>),
+        /// Add two numbers
+        Add(i64, i64),
+        #[command(rename = "say", description = "Repeat the text")]
+        Echo { text: String },
+        #[command(hide)]
+        DebugInfo,
+    }
+
+    #[test]
+    fn parse_commands() {
+        assert_eq!(Command::parse("/start", "my_bot"), Ok(Command::Start(None)));
+        assert_eq!(
+            Command::parse("/start@My_Bot ref42", "my_bot"),
+            Ok(Command::Start(Some("ref42".to_string())))
+        );
+        assert_eq!(
+            Command::parse("/add 2  3", "my_bot"),
+            Ok(Command::Add(2, 3))
+        );
+        assert_eq!(
+            Command::parse("/say hello  world", "my_bot"),
+            Ok(Command::Echo {
+                text: "hello  world".to_string()
+            })
+        );
+        assert_eq!(
+            Command::parse("/debug_info", "my_bot"),
+            Ok(Command::DebugInfo)
+        );
+    }
+
+    #[test]
+    fn wrong_arguments() {
+        assert_eq!(
+            Command::parse("/add 2", "my_bot"),
+            Err(CommandParseError::TooFewArguments {
+                expected: 2,
+                found: 1
+            })
+        );
+        assert!(matches!(
+            Command::parse("/add 2 x", "my_bot"),
+            Err(CommandParseError::InvalidArgument { .. })
+        ));
+        assert_eq!(
+            Command::parse("/debug_info now", "my_bot"),
+            Err(CommandParseError::TooManyArguments {
+                expected: 0,
+                found: 1
+            })
+        );
+    }
+
+    #[test]
+    fn not_our_command() {
+        assert_eq!(
+            Command::parse("/start@other_bot", "my_bot"),
+            Err(CommandParseError::WrongBotName("other_bot".to_string()))
+        );
+        assert_eq!(
+            Command::parse("/stop", "my_bot"),
+            Err(CommandParseError::UnknownCommand("stop".to_string()))
+        );
+        assert_eq!(
+            Command::parse("start", "my_bot"),
+            Err(CommandParseError::NotACommand)
+        );
+    }
+
+    #[test]
+    fn command_list() {
+        let commands = Command::bot_commands();
+        let names: Vec<_> = commands.iter().map(|c| c.command.as_str()).collect();
+        assert_eq!(names, ["start", "add", "say"]);
+        assert_eq!(commands[0].description, "Start the bot");
+        assert_eq!(
+            Command::bot_commands_for(Some("ru"))[0].description,
+            "Запустить бота"
+        );
+        assert_eq!(
+            Command::bot_commands_for(Some("ru"))[1].description,
+            "Add two numbers"
+        );
+    }
+
+    #[test]
+    fn set_my_commands_per_language() {
+        let requests = Command::set_my_commands_all();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[1].get_language_code().as_deref(), Some("ru"));
+    }
+}
+
+// The types below are generated from spec/bot_api.json by `cargo run -p rutel_codegen`, edit the spec instead.
+
 /// This object represents the scope to which bot commands are applied. Currently, the following 7 scopes are supported:
 #[derive(Clone, Serialize, Deserialize, Debug)]
+#[serde(tag = "type")]
 pub enum BotCommandScope {
-    BotCommandScopeDefault,
-    BotCommandScopeAllPrivateChats,
-    BotCommandScopeAllGroupChats,
-    BotCommandScopeAllChatAdministrators,
-    BotCommandScopeChat,
-    BotCommandScopeChatAdministrators,
-    BotCommandScopeChatMember,
+    #[serde(rename = "default")]
+    BotCommandScopeDefault(BotCommandScopeDefault),
+    #[serde(rename = "all_private_chats")]
+    BotCommandScopeAllPrivateChats(BotCommandScopeAllPrivateChats),
+    #[serde(rename = "all_group_chats")]
+    BotCommandScopeAllGroupChats(BotCommandScopeAllGroupChats),
+    #[serde(rename = "all_chat_administrators")]
+    BotCommandScopeAllChatAdministrators(BotCommandScopeAllChatAdministrators),
+    #[serde(rename = "chat")]
+    BotCommandScopeChat(BotCommandScopeChat),
+    #[serde(rename = "chat_administrators")]
+    BotCommandScopeChatAdministrators(BotCommandScopeChatAdministrators),
+    #[serde(rename = "chat_member")]
+    BotCommandScopeChatMember(BotCommandScopeChatMember),
 }
 
 /// Represents the default scope of bot commands. Default commands are used if no commands with a narrower scope are specified for the user.
 #[derive(Clone, Serialize, Deserialize, Debug)]
-pub struct BotCommandScopeDefault {
-    /// Scope type, must be default
-    #[serde(rename = "type")]
-    pub kind: String,
-}
+pub struct BotCommandScopeDefault {}
 
 /// Represents the scope of bot commands, covering all private chats.
 #[derive(Clone, Serialize, Deserialize, Debug)]
-pub struct BotCommandScopeAllPrivateChats {
-    /// Scope type, must be all_private_chats
-    #[serde(rename = "type")]
-    pub kind: String,
-}
+pub struct BotCommandScopeAllPrivateChats {}
 
 /// Represents the scope of bot commands, covering all group and supergroup chats.
 #[derive(Clone, Serialize, Deserialize, Debug)]
-pub struct BotCommandScopeAllGroupChats {
-    /// Scope type, must be all_group_chats
-    #[serde(rename = "type")]
-    pub kind: String,
-}
+pub struct BotCommandScopeAllGroupChats {}
 
 /// Represents the scope of bot commands, covering all group and supergroup chat administrators.
 #[derive(Clone, Serialize, Deserialize, Debug)]
-pub struct BotCommandScopeAllChatAdministrators {
-    /// Scope type, must be all_chat_administrators
-    #[serde(rename = "type")]
-    pub kind: String,
-}
+pub struct BotCommandScopeAllChatAdministrators {}
 
 /// Represents the scope of bot commands, covering a specific chat.
 #[derive(Clone, Serialize, Deserialize, Debug)]
 pub struct BotCommandScopeChat {
-    /// Scope type, must be chat
-    #[serde(rename = "type")]
-    pub kind: String,
     /// Unique identifier for the target chat or username of the target supergroup (in the format @supergroupusername)
     pub chat_id: ChatID,
 }
@@ -66,9 +270,6 @@ pub struct BotCommandScopeChat {
 /// Represents the scope of bot commands, covering all administrators of a specific group or supergroup chat.
 #[derive(Clone, Serialize, Deserialize, Debug)]
 pub struct BotCommandScopeChatAdministrators {
-    /// Scope type, must be chat_administrators
-    #[serde(rename = "type")]
-    pub kind: String,
     /// Unique identifier for the target chat or username of the target supergroup (in the format @supergroupusername)
     pub chat_id: ChatID,
 }
@@ -79,5 +280,5 @@ pub struct BotCommandScopeChatMember {
     /// Unique identifier for the target chat or username of the target supergroup (in the format @supergroupusername)
     pub chat_id: ChatID,
     /// Unique identifier of the target user
-    pub user_id: Integer,
+    pub user_id: UserId,
 }
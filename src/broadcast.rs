@@ -0,0 +1,439 @@
+use std::{
+    collections::HashMap,
+    fs, io,
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+use serde::{Deserialize, Serialize};
+use serde_json::{to_value, Value};
+use tokio::time::{sleep_until, Instant};
+
+use crate::{
+    bot::Bot,
+    error::{Error, Result},
+    types::ChatID,
+    validate::Validate,
+};
+
+/// Why a message wasn't delivered to a chat.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Failure {
+    /// The user blocked the bot, or the bot was removed from the chat
+    Blocked,
+    /// The user deleted their account
+    Deactivated,
+    ChatNotFound,
+    /// Flood control was still exceeded after the retries
+    Flood,
+    Other,
+}
+
+impl Failure {
+    /// Classifies an error by its error code, and by its description where a code has several causes
+    pub fn classify(error: &Error) -> Failure {
+        let description = error.description().unwrap_or_default();
+        match error.code() {
+            Some(403) if description.contains("deactivated") => Failure::Deactivated,
+            Some(403) => Failure::Blocked,
+            Some(400) if description.contains("chat not found") => Failure::ChatNotFound,
+            Some(429) => Failure::Flood,
+            _ => Failure::Other,
+        }
+    }
+
+    /// True if the chat won't receive messages again and can be pruned
+    pub fn is_dead(self) -> bool {
+        matches!(
+            self,
+            Failure::Blocked | Failure::Deactivated | Failure::ChatNotFound
+        )
+    }
+}
+
+/// A chat the message wasn't delivered to.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct FailedChat {
+    pub chat_id: ChatID,
+    pub failure: Failure,
+    /// The error as reported by the Bot API
+    pub error: String,
+}
+
+/// The outcome of a broadcast so far.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct DeliveryReport {
+    pub delivered: usize,
+    pub failed: Vec<FailedChat>,
+}
+
+impl DeliveryReport {
+    /// Number of chats that failed for a reason
+    pub fn count(&self, failure: Failure) -> usize {
+        self.failed
+            .iter()
+            .filter(|chat| chat.failure == failure)
+            .count()
+    }
+
+    /// Chats that won't receive messages again
+    pub fn dead_chats(&self) -> impl Iterator<Item = &ChatID> {
+        self.failed
+            .iter()
+            .filter(|chat| chat.failure.is_dead())
+            .map(|chat| &chat.chat_id)
+    }
+}
+
+/// How a broadcast is paced.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Throttle {
+    /// Pause between two requests, 1/30 s keeps within the limit of about 30 messages per second
+    pub global: Duration,
+    /// Pause between two requests to the same chat
+    pub per_chat: Duration,
+    /// How many times a message is repeated after the Bot API asks to retry later
+    pub max_retries: u32,
+}
+
+impl Default for Throttle {
+    fn default() -> Self {
+        Throttle {
+            global: Duration::from_millis(34),
+            per_chat: Duration::from_secs(1),
+            max_retries: 3,
+        }
+    }
+}
+
+impl Throttle {
+    pub fn global(&mut self, global: Duration) -> &mut Self {
+        self.global = global;
+        self
+    }
+
+    pub fn per_chat(&mut self, per_chat: Duration) -> &mut Self {
+        self.per_chat = per_chat;
+        self
+    }
+
+    pub fn max_retries(&mut self, max_retries: u32) -> &mut Self {
+        self.max_retries = max_retries;
+        self
+    }
+}
+
+/// Pauses a running broadcast from another task, made by [`Broadcast::pause_handle`].
+#[derive(Clone, Debug, Default)]
+pub struct PauseHandle(Arc<AtomicBool>);
+
+impl PauseHandle {
+    /// Stops the broadcast after the message being sent
+    pub fn pause(&self) {
+        self.0.store(true, Ordering::SeqCst);
+    }
+
+    pub fn resume(&self) {
+        self.0.store(false, Ordering::SeqCst);
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.0.load(Ordering::SeqCst)
+    }
+}
+
+/// Where a broadcast stopped, saved to the state file.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+struct Progress {
+    /// Index of the next chat
+    cursor: usize,
+    report: DeliveryReport,
+}
+
+/// How [`Broadcast::run`] returned.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Status {
+    /// Every chat was tried
+    Finished,
+    /// Paused with the [`PauseHandle`], `run` continues from the next chat
+    Paused,
+}
+
+/// Sends one message to many chats.
+///
+/// The message is any send request, its chat_id is replaced by each chat in turn. With a state file the cursor and
+/// the report are saved every `save_every` chats and when the broadcast stops, so a broadcast resumes where it
+/// stopped after a restart, given the same chats in the same order. If the process dies between two saves, the chats
+/// sent since the last save get the message again on resume.
+pub struct Broadcast<R> {
+    message: R,
+    chats: Vec<ChatID>,
+    throttle: Throttle,
+    state_path: Option<PathBuf>,
+    save_every: usize,
+    progress: Progress,
+    pause: PauseHandle,
+}
+
+impl<R: Validate> Broadcast<R> {
+    pub fn new(message: R, chats: Vec<ChatID>) -> Self {
+        Broadcast {
+            message,
+            chats,
+            throttle: Throttle::default(),
+            state_path: None,
+            save_every: 100,
+            progress: Progress::default(),
+            pause: PauseHandle::default(),
+        }
+    }
+
+    pub fn throttle(&mut self, throttle: Throttle) -> &mut Self {
+        self.throttle = throttle;
+        self
+    }
+
+    /// Keeps the progress in `path`, loading it if the file exists
+    pub fn state_file<P: AsRef<Path>>(&mut self, path: P) -> Result<&mut Self> {
+        let path = path.as_ref().to_path_buf();
+        match fs::read(&path) {
+            Ok(data) => self.progress = serde_json::from_slice(&data)?,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {}
+            Err(err) => return Err(err.into()),
+        }
+        self.state_path = Some(path);
+        Ok(self)
+    }
+
+    /// Saves the progress after every `chats` chats, 100 by default.
+    ///
+    /// After a crash up to `chats - 1` chats can receive the message twice; set it to 1 when duplicates matter more
+    /// than the cost of writing the state file after each chat.
+    pub fn save_every(&mut self, chats: usize) -> &mut Self {
+        self.save_every = chats.max(1);
+        self
+    }
+
+    pub fn pause_handle(&self) -> PauseHandle {
+        self.pause.clone()
+    }
+
+    /// Index of the next chat
+    pub fn cursor(&self) -> usize {
+        self.progress.cursor
+    }
+
+    pub fn report(&self) -> &DeliveryReport {
+        &self.progress.report
+    }
+
+    async fn save(&self) -> Result<()> {
+        let Some(path) = &self.state_path else {
+            return Ok(());
+        };
+        let data = serde_json::to_vec(&self.progress)?;
+        let tmp = path.with_extension("tmp");
+        tokio::fs::write(&tmp, data).await?;
+        tokio::fs::rename(&tmp, path).await?;
+        Ok(())
+    }
+
+    /// Sends the message to the chats from the cursor on, until every chat is tried or the broadcast is paused.
+    ///
+    /// Failed chats are recorded in the report; an error is returned only if the message can't be serialized or the
+    /// state file can't be written.
+    pub async fn run(&mut self, bot: &mut Bot) -> Result<Status> {
+        let mut params = to_value(&self.message)?;
+        let mut next = Instant::now();
+        let mut last_sent: HashMap<String, Instant> = HashMap::new();
+        let mut unsaved = 0;
+        while let Some(chat_id) = self.chats.get(self.progress.cursor).cloned() {
+            if self.pause.is_paused() {
+                self.save().await?;
+                return Ok(Status::Paused);
+            }
+            params["chat_id"] = to_value(&chat_id)?;
+            let chat_key = params["chat_id"].to_string();
+            if let Some(last) = last_sent.get(&chat_key) {
+                next = next.max(*last + self.throttle.per_chat);
+            }
+            let result = self.send(bot, &params, &mut next).await;
+            last_sent.insert(chat_key, Instant::now());
+            let report = &mut self.progress.report;
+            match result {
+                Ok(_) => report.delivered += 1,
+                Err(error) => report.failed.push(FailedChat {
+                    chat_id,
+                    failure: Failure::classify(&error),
+                    error: error.to_string(),
+                }),
+            }
+            self.progress.cursor += 1;
+            unsaved += 1;
+            if unsaved >= self.save_every {
+                self.save().await?;
+                unsaved = 0;
+            }
+        }
+        self.save().await?;
+        Ok(Status::Finished)
+    }
+
+    /// Sends to one chat no earlier than `next`, repeating the request when flood control is exceeded
+    async fn send(&self, bot: &mut Bot, params: &Value, next: &mut Instant) -> Result<Value> {
+        sleep_until(*next).await;
+        let result = bot
+            .send_value_retrying(R::METHOD, params, self.throttle.max_retries)
+            .await;
+        *next = Instant::now() + self.throttle.global;
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::{
+        bot::SendMessage,
+        test_util::{flood, mock_bot},
+    };
+
+    fn api_error(code: i64, description: &str) -> Error {
+        Error::Api {
+            code,
+            description: description.to_string(),
+            parameters: None,
+        }
+    }
+
+    fn state_file(name: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("rutel-{name}-{}.json", std::process::id()));
+        let _ = fs::remove_file(&path);
+        path
+    }
+
+    fn broadcast(path: &Path) -> Broadcast<SendMessage> {
+        let chats = (1..=3).map(ChatID::from).collect();
+        let mut broadcast =
+            Broadcast::new(SendMessage::new(ChatID::from(0), "News".to_string()), chats);
+        broadcast
+            .state_file(path)
+            .unwrap()
+            .throttle(*Throttle::default().global(Duration::ZERO));
+        broadcast
+    }
+
+    #[test]
+    fn classify_failures() {
+        let failures = [
+            (
+                api_error(403, "Forbidden: bot was blocked by the user"),
+                Failure::Blocked,
+            ),
+            (
+                api_error(403, "Forbidden: bot was kicked from the group chat"),
+                Failure::Blocked,
+            ),
+            (
+                api_error(403, "Forbidden: user is deactivated"),
+                Failure::Deactivated,
+            ),
+            (
+                api_error(400, "Bad Request: chat not found"),
+                Failure::ChatNotFound,
+            ),
+            (
+                api_error(429, "Too Many Requests: retry after 5"),
+                Failure::Flood,
+            ),
+            (
+                api_error(400, "Bad Request: message text is empty"),
+                Failure::Other,
+            ),
+            (
+                Error::Description("Bad Request: chat not found".to_string()),
+                Failure::Other,
+            ),
+        ];
+        for (error, failure) in &failures {
+            assert_eq!(Failure::classify(error), *failure, "{error}");
+        }
+    }
+
+    #[test]
+    fn dead_chats() {
+        assert!(Failure::Deactivated.is_dead());
+        assert!(!Failure::Flood.is_dead());
+    }
+
+    #[tokio::test]
+    async fn paused_broadcast_saves_its_cursor() {
+        let path = state_file("broadcast-paused");
+        let mut bot = Bot::new("123:abc").unwrap();
+        let mut broadcast = broadcast(&path);
+        broadcast.pause_handle().pause();
+        assert_eq!(broadcast.run(&mut bot).await.unwrap(), Status::Paused);
+        assert_eq!(broadcast.cursor(), 0);
+        assert_eq!(
+            serde_json::from_slice::<Value>(&fs::read(&path).unwrap()).unwrap(),
+            json!({"cursor": 0, "report": {"delivered": 0, "failed": []}})
+        );
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn resumes_from_the_state_file() {
+        let path = state_file("broadcast-resumed");
+        fs::write(
+            &path,
+            json!({"cursor": 3, "report": {"delivered": 1, "failed": [
+                {"chat_id": 2, "failure": "blocked", "error": "Error Response 403: Forbidden: bot was blocked by the user"},
+                {"chat_id": 3, "failure": "flood", "error": "Error Response 429: Too Many Requests: retry after 5"}
+            ]}})
+            .to_string(),
+        )
+        .unwrap();
+        let mut bot = Bot::new("123:abc").unwrap();
+        let mut broadcast = broadcast(&path);
+        assert_eq!(broadcast.run(&mut bot).await.unwrap(), Status::Finished);
+        let report = broadcast.report();
+        assert_eq!(report.delivered, 1);
+        assert_eq!(report.count(Failure::Blocked), 1);
+        assert_eq!(report.count(Failure::Flood), 1);
+        assert_eq!(
+            serde_json::to_value(report.dead_chats().collect::<Vec<_>>()).unwrap(),
+            json!([2])
+        );
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn flood_control_retried() {
+        let (mut bot, mock) = mock_bot([
+            Ok(json!({})),
+            Err(flood(2)),
+            Ok(json!({})),
+            Err(api_error(403, "Forbidden: bot was blocked by the user")),
+        ]);
+        let chats = (1..=3).map(ChatID::from).collect();
+        let mut broadcast =
+            Broadcast::new(SendMessage::new(ChatID::from(0), "News".to_string()), chats);
+        assert_eq!(broadcast.run(&mut bot).await.unwrap(), Status::Finished);
+        let report = broadcast.report();
+        assert_eq!(report.delivered, 2);
+        assert_eq!(report.count(Failure::Blocked), 1);
+        let chat_ids: Vec<Value> = mock
+            .requests()
+            .into_iter()
+            .map(|(_, params)| params["chat_id"].clone())
+            .collect();
+        assert_eq!(chat_ids, [json!(1), json!(2), json!(2), json!(3)]);
+    }
+}
@@ -0,0 +1,218 @@
+use std::time::Duration;
+
+use serde_json::{from_value, to_value, Value};
+
+use crate::{
+    bot::{Bot, CopyMessages, DeleteMessages, ForwardMessages},
+    error::{Error, Result},
+    ids::MessageId,
+    validate::Validate,
+};
+
+/// Most message ids forwardMessages, copyMessages and deleteMessages accept in one request
+pub const MAX_IDS: usize = 100;
+
+/// Sorts and dedupes message ids and splits them into chunks of at most `MAX_IDS`
+pub fn chunk_ids(ids: impl IntoIterator<Item = MessageId>) -> Vec<Vec<MessageId>> {
+    let mut ids: Vec<MessageId> = ids.into_iter().collect();
+    ids.sort_unstable();
+    ids.dedup();
+    ids.chunks(MAX_IDS).map(<[MessageId]>::to_vec).collect()
+}
+
+/// How bulk requests are paced.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Bulk {
+    /// Pause between two chunks
+    pub interval: Duration,
+    /// How many times a chunk is repeated after the Bot API asks to retry later
+    pub max_retries: u32,
+}
+
+impl Default for Bulk {
+    /// A chunk every 3 seconds, which keeps a job within the flood limits of a group, and 3 retries
+    fn default() -> Self {
+        Bulk {
+            interval: Duration::from_secs(3),
+            max_retries: 3,
+        }
+    }
+}
+
+impl Bulk {
+    pub fn interval(&mut self, interval: Duration) -> &mut Self {
+        self.interval = interval;
+        self
+    }
+
+    pub fn max_retries(&mut self, max_retries: u32) -> &mut Self {
+        self.max_retries = max_retries;
+        self
+    }
+}
+
+/// A chunk the Bot API rejected.
+#[derive(Debug)]
+pub struct ChunkFailure {
+    /// The message ids of the chunk
+    pub message_ids: Vec<MessageId>,
+    pub error: Error,
+}
+
+/// The outcome of a bulk request.
+#[derive(Debug, Default)]
+pub struct BulkReport {
+    /// The ids of the sent messages in the order of the chunks for forwarding and copying, the ids of the deleted
+    /// messages for deleting. Messages that can't be found or copied are skipped by the Bot API.
+    pub message_ids: Vec<MessageId>,
+    pub failures: Vec<ChunkFailure>,
+}
+
+impl BulkReport {
+    /// True if every chunk succeeded
+    pub fn is_complete(&self) -> bool {
+        self.failures.is_empty()
+    }
+}
+
+impl Bot {
+    /// Forwards any number of messages, `request.message_ids` is sorted, deduped and sent in chunks of `MAX_IDS`
+    pub async fn forward_messages_bulk(
+        &mut self,
+        request: &ForwardMessages,
+        bulk: &Bulk,
+    ) -> Result<BulkReport> {
+        self.bulk(request, &request.message_ids, bulk, |result, _| {
+            from_value(result)
+        })
+        .await
+    }
+
+    /// Copies any number of messages, `request.message_ids` is sorted, deduped and sent in chunks of `MAX_IDS`
+    pub async fn copy_messages_bulk(
+        &mut self,
+        request: &CopyMessages,
+        bulk: &Bulk,
+    ) -> Result<BulkReport> {
+        self.bulk(request, &request.message_ids, bulk, |result, _| {
+            from_value(result)
+        })
+        .await
+    }
+
+    /// Deletes any number of messages, `request.message_ids` is deduped and sent in chunks of `MAX_IDS`
+    pub async fn delete_messages_bulk(
+        &mut self,
+        request: &DeleteMessages,
+        bulk: &Bulk,
+    ) -> Result<BulkReport> {
+        self.bulk(request, &request.message_ids, bulk, |_, chunk| {
+            Ok(chunk.to_vec())
+        })
+        .await
+    }
+
+    /// Sends a request once per chunk of `message_ids`, waiting `bulk.interval` between chunks and as long as the
+    /// Bot API asks when flood control is exceeded. Fails only if the request can't be serialized.
+    async fn bulk<R: Validate>(
+        &mut self,
+        request: &R,
+        message_ids: &[MessageId],
+        bulk: &Bulk,
+        sent: impl Fn(Value, &[MessageId]) -> serde_json::Result<Vec<MessageId>>,
+    ) -> Result<BulkReport> {
+        let mut params = to_value(request)?;
+        let mut report = BulkReport::default();
+        for (i, chunk) in chunk_ids(message_ids.iter().copied())
+            .into_iter()
+            .enumerate()
+        {
+            if i > 0 {
+                tokio::time::sleep(bulk.interval).await;
+            }
+            params["message_ids"] = to_value(&chunk)?;
+            let result = self
+                .send_value_retrying(R::METHOD, &params, bulk.max_retries)
+                .await;
+            match result.and_then(|result| Ok(sent(result, &chunk)?)) {
+                Ok(ids) => report.message_ids.extend(ids),
+                Err(error) => report.failures.push(ChunkFailure {
+                    message_ids: chunk,
+                    error,
+                }),
+            }
+        }
+        Ok(report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::{
+        test_util::{flood, mock_bot},
+        types::ChatID,
+    };
+
+    fn ids(ids: &[i64]) -> Vec<MessageId> {
+        ids.iter().map(|&id| MessageId(id)).collect()
+    }
+
+    #[test]
+    fn chunk_ids_sorts_and_dedupes() {
+        assert_eq!(chunk_ids(ids(&[5, 1, 3, 1, 5])), vec![ids(&[1, 3, 5])]);
+        assert!(chunk_ids(Vec::new()).is_empty());
+    }
+
+    #[test]
+    fn chunks_fit_the_limit() {
+        let chunks = chunk_ids((1..=250).rev().chain(1..=10).map(MessageId));
+        assert_eq!(
+            chunks.iter().map(Vec::len).collect::<Vec<_>>(),
+            [MAX_IDS, MAX_IDS, 50]
+        );
+        assert_eq!(chunks[1][0], MessageId(101));
+        for chunk in &chunks {
+            let request = ForwardMessages::new(ChatID::from(1), ChatID::from(2), chunk.clone());
+            assert!(request.validate().is_ok());
+        }
+    }
+
+    #[tokio::test]
+    async fn nothing_to_send() {
+        let mut bot = Bot::new("123:abc").unwrap();
+        let request = DeleteMessages::new(ChatID::from(1), Vec::new());
+        let report = bot
+            .delete_messages_bulk(&request, Bulk::default().interval(Duration::ZERO))
+            .await
+            .unwrap();
+        assert!(report.is_complete());
+        assert!(report.message_ids.is_empty());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn chunks_retried_after_flood_control() {
+        let (mut bot, mock) =
+            mock_bot([Err(flood(5)), Ok(json!(true)), Err(flood(5)), Err(flood(5))]);
+        let request = DeleteMessages::new(ChatID::from(1), (1..=150).map(MessageId).collect());
+        let report = bot
+            .delete_messages_bulk(&request, Bulk::default().max_retries(1))
+            .await
+            .unwrap();
+        assert_eq!(report.message_ids, ids(&(1..=100).collect::<Vec<_>>()));
+        assert_eq!(report.failures.len(), 1);
+        assert_eq!(report.failures[0].message_ids[0], MessageId(101));
+        assert_eq!(
+            report.failures[0].error.retry_after(),
+            Some(Duration::from_secs(5))
+        );
+        let requests = mock.requests();
+        assert_eq!(requests.len(), 4);
+        assert!(requests
+            .iter()
+            .all(|(method, _)| *method == "deleteMessages"));
+        assert_eq!(requests[1].1["message_ids"], requests[0].1["message_ids"]);
+    }
+}
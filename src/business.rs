@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 use crate::{
+    ids::{ChatId, MessageId},
     stickers::Sticker,
     types::{Boolean, Chat, Integer, Location, User},
 };
@@ -13,7 +14,7 @@ pub struct BusinessConnection {
     /// Business account user that created the business connection
     pub user: User,
     /// Identifier of a private chat with the user who created the business connection. This number may have more than 32 significant bits and some programming languages may have difficulty/silent defects in interpreting it. But it has at most 52 significant bits, so a 64-bit integer or double-precision float type are safe for storing this identifier.
-    pub user_chat_id: Integer,
+    pub user_chat_id: ChatId,
     /// Date the connection was established in Unix time
     pub date: Integer,
     /// True, if the bot can act on behalf of the business account in chats that were active in the last 24 hours
@@ -30,7 +31,7 @@ pub struct BusinessMessagesDeleted {
     /// Information about a chat in the business account. The bot may not have access to the chat or the corresponding user.
     pub chat: Chat,
     /// The list of identifiers of deleted messages in the chat of the business account
-    pub message_ids: Vec<Integer>,
+    pub message_ids: Vec<MessageId>,
 }
 
 /// Contains information about the start page settings of a Telegram Business account.
@@ -0,0 +1,309 @@
+use std::{fmt::Display, str::FromStr};
+
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use ring::hmac;
+use thiserror::Error;
+
+use crate::{
+    keyboard::{btn, InlineButton},
+    types::CallbackQuery,
+};
+
+pub use rutel_macros::CallbackData;
+
+/// Maximum size of the callback data of an inline keyboard button in bytes
+pub const CALLBACK_DATA_LIMIT: usize = 64;
+
+/// An error returned when a value can't be encoded as callback data or callback data can't be decoded.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum CallbackDataError {
+    #[error("callback data is {0} bytes long, the limit is 64")]
+    TooLong(usize),
+    #[error("callback query has no data")]
+    NoData,
+    #[error("unknown callback data tag: {0:?}")]
+    UnknownTag(String),
+    #[error("wrong number of callback data fields: expected {expected}, found {found}")]
+    WrongFieldCount { expected: usize, found: usize },
+    #[error("invalid callback data field {field:?}: {reason}")]
+    InvalidField { field: String, reason: String },
+    #[error("callback data signature doesn't match")]
+    BadSignature,
+}
+
+/// A value carried in the callback data of inline keyboard buttons, usually derived with `#[derive(CallbackData)]`.
+///
+/// The derived encoding is the tag of the variant (or of the struct) in snake_case, or as set with
+/// `#[callback_data(rename = "...")]`, followed by the fields written with `Display` and read with `FromStr`, all
+/// separated by `:`. A `None` field is written as an empty string, so `Some` of an empty string reads back as `None`.
+pub trait CallbackData: Sized {
+    fn encode(&self) -> String;
+
+    fn decode(data: &str) -> Result<Self, CallbackDataError>;
+
+    /// The encoded value, checked against `CALLBACK_DATA_LIMIT`
+    fn to_callback_data(&self) -> Result<String, CallbackDataError> {
+        check_len(self.encode())
+    }
+
+    /// Decodes the data of a callback query
+    fn from_query(query: &CallbackQuery) -> Result<Self, CallbackDataError> {
+        Self::decode(query.data.as_deref().ok_or(CallbackDataError::NoData)?)
+    }
+
+    /// A callback button carrying the value
+    fn button(&self, text: &str) -> Result<InlineButton, CallbackDataError> {
+        Ok(btn::callback(text, &self.to_callback_data()?))
+    }
+}
+
+fn check_len(data: String) -> Result<String, CallbackDataError> {
+    if data.len() > CALLBACK_DATA_LIMIT {
+        return Err(CallbackDataError::TooLong(data.len()));
+    }
+    Ok(data)
+}
+
+fn escape(field: &str, data: &mut String) {
+    for c in field.chars() {
+        if c == ':' || c == '\\' {
+            data.push('\\');
+        }
+        data.push(c);
+    }
+}
+
+/// Appends a field to encoded callback data
+pub fn push_field<T: Display>(data: &mut String, field: &T) {
+    data.push(':');
+    escape(&field.to_string(), data);
+}
+
+/// Appends an optional field to encoded callback data, None is an empty field
+pub fn push_optional_field<T: Display>(data: &mut String, field: Option<&T>) {
+    data.push(':');
+    if let Some(field) = field {
+        escape(&field.to_string(), data);
+    }
+}
+
+/// Splits encoded callback data into the tag and the unescaped fields
+pub fn split_fields(data: &str) -> (String, Vec<String>) {
+    let mut parts = vec![String::new()];
+    let mut chars = data.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => parts.last_mut().unwrap().extend(chars.next()),
+            ':' => parts.push(String::new()),
+            c => parts.last_mut().unwrap().push(c),
+        }
+    }
+    let tag = parts.remove(0);
+    (tag, parts)
+}
+
+pub fn parse_field<T>(field: &str) -> Result<T, CallbackDataError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    field
+        .parse()
+        .map_err(|err: T::Err| CallbackDataError::InvalidField {
+            field: field.to_string(),
+            reason: err.to_string(),
+        })
+}
+
+pub fn parse_optional_field<T>(field: &str) -> Result<Option<T>, CallbackDataError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    if field.is_empty() {
+        return Ok(None);
+    }
+    parse_field(field).map(Some)
+}
+
+/// Signs callback data with a truncated HMAC-SHA256, so callback queries with data the bot didn't make are rejected.
+///
+/// The signature is appended to the data in URL-safe base64 and counts towards `CALLBACK_DATA_LIMIT`.
+pub struct Signer {
+    key: hmac::Key,
+    tag_len: usize,
+}
+
+impl Signer {
+    /// A signer with the secret key and 6-byte signatures (8 characters)
+    pub fn new(secret: &[u8]) -> Self {
+        Signer {
+            key: hmac::Key::new(hmac::HMAC_SHA256, secret),
+            tag_len: 6,
+        }
+    }
+
+    /// Length of the signatures in bytes, 4-32
+    pub fn tag_len(&mut self, tag_len: usize) -> &mut Self {
+        self.tag_len = tag_len.clamp(4, 32);
+        self
+    }
+
+    fn tag(&self, data: &str) -> String {
+        let tag = hmac::sign(&self.key, data.as_bytes());
+        URL_SAFE_NO_PAD.encode(&tag.as_ref()[..self.tag_len])
+    }
+
+    /// The data with its signature
+    pub fn sign(&self, data: &str) -> String {
+        format!("{data}{}", self.tag(data))
+    }
+
+    /// The data without the signature, if the signature matches
+    pub fn verify<'a>(&self, signed: &'a str) -> Result<&'a str, CallbackDataError> {
+        let tag_chars = (self.tag_len * 4).div_ceil(3);
+        let split = signed
+            .len()
+            .checked_sub(tag_chars)
+            .filter(|&i| signed.is_char_boundary(i))
+            .ok_or(CallbackDataError::BadSignature)?;
+        let (data, tag) = signed.split_at(split);
+        let expected = self.tag(data);
+        let diff = expected
+            .bytes()
+            .zip(tag.bytes())
+            .fold(0, |diff, (a, b)| diff | (a ^ b));
+        if diff != 0 {
+            return Err(CallbackDataError::BadSignature);
+        }
+        Ok(data)
+    }
+
+    /// The signed encoded value, checked against `CALLBACK_DATA_LIMIT`
+    pub fn encode<T: CallbackData>(&self, value: &T) -> Result<String, CallbackDataError> {
+        check_len(self.sign(&value.encode()))
+    }
+
+    /// Decodes signed callback data, rejecting it if the signature doesn't match
+    pub fn decode<T: CallbackData>(&self, data: &str) -> Result<T, CallbackDataError> {
+        T::decode(self.verify(data)?)
+    }
+
+    /// Decodes the signed data of a callback query
+    pub fn decode_query<T: CallbackData>(
+        &self,
+        query: &CallbackQuery,
+    ) -> Result<T, CallbackDataError> {
+        self.decode(query.data.as_deref().ok_or(CallbackDataError::NoData)?)
+    }
+
+    /// A callback button carrying the signed value
+    pub fn button<T: CallbackData>(
+        &self,
+        text: &str,
+        value: &T,
+    ) -> Result<InlineButton, CallbackDataError> {
+        Ok(btn::callback(text, &self.encode(value)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[derive(CallbackData, Debug, PartialEq)]
+    enum Action {
+        Refresh,
+        #[callback_data(rename = "o")]
+        Open(u32),
+        Rename {
+            id: u32,
+            name: String,
+            note: Option<String>,
+        },
+    }
+
+    #[derive(CallbackData, Debug, PartialEq)]
+    struct Vote(i64, bool);
+
+    #[test]
+    fn encode_and_decode_fields() {
+        assert_eq!(Action::Refresh.encode(), "refresh");
+        assert_eq!(Action::Open(7).encode(), "o:7");
+        let rename = Action::Rename {
+            id: 1,
+            name: "a:b\\c".to_string(),
+            note: None,
+        };
+        assert_eq!(rename.encode(), "rename:1:a\\:b\\\\c:");
+        assert_eq!(Action::decode(&rename.encode()), Ok(rename));
+        assert_eq!(Vote::decode("vote:-5:true"), Ok(Vote(-5, true)));
+    }
+
+    #[test]
+    fn decode_errors() {
+        assert_eq!(
+            Action::decode("close"),
+            Err(CallbackDataError::UnknownTag("close".to_string()))
+        );
+        assert_eq!(
+            Action::decode("o:1:2"),
+            Err(CallbackDataError::WrongFieldCount {
+                expected: 1,
+                found: 2
+            })
+        );
+        assert!(matches!(
+            Action::decode("o:x"),
+            Err(CallbackDataError::InvalidField { .. })
+        ));
+    }
+
+    #[test]
+    fn size_limit() {
+        let long = Action::Rename {
+            id: 1,
+            name: "x".repeat(60),
+            note: None,
+        };
+        assert_eq!(long.to_callback_data(), Err(CallbackDataError::TooLong(70)));
+        assert!(long.button("Rename").is_err());
+    }
+
+    #[test]
+    fn signed_data() {
+        let signer = Signer::new(b"secret");
+        let signed = signer.encode(&Action::Open(42)).unwrap();
+        assert_eq!(signed.len(), "o:42".len() + 8);
+        assert_eq!(signer.decode(&signed), Ok(Action::Open(42)));
+        assert_eq!(
+            signer.decode::<Action>(&signed.replacen("42", "43", 1)),
+            Err(CallbackDataError::BadSignature)
+        );
+        assert_eq!(
+            Signer::new(b"other").decode::<Action>(&signed),
+            Err(CallbackDataError::BadSignature)
+        );
+        assert_eq!(
+            signer.decode::<Action>("o:1"),
+            Err(CallbackDataError::BadSignature)
+        );
+    }
+
+    #[test]
+    fn decode_from_query() {
+        let signer = Signer::new(b"secret");
+        let mut query: CallbackQuery = serde_json::from_value(json!({
+            "id": "1",
+            "from": {"id": 1, "is_bot": false, "first_name": "A"},
+            "chat_instance": "c",
+            "data": signer.encode(&Action::Open(42)).unwrap()
+        }))
+        .unwrap();
+        assert_eq!(signer.decode_query(&query), Ok(Action::Open(42)));
+        query.data = None;
+        assert_eq!(Action::from_query(&query), Err(CallbackDataError::NoData));
+    }
+}
@@ -1,48 +1,9 @@
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 
+use crate::ids::MessageId;
 use crate::types::{Boolean, Chat, Integer, User};
 
-/// This object describes the source of a chat boost. It can be one of
-#[derive(Clone, Serialize, Deserialize, Debug)]
-pub enum ChatBoostSource {
-    ChatBoostSourcePremium(ChatBoostSourcePremium),
-    ChatBoostSourceGiftCode(ChatBoostSourceGiftCode),
-    ChatBoostSourceGiveaway(ChatBoostSourceGiveaway),
-}
-
-/// The boost was obtained by subscribing to Telegram Premium or by gifting a Telegram Premium subscription to another user.
-#[derive(Clone, Serialize, Deserialize, Debug)]
-pub struct ChatBoostSourcePremium {
-    /// Source of the boost, always “premium”
-    pub source: String,
-    /// User that boosted the chat
-    pub user: User,
-}
-
-/// The boost was obtained by the creation of Telegram Premium gift codes to boost a chat. Each such code boosts the chat 4 times for the duration of the corresponding Telegram Premium subscription.
-#[derive(Clone, Serialize, Deserialize, Debug)]
-pub struct ChatBoostSourceGiftCode {
-    /// Source of the boost, always “gift_code”
-    pub source: String,
-    /// User for which the gift code was created
-    pub user: User,
-}
-
-///The boost was obtained by the creation of a Telegram Premium giveaway. This boosts the chat 4 times for the duration of the corresponding Telegram Premium subscription.
-#[derive(Clone, Serialize, Deserialize, Debug)]
-pub struct ChatBoostSourceGiveaway {
-    /// Source of the boost, always “giveaway”
-    pub source: String,
-    /// Identifier of a message in the chat with the giveaway; the message could have been deleted already. May be 0 if the message isn't sent yet.
-    pub giveaway_message_id: Integer,
-    /// Optional. User that won the prize in the giveaway if any
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub user: Option<User>,
-    /// Optional. True, if the giveaway was completed, but there was no user to win the prize
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub is_unclaimed: Option<Boolean>,
-}
-
 /// This object contains information about a chat boost.
 #[derive(Clone, Serialize, Deserialize, Debug)]
 pub struct ChatBoost {
@@ -84,3 +45,65 @@ pub struct UserChatBoosts {
     /// The list of boosts added to the chat by the user
     pub boosts: Vec<ChatBoost>,
 }
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn boost_source_tagged_by_source() {
+        let user = json!({"id": 1, "is_bot": false, "first_name": "A"});
+        let source: ChatBoostSource =
+            serde_json::from_value(json!({"source": "gift_code", "user": user})).unwrap();
+        assert!(matches!(
+            source,
+            ChatBoostSource::ChatBoostSourceGiftCode(_)
+        ));
+    }
+}
+
+// The types below are generated from spec/bot_api.json by `cargo run -p rutel_codegen`, edit the spec instead.
+
+/// This object describes the source of a chat boost. It can be one of
+#[derive(Clone, Serialize, Deserialize, Debug)]
+#[serde(tag = "source")]
+pub enum ChatBoostSource {
+    #[serde(rename = "premium")]
+    ChatBoostSourcePremium(ChatBoostSourcePremium),
+    #[serde(rename = "gift_code")]
+    ChatBoostSourceGiftCode(ChatBoostSourceGiftCode),
+    #[serde(rename = "giveaway")]
+    ChatBoostSourceGiveaway(ChatBoostSourceGiveaway),
+    /// An object not known to this version of the library, or one that can't be decoded
+    #[serde(untagged)]
+    Unknown(Value),
+}
+
+/// The boost was obtained by subscribing to Telegram Premium or by gifting a Telegram Premium subscription to another user.
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct ChatBoostSourcePremium {
+    /// User that boosted the chat
+    pub user: User,
+}
+
+/// The boost was obtained by the creation of Telegram Premium gift codes to boost a chat. Each such code boosts the chat 4 times for the duration of the corresponding Telegram Premium subscription.
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct ChatBoostSourceGiftCode {
+    /// User for which the gift code was created
+    pub user: User,
+}
+
+/// The boost was obtained by the creation of a Telegram Premium giveaway. This boosts the chat 4 times for the duration of the corresponding Telegram Premium subscription.
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct ChatBoostSourceGiveaway {
+    /// Identifier of a message in the chat with the giveaway; the message could have been deleted already. May be 0 if the message isn't sent yet.
+    pub giveaway_message_id: MessageId,
+    /// Optional. User that won the prize in the giveaway if any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<User>,
+    /// Optional. True, if the giveaway was completed, but there was no user to win the prize
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_unclaimed: Option<Boolean>,
+}
@@ -0,0 +1,383 @@
+use crate::{
+    files::{Animation, Audio, Document, PhotoSize, Video, VideoNote, Voice},
+    forum_topic::{
+        ForumTopicClosed, ForumTopicCreated, ForumTopicEdited, ForumTopicReopened,
+        GeneralForumTopicHidden, GeneralForumTopicUnhidden,
+    },
+    games::Game,
+    giveaway::{Giveaway, GiveawayCompleted, GiveawayCreated, GiveawayWinners},
+    ids::ChatId,
+    message::{MaybeInaccessibleMessage, Message},
+    passport::PassportData,
+    payments::{Invoice, PaidMediaInfo, RefundedPayment, SuccessfulPayment},
+    poll::Poll,
+    stickers::Sticker,
+    types::{
+        ChatBoostAdded, ChatShared, Contact, Dice, Location, MessageAutoDeleteTimerChanged,
+        ProximityAlertTriggered, Story, User, UsersShared, Venue, VideoChatEnded,
+        VideoChatParticipantsInvited, VideoChatScheduled, VideoChatStarted, WebAppData,
+        WriteAccessAllowed,
+    },
+};
+
+/// Declares `MessageContent` and `ContentType` from one list of variants, so that every kind of content can be
+/// filtered on.
+macro_rules! message_contents {
+    ($($(#[$meta:meta])* $variant:ident$(($ty:ty))?,)*) => {
+        /// What a message is, borrowing the fields of the message that describe it.
+        ///
+        /// Animations are also sent with a `document` and venues with a `location`; they are classified as `Animation`
+        /// and `Venue` only. The caption and its entities of media messages stay on the message.
+        #[derive(Clone, Copy, Debug)]
+        pub enum MessageContent<'a> {
+            $($(#[$meta])* $variant$(($ty))?,)*
+        }
+
+        /// The kind of a [`MessageContent`] without its fields, used by `Filter::content`.
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+        pub enum ContentType {
+            $($variant,)*
+        }
+
+        impl MessageContent<'_> {
+            /// The kind of the content
+            pub fn content_type(&self) -> ContentType {
+                match self {
+                    $(MessageContent::$variant { .. } => ContentType::$variant,)*
+                }
+            }
+        }
+    };
+}
+
+message_contents! {
+    Text(&'a str),
+    Animation(&'a Animation),
+    Audio(&'a Audio),
+    Document(&'a Document),
+    PaidMedia(&'a PaidMediaInfo),
+    /// Available sizes of the photo, from the smallest
+    Photo(&'a [PhotoSize]),
+    Sticker(&'a Sticker),
+    Story(&'a Story),
+    Video(&'a Video),
+    VideoNote(&'a VideoNote),
+    Voice(&'a Voice),
+    Contact(&'a Contact),
+    Dice(&'a Dice),
+    Game(&'a Game),
+    Poll(&'a Poll),
+    Venue(&'a Venue),
+    Location(&'a Location),
+    Invoice(&'a Invoice),
+    Giveaway(&'a Giveaway),
+    GiveawayWinners(&'a GiveawayWinners),
+    NewChatMembers(&'a [User]),
+    LeftChatMember(&'a User),
+    NewChatTitle(&'a str),
+    NewChatPhoto(&'a [PhotoSize]),
+    DeleteChatPhoto,
+    GroupChatCreated,
+    SupergroupChatCreated,
+    ChannelChatCreated,
+    MessageAutoDeleteTimerChanged(&'a MessageAutoDeleteTimerChanged),
+    MigrateToChatId(ChatId),
+    MigrateFromChatId(ChatId),
+    PinnedMessage(&'a MaybeInaccessibleMessage),
+    SuccessfulPayment(&'a SuccessfulPayment),
+    RefundedPayment(&'a RefundedPayment),
+    UsersShared(&'a UsersShared),
+    ChatShared(&'a ChatShared),
+    ConnectedWebsite(&'a str),
+    WriteAccessAllowed(&'a WriteAccessAllowed),
+    PassportData(&'a PassportData),
+    ProximityAlertTriggered(&'a ProximityAlertTriggered),
+    BoostAdded(&'a ChatBoostAdded),
+    ForumTopicCreated(&'a ForumTopicCreated),
+    ForumTopicEdited(&'a ForumTopicEdited),
+    ForumTopicClosed(&'a ForumTopicClosed),
+    ForumTopicReopened(&'a ForumTopicReopened),
+    GeneralForumTopicHidden(&'a GeneralForumTopicHidden),
+    GeneralForumTopicUnhidden(&'a GeneralForumTopicUnhidden),
+    GiveawayCreated(&'a GiveawayCreated),
+    GiveawayCompleted(&'a GiveawayCompleted),
+    VideoChatScheduled(&'a VideoChatScheduled),
+    VideoChatStarted(&'a VideoChatStarted),
+    VideoChatEnded(&'a VideoChatEnded),
+    VideoChatParticipantsInvited(&'a VideoChatParticipantsInvited),
+    WebAppData(&'a WebAppData),
+    /// A kind of message this version of the library doesn't know, its fields are in `Message::extra`
+    Unknown,
+}
+
+impl MessageContent<'_> {
+    /// True for messages sent by Telegram about changes in the chat rather than by users
+    pub fn is_service(&self) -> bool {
+        use MessageContent::*;
+        matches!(
+            self,
+            NewChatMembers(_)
+                | LeftChatMember(_)
+                | NewChatTitle(_)
+                | NewChatPhoto(_)
+                | DeleteChatPhoto
+                | GroupChatCreated
+                | SupergroupChatCreated
+                | ChannelChatCreated
+                | MessageAutoDeleteTimerChanged(_)
+                | MigrateToChatId(_)
+                | MigrateFromChatId(_)
+                | PinnedMessage(_)
+                | SuccessfulPayment(_)
+                | RefundedPayment(_)
+                | UsersShared(_)
+                | ChatShared(_)
+                | ConnectedWebsite(_)
+                | WriteAccessAllowed(_)
+                | PassportData(_)
+                | ProximityAlertTriggered(_)
+                | BoostAdded(_)
+                | ForumTopicCreated(_)
+                | ForumTopicEdited(_)
+                | ForumTopicClosed(_)
+                | ForumTopicReopened(_)
+                | GeneralForumTopicHidden(_)
+                | GeneralForumTopicUnhidden(_)
+                | GiveawayCreated(_)
+                | GiveawayCompleted(_)
+                | VideoChatScheduled(_)
+                | VideoChatStarted(_)
+                | VideoChatEnded(_)
+                | VideoChatParticipantsInvited(_)
+                | WebAppData(_)
+        )
+    }
+
+    /// True for messages carrying a file: animations, audios, documents, paid media, photos, stickers, videos, video
+    /// notes and voice notes
+    pub fn has_media(&self) -> bool {
+        use MessageContent::*;
+        matches!(
+            self,
+            Animation(_)
+                | Audio(_)
+                | Document(_)
+                | PaidMedia(_)
+                | Photo(_)
+                | Sticker(_)
+                | Video(_)
+                | VideoNote(_)
+                | Voice(_)
+        )
+    }
+}
+
+/// The largest size of a photo, by area and then by file size
+pub fn best_photo(sizes: &[PhotoSize]) -> Option<&PhotoSize> {
+    sizes
+        .iter()
+        .max_by_key(|size| (size.width * size.height, size.file_size))
+}
+
+impl Message {
+    /// What the message is
+    pub fn content(&self) -> MessageContent<'_> {
+        use MessageContent as C;
+        if let Some(text) = &self.text {
+            C::Text(text)
+        } else if let Some(animation) = &self.animation {
+            C::Animation(animation)
+        } else if let Some(audio) = &self.audio {
+            C::Audio(audio)
+        } else if let Some(document) = &self.document {
+            C::Document(document)
+        } else if let Some(paid_media) = &self.paid_media {
+            C::PaidMedia(paid_media)
+        } else if let Some(photo) = &self.photo {
+            C::Photo(photo)
+        } else if let Some(sticker) = &self.sticker {
+            C::Sticker(sticker)
+        } else if let Some(story) = &self.story {
+            C::Story(story)
+        } else if let Some(video) = &self.video {
+            C::Video(video)
+        } else if let Some(video_note) = &self.video_note {
+            C::VideoNote(video_note)
+        } else if let Some(voice) = &self.voice {
+            C::Voice(voice)
+        } else if let Some(contact) = &self.contact {
+            C::Contact(contact)
+        } else if let Some(dice) = &self.dice {
+            C::Dice(dice)
+        } else if let Some(game) = &self.game {
+            C::Game(game)
+        } else if let Some(poll) = &self.poll {
+            C::Poll(poll)
+        } else if let Some(venue) = &self.venue {
+            C::Venue(venue)
+        } else if let Some(location) = &self.location {
+            C::Location(location)
+        } else if let Some(invoice) = &self.invoice {
+            C::Invoice(invoice)
+        } else if let Some(giveaway) = &self.giveaway {
+            C::Giveaway(giveaway)
+        } else if let Some(winners) = &self.giveaway_winners {
+            C::GiveawayWinners(winners)
+        } else if let Some(members) = &self.new_chat_members {
+            C::NewChatMembers(members)
+        } else if let Some(member) = &self.left_chat_member {
+            C::LeftChatMember(member)
+        } else if let Some(title) = &self.new_chat_title {
+            C::NewChatTitle(title)
+        } else if let Some(photo) = &self.new_chat_photo {
+            C::NewChatPhoto(photo)
+        } else if self.delete_chat_photo == Some(true) {
+            C::DeleteChatPhoto
+        } else if self.group_chat_created == Some(true) {
+            C::GroupChatCreated
+        } else if self.supergroup_chat_created == Some(true) {
+            C::SupergroupChatCreated
+        } else if self.channel_chat_created == Some(true) {
+            C::ChannelChatCreated
+        } else if let Some(changed) = &self.message_auto_delete_timer_changed {
+            C::MessageAutoDeleteTimerChanged(changed)
+        } else if let Some(id) = self.migrate_to_chat_id {
+            C::MigrateToChatId(id)
+        } else if let Some(id) = self.migrate_from_chat_id {
+            C::MigrateFromChatId(id)
+        } else if let Some(pinned) = &self.pinned_message {
+            C::PinnedMessage(pinned)
+        } else if let Some(payment) = &self.successful_payment {
+            C::SuccessfulPayment(payment)
+        } else if let Some(payment) = &self.refunded_payment {
+            C::RefundedPayment(payment)
+        } else if let Some(shared) = &self.users_shared {
+            C::UsersShared(shared)
+        } else if let Some(shared) = &self.chat_shared {
+            C::ChatShared(shared)
+        } else if let Some(website) = &self.connected_website {
+            C::ConnectedWebsite(website)
+        } else if let Some(allowed) = &self.write_access_allowed {
+            C::WriteAccessAllowed(allowed)
+        } else if let Some(data) = &self.passport_data {
+            C::PassportData(data)
+        } else if let Some(alert) = &self.proximity_alert_triggered {
+            C::ProximityAlertTriggered(alert)
+        } else if let Some(boost) = &self.boost_added {
+            C::BoostAdded(boost)
+        } else if let Some(topic) = &self.forum_topic_created {
+            C::ForumTopicCreated(topic)
+        } else if let Some(topic) = &self.forum_topic_edited {
+            C::ForumTopicEdited(topic)
+        } else if let Some(topic) = &self.forum_topic_closed {
+            C::ForumTopicClosed(topic)
+        } else if let Some(topic) = &self.forum_topic_reopened {
+            C::ForumTopicReopened(topic)
+        } else if let Some(topic) = &self.general_forum_topic_hidden {
+            C::GeneralForumTopicHidden(topic)
+        } else if let Some(topic) = &self.general_forum_topic_unhidden {
+            C::GeneralForumTopicUnhidden(topic)
+        } else if let Some(created) = &self.giveaway_created {
+            C::GiveawayCreated(created)
+        } else if let Some(completed) = &self.giveaway_completed {
+            C::GiveawayCompleted(completed)
+        } else if let Some(scheduled) = &self.video_chat_scheduled {
+            C::VideoChatScheduled(scheduled)
+        } else if let Some(started) = &self.video_chat_started {
+            C::VideoChatStarted(started)
+        } else if let Some(ended) = &self.video_chat_ended {
+            C::VideoChatEnded(ended)
+        } else if let Some(invited) = &self.video_chat_participants_invited {
+            C::VideoChatParticipantsInvited(invited)
+        } else if let Some(data) = &self.web_app_data {
+            C::WebAppData(data)
+        } else {
+            C::Unknown
+        }
+    }
+
+    /// True for messages sent by Telegram about changes in the chat rather than by users
+    pub fn is_service(&self) -> bool {
+        self.content().is_service()
+    }
+
+    /// True if the message carries a file, see [`MessageContent::has_media`]
+    pub fn has_media(&self) -> bool {
+        self.content().has_media()
+    }
+
+    /// The largest size of the photo of the message
+    pub fn best_photo(&self) -> Option<&PhotoSize> {
+        best_photo(self.photo.as_deref()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value};
+
+    use super::*;
+    use crate::ids::{FileId, UserId};
+
+    fn message(fields: Value) -> Message {
+        let mut message = json!({"message_id": 1, "date": 0, "chat": {"id": -5, "type": "group"}});
+        message
+            .as_object_mut()
+            .unwrap()
+            .extend(fields.as_object().unwrap().clone());
+        serde_json::from_value(message).unwrap()
+    }
+
+    fn size(id: &str, width: i64, file_size: i64) -> Value {
+        json!({"file_id": id, "file_unique_id": id, "width": width, "height": width, "file_size": file_size})
+    }
+
+    #[test]
+    fn photo_content() {
+        let photo = message(
+            json!({"photo": [size("s", 90, 1), size("x", 800, 3), size("m", 320, 2)], "caption": "hi"}),
+        );
+        assert!(matches!(photo.content(), MessageContent::Photo(sizes) if sizes.len() == 3));
+        assert!(photo.has_media() && !photo.is_service());
+        assert_eq!(photo.best_photo().unwrap().file_id, FileId::from("x"));
+    }
+
+    #[test]
+    fn venue_before_location() {
+        let venue = message(json!({
+            "location": {"latitude": 1.0, "longitude": 2.0},
+            "venue": {"location": {"latitude": 1.0, "longitude": 2.0}, "title": "T", "address": "A"}
+        }));
+        assert!(matches!(venue.content(), MessageContent::Venue(venue) if venue.title == "T"));
+        assert_eq!(venue.content().content_type(), ContentType::Venue);
+        assert!(!venue.has_media() && venue.best_photo().is_none());
+    }
+
+    #[test]
+    fn service_messages() {
+        let joined =
+            message(json!({"new_chat_members": [{"id": 7, "is_bot": false, "first_name": "B"}]}));
+        assert!(
+            matches!(joined.content(), MessageContent::NewChatMembers([user]) if user.id == UserId(7))
+        );
+        assert!(joined.is_service());
+        let migrated = message(json!({"migrate_to_chat_id": -1001234}));
+        assert!(matches!(
+            migrated.content(),
+            MessageContent::MigrateToChatId(ChatId(-1001234))
+        ));
+        assert!(message(json!({"group_chat_created": true})).is_service());
+    }
+
+    #[test]
+    fn text_and_unknown_content() {
+        assert!(matches!(
+            message(json!({})).content(),
+            MessageContent::Unknown
+        ));
+        let text = message(json!({"text": "hello"}));
+        assert!(matches!(text.content(), MessageContent::Text("hello")));
+        assert_eq!(text.content().content_type(), ContentType::Text);
+        assert!(!text.is_service() && !text.has_media());
+    }
+}
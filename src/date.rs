@@ -0,0 +1,285 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::{
+    bot::{BanChatMember, RestrictChatMember},
+    message::Message,
+    poll::Poll,
+    types::{ChatInviteLink, ChatMemberBanned, ChatMemberRestricted, Integer},
+};
+
+/// Bans and restrictions longer than this are permanent
+pub const MAX_RESTRICTION: Duration = Duration::from_secs(366 * 24 * 60 * 60);
+
+/// Bans and restrictions shorter than 30 seconds are permanent, this leaves room for the time the request takes
+const MIN_RESTRICTION: Duration = Duration::from_secs(60);
+
+/// A point in time the Bot API sends and receives as Unix time in seconds.
+///
+/// Implemented for `SystemTime`, for `chrono::DateTime<Utc>` with the `chrono` feature and for
+/// `time::OffsetDateTime` with the `time` feature.
+pub trait UnixTime {
+    fn from_unix_time(seconds: Integer) -> Self;
+
+    fn unix_time(&self) -> Integer;
+}
+
+impl UnixTime for SystemTime {
+    fn from_unix_time(seconds: Integer) -> Self {
+        let offset = Duration::from_secs(seconds.unsigned_abs());
+        if seconds < 0 {
+            UNIX_EPOCH.checked_sub(offset)
+        } else {
+            UNIX_EPOCH.checked_add(offset)
+        }
+        .unwrap_or(UNIX_EPOCH)
+    }
+
+    fn unix_time(&self) -> Integer {
+        match self.duration_since(UNIX_EPOCH) {
+            Ok(after) => Integer::try_from(after.as_secs()).unwrap_or(Integer::MAX),
+            Err(before) => Integer::saturating_sub_unsigned(0, before.duration().as_secs()),
+        }
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl UnixTime for chrono::DateTime<chrono::Utc> {
+    fn from_unix_time(seconds: Integer) -> Self {
+        chrono::DateTime::from_timestamp(seconds, 0).unwrap_or_default()
+    }
+
+    fn unix_time(&self) -> Integer {
+        self.timestamp()
+    }
+}
+
+#[cfg(feature = "time")]
+impl UnixTime for time::OffsetDateTime {
+    fn from_unix_time(seconds: Integer) -> Self {
+        time::OffsetDateTime::from_unix_timestamp(seconds)
+            .unwrap_or(time::OffsetDateTime::UNIX_EPOCH)
+    }
+
+    fn unix_time(&self) -> Integer {
+        self.unix_timestamp()
+    }
+}
+
+/// A Unix time field where 0 means no date
+fn optional<T: UnixTime>(seconds: Integer) -> Option<T> {
+    (seconds != 0).then(|| T::from_unix_time(seconds))
+}
+
+/// How long a ban or restriction lasts.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Until {
+    Forever,
+    /// From now. Durations under a minute are rounded up to a minute, as Telegram makes anything under 30 seconds
+    /// permanent; durations over `MAX_RESTRICTION` are permanent.
+    For(Duration),
+}
+
+impl Until {
+    /// The until_date to send at `now`, 0 for forever
+    pub fn until_date(&self, now: SystemTime) -> Integer {
+        match *self {
+            Until::For(duration) if duration <= MAX_RESTRICTION => {
+                (now + duration.max(MIN_RESTRICTION)).unix_time()
+            }
+            _ => 0,
+        }
+    }
+}
+
+impl From<Duration> for Until {
+    fn from(duration: Duration) -> Self {
+        Until::For(duration)
+    }
+}
+
+impl BanChatMember {
+    /// Sets when the user is unbanned
+    pub fn until(&mut self, until: impl Into<Until>) -> &mut Self {
+        self.until_date = Some(until.into().until_date(SystemTime::now()));
+        self
+    }
+}
+
+impl RestrictChatMember {
+    /// Sets when the restrictions are lifted
+    pub fn until(&mut self, until: impl Into<Until>) -> &mut Self {
+        self.until_date = Some(until.into().until_date(SystemTime::now()));
+        self
+    }
+}
+
+impl Message {
+    /// Date the message was sent
+    pub fn sent_at<T: UnixTime>(&self) -> T {
+        T::from_unix_time(self.date)
+    }
+
+    /// Date the message was last edited
+    pub fn edited_at<T: UnixTime>(&self) -> Option<T> {
+        self.edit_date.map(T::from_unix_time)
+    }
+}
+
+impl Poll {
+    /// Date the poll will be or was automatically closed
+    pub fn closes_at<T: UnixTime>(&self) -> Option<T> {
+        self.close_date.map(T::from_unix_time)
+    }
+}
+
+impl ChatInviteLink {
+    /// Date the link will expire or has expired
+    pub fn expires_at<T: UnixTime>(&self) -> Option<T> {
+        self.expire_date.map(T::from_unix_time)
+    }
+}
+
+impl ChatMemberRestricted {
+    /// Date the restrictions will be lifted, None if the user is restricted forever
+    pub fn restricted_until<T: UnixTime>(&self) -> Option<T> {
+        optional(self.until_date)
+    }
+}
+
+impl ChatMemberBanned {
+    /// Date the user will be unbanned, None if the user is banned forever
+    pub fn banned_until<T: UnixTime>(&self) -> Option<T> {
+        optional(self.until_date)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::{
+        ids::UserId,
+        types::{ChatID, ChatMember},
+    };
+
+    fn message() -> Message {
+        serde_json::from_value(json!({
+            "message_id": 1, "date": 1700000000, "edit_date": 1700000060,
+            "chat": {"id": 5, "type": "private"}
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn message_times() {
+        let message = message();
+        let sent: SystemTime = message.sent_at();
+        assert_eq!(sent, UNIX_EPOCH + Duration::from_secs(1_700_000_000));
+        assert_eq!(
+            message.edited_at::<SystemTime>().unwrap().unix_time(),
+            1700000060
+        );
+        assert_eq!(SystemTime::from_unix_time(-5).unix_time(), -5);
+    }
+
+    #[test]
+    fn out_of_range_times() {
+        // The range of SystemTime depends on the platform, times out of it are the epoch
+        for seconds in [Integer::MAX, Integer::MIN] {
+            let time = SystemTime::from_unix_time(seconds);
+            assert!(time == UNIX_EPOCH || time.unix_time() == seconds);
+        }
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn chrono_times() {
+        assert_eq!(
+            message()
+                .sent_at::<chrono::DateTime<chrono::Utc>>()
+                .to_rfc3339(),
+            "2023-11-14T22:13:20+00:00"
+        );
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn time_times() {
+        assert_eq!(message().sent_at::<time::OffsetDateTime>().year(), 2023);
+    }
+
+    #[test]
+    fn until_dates() {
+        let now = UNIX_EPOCH + Duration::from_secs(1_000_000);
+        let hours = Until::For(Duration::from_secs(2 * 60 * 60));
+        assert_eq!(hours.until_date(now), 1_007_200);
+        assert_eq!(
+            Until::For(Duration::from_secs(10)).until_date(now),
+            1_000_060
+        );
+        assert_eq!(
+            Until::For(MAX_RESTRICTION).until_date(now),
+            1_000_000 + 366 * 86400
+        );
+        assert_eq!(Until::For(MAX_RESTRICTION * 2).until_date(now), 0);
+        assert_eq!(Until::Forever.until_date(now), 0);
+    }
+
+    #[test]
+    fn ban_request_until() {
+        let mut ban = BanChatMember::new(ChatID::from(-1), UserId(2));
+        ban.until(Duration::from_secs(3600));
+        let until = ban.until_date.unwrap() - SystemTime::now().unix_time();
+        assert!((3599..=3600).contains(&until));
+    }
+
+    fn member(status: &str, until_date: Integer) -> ChatMember {
+        let mut member = json!({
+            "status": status,
+            "user": {"id": 2, "is_bot": false, "first_name": "B"},
+            "until_date": until_date
+        });
+        if status == "restricted" {
+            for permission in [
+                "is_member",
+                "can_send_messages",
+                "can_send_audios",
+                "can_send_documents",
+                "can_send_photos",
+                "can_send_videos",
+                "can_send_video_notes",
+                "can_send_voice_notes",
+                "can_send_polls",
+                "can_send_other_messages",
+                "can_add_web_page_previews",
+                "can_change_info",
+                "can_invite_users",
+                "can_pin_messages",
+                "can_manage_topics",
+            ] {
+                member[permission] = json!(false);
+            }
+        }
+        serde_json::from_value(member).unwrap()
+    }
+
+    #[test]
+    fn restricted_until_from_chat_member() {
+        let ChatMember::ChatMemberRestricted(member) = member("restricted", 1_700_000_000) else {
+            panic!("expected a restricted member");
+        };
+        assert_eq!(
+            member.restricted_until::<SystemTime>(),
+            Some(UNIX_EPOCH + Duration::from_secs(1_700_000_000))
+        );
+    }
+
+    #[test]
+    fn banned_forever_from_chat_member() {
+        let ChatMember::ChatMemberBanned(member) = member("kicked", 0) else {
+            panic!("expected a banned member");
+        };
+        assert_eq!(member.banned_until::<SystemTime>(), None);
+    }
+}
@@ -0,0 +1,546 @@
+use std::{
+    collections::HashMap,
+    fs,
+    future::Future,
+    io,
+    marker::PhantomData,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex, PoisonError},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use crate::{
+    dispatcher::{Context, Dispatcher, Filter},
+    error::Result,
+    ids::{ChatId, UserId},
+    types::{Integer, Update},
+};
+
+/// Identifies a conversation: the chat, the user within the chat and the forum topic.
+#[derive(Clone, Copy, Serialize, Deserialize, Debug, PartialEq, Eq, Hash)]
+pub struct DialogueKey {
+    pub chat_id: ChatId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<UserId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_thread_id: Option<Integer>,
+}
+
+impl DialogueKey {
+    pub fn new(chat_id: impl Into<ChatId>) -> Self {
+        DialogueKey {
+            chat_id: chat_id.into(),
+            user_id: None,
+            message_thread_id: None,
+        }
+    }
+
+    /// The conversation an update belongs to, if the update came from a chat
+    pub fn from_update(update: &Update) -> Option<Self> {
+        let chat_id = update.chat()?.id;
+        let message_thread_id = match update.any_message() {
+            Some(message) => message.message_thread_id,
+            None => update
+                .callback_query
+                .as_ref()
+                .and_then(|q| q.message.as_ref()?.message()?.message_thread_id),
+        };
+        Some(DialogueKey {
+            chat_id,
+            user_id: update.user().map(|user| user.id),
+            message_thread_id,
+        })
+    }
+}
+
+/// A stored dialogue state with the time it was last set.
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct Entry<D> {
+    pub state: D,
+    /// Unix time in milliseconds
+    pub updated_at: Integer,
+}
+
+/// A place to keep dialogue states.
+pub trait Storage<D>: Send + Sync + 'static {
+    fn get(&self, key: &DialogueKey) -> impl Future<Output = Result<Option<Entry<D>>>> + Send;
+
+    fn set(&self, key: DialogueKey, entry: Entry<D>) -> impl Future<Output = Result<()>> + Send;
+
+    fn remove(&self, key: &DialogueKey) -> impl Future<Output = Result<()>> + Send;
+}
+
+/// Keeps dialogue states in memory, they are lost on restart.
+pub struct MemoryStorage<D> {
+    entries: Mutex<HashMap<DialogueKey, Entry<D>>>,
+}
+
+impl<D> MemoryStorage<D> {
+    pub fn new() -> Self {
+        MemoryStorage {
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+}
+
+impl<D> Default for MemoryStorage<D> {
+    fn default() -> Self {
+        MemoryStorage::new()
+    }
+}
+
+impl<D: Clone + Send + 'static> Storage<D> for MemoryStorage<D> {
+    async fn get(&self, key: &DialogueKey) -> Result<Option<Entry<D>>> {
+        Ok(lock(&self.entries).get(key).cloned())
+    }
+
+    async fn set(&self, key: DialogueKey, entry: Entry<D>) -> Result<()> {
+        lock(&self.entries).insert(key, entry);
+        Ok(())
+    }
+
+    async fn remove(&self, key: &DialogueKey) -> Result<()> {
+        lock(&self.entries).remove(key);
+        Ok(())
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct JsonRecord<D> {
+    key: DialogueKey,
+    #[serde(flatten)]
+    entry: Entry<D>,
+}
+
+/// Keeps dialogue states in a JSON file, which is rewritten on every change.
+pub struct JsonStorage<D> {
+    path: PathBuf,
+    entries: Mutex<Entries<D>>,
+    /// Version of the snapshot in the file, held while the file is written
+    written: tokio::sync::Mutex<u64>,
+}
+
+struct Entries<D> {
+    map: HashMap<DialogueKey, Entry<D>>,
+    /// Incremented on every change
+    version: u64,
+}
+
+impl<D: Clone + Serialize + DeserializeOwned> JsonStorage<D> {
+    /// Opens the storage, loading the states saved in `path` if the file exists
+    pub fn new<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        let entries = match fs::read(&path) {
+            Ok(data) => serde_json::from_slice::<Vec<JsonRecord<D>>>(&data)?
+                .into_iter()
+                .map(|record| (record.key, record.entry))
+                .collect(),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => HashMap::new(),
+            Err(err) => return Err(err.into()),
+        };
+        Ok(JsonStorage {
+            path,
+            entries: Mutex::new(Entries {
+                map: entries,
+                version: 0,
+            }),
+            written: tokio::sync::Mutex::new(0),
+        })
+    }
+
+    /// Serializes the entries after a change, the lock is released before the file is written
+    fn snapshot(
+        &self,
+        change: impl FnOnce(&mut HashMap<DialogueKey, Entry<D>>) -> bool,
+    ) -> Result<Option<(u64, Vec<u8>)>> {
+        let mut entries = lock(&self.entries);
+        if !change(&mut entries.map) {
+            return Ok(None);
+        }
+        entries.version += 1;
+        let records: Vec<JsonRecord<&D>> = entries
+            .map
+            .iter()
+            .map(|(key, entry)| JsonRecord {
+                key: *key,
+                entry: Entry {
+                    state: &entry.state,
+                    updated_at: entry.updated_at,
+                },
+            })
+            .collect();
+        Ok(Some((entries.version, serde_json::to_vec(&records)?)))
+    }
+
+    /// Writes a snapshot unless a later one was written meanwhile
+    async fn save(&self, snapshot: Option<(u64, Vec<u8>)>) -> Result<()> {
+        let Some((version, data)) = snapshot else {
+            return Ok(());
+        };
+        let mut written = self.written.lock().await;
+        if *written >= version {
+            return Ok(());
+        }
+        let tmp = self.path.with_extension("tmp");
+        tokio::fs::write(&tmp, data).await?;
+        tokio::fs::rename(&tmp, &self.path).await?;
+        *written = version;
+        Ok(())
+    }
+}
+
+impl<D> Storage<D> for JsonStorage<D>
+where
+    D: Clone + Serialize + DeserializeOwned + Send + 'static,
+{
+    async fn get(&self, key: &DialogueKey) -> Result<Option<Entry<D>>> {
+        Ok(lock(&self.entries).map.get(key).cloned())
+    }
+
+    async fn set(&self, key: DialogueKey, entry: Entry<D>) -> Result<()> {
+        let snapshot = self.snapshot(|entries| {
+            entries.insert(key, entry);
+            true
+        })?;
+        self.save(snapshot).await
+    }
+
+    async fn remove(&self, key: &DialogueKey) -> Result<()> {
+        let snapshot = self.snapshot(|entries| entries.remove(key).is_some())?;
+        self.save(snapshot).await
+    }
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> std::sync::MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+fn now() -> Integer {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_millis() as Integer)
+}
+
+/// Dialogue states of type `D` kept in the storage `St`. A conversation without a stored (or with an expired) state is in `D::default()`.
+pub struct Dialogues<D, St> {
+    storage: Arc<St>,
+    timeout: Option<Duration>,
+    state: PhantomData<fn() -> D>,
+}
+
+impl<D, St> Clone for Dialogues<D, St> {
+    fn clone(&self) -> Self {
+        Dialogues {
+            storage: self.storage.clone(),
+            timeout: self.timeout,
+            state: PhantomData,
+        }
+    }
+}
+
+impl<D, St> Dialogues<D, St>
+where
+    D: Default + Send + 'static,
+    St: Storage<D>,
+{
+    pub fn new(storage: St) -> Self {
+        Dialogues {
+            storage: Arc::new(storage),
+            timeout: None,
+            state: PhantomData,
+        }
+    }
+
+    /// Resets conversations to the default state after this much time without a state change
+    pub fn timeout(&mut self, timeout: Duration) -> &mut Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub fn storage(&self) -> &Arc<St> {
+        &self.storage
+    }
+
+    pub fn dialogue(&self, key: DialogueKey) -> Dialogue<D, St> {
+        Dialogue {
+            key,
+            dialogues: self.clone(),
+        }
+    }
+
+    /// The current state of the conversation, None if there is no state or it has expired
+    pub async fn state(&self, key: &DialogueKey) -> Result<Option<D>> {
+        let Some(entry) = self.storage.get(key).await? else {
+            return Ok(None);
+        };
+        let expired = self
+            .timeout
+            .is_some_and(|timeout| now() - entry.updated_at >= timeout.as_millis() as Integer);
+        if expired {
+            self.storage.remove(key).await?;
+            return Ok(None);
+        }
+        Ok(Some(entry.state))
+    }
+}
+
+/// A handle to the state of one conversation.
+pub struct Dialogue<D, St> {
+    key: DialogueKey,
+    dialogues: Dialogues<D, St>,
+}
+
+impl<D, St> Clone for Dialogue<D, St> {
+    fn clone(&self) -> Self {
+        Dialogue {
+            key: self.key,
+            dialogues: self.dialogues.clone(),
+        }
+    }
+}
+
+impl<D, St> Dialogue<D, St>
+where
+    D: Default + Send + 'static,
+    St: Storage<D>,
+{
+    pub fn key(&self) -> &DialogueKey {
+        &self.key
+    }
+
+    /// The current state, `D::default()` if the conversation has not started or has expired
+    pub async fn get(&self) -> Result<D> {
+        Ok(self.dialogues.state(&self.key).await?.unwrap_or_default())
+    }
+
+    pub async fn update(&self, state: D) -> Result<()> {
+        let entry = Entry {
+            state,
+            updated_at: now(),
+        };
+        self.dialogues.storage.set(self.key, entry).await
+    }
+
+    /// Ends the conversation, returning it to the default state
+    pub async fn exit(&self) -> Result<()> {
+        self.dialogues.storage.remove(&self.key).await
+    }
+}
+
+impl<S: Send + Sync + 'static> Dispatcher<S> {
+    /// Registers a handler for updates matching the filter whose conversation is in a state accepted by `state`.
+    ///
+    /// Updates of the same chat are handled in order, so the state seen by the handler already includes the changes made while handling the previous update.
+    pub fn on_dialogue<D, St, P, F, Fut>(
+        &mut self,
+        dialogues: &Dialogues<D, St>,
+        state: P,
+        filter: Filter,
+        handler: F,
+    ) -> &mut Self
+    where
+        D: Default + Send + 'static,
+        St: Storage<D>,
+        P: Fn(&D) -> bool + Send + Sync + 'static,
+        F: Fn(Context<S>, Dialogue<D, St>, D) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<()>> + Send + 'static,
+    {
+        let dialogues = dialogues.clone();
+        let state = Arc::new(state);
+        let handler = Arc::new(handler);
+        let filter = Filter::new(|u| DialogueKey::from_update(u).is_some()) & filter;
+        self.push(
+            filter,
+            Arc::new(move |cx| {
+                let dialogues = dialogues.clone();
+                let state = state.clone();
+                let handler = handler.clone();
+                Box::pin(async move {
+                    let dialogue = dialogues.dialogue(DialogueKey::from_update(&cx.update)?);
+                    let current = match dialogue.get().await {
+                        Ok(current) => current,
+                        Err(err) => return Some(Err(err)),
+                    };
+                    if !state(&current) {
+                        return None;
+                    }
+                    Some(handler(cx, dialogue, current).await)
+                })
+            }),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{bot::Bot, test_util::message_update};
+
+    #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+    enum Signup {
+        #[default]
+        Start,
+        Name,
+        Age {
+            name: String,
+        },
+    }
+
+    fn temp_file(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("rutel-{name}-{}.json", std::process::id()))
+    }
+
+    #[tokio::test]
+    async fn conversations_per_chat() {
+        let bot = Bot::new("123:abc").unwrap();
+        let dialogues = Dialogues::new(MemoryStorage::new());
+        let mut dispatcher = Dispatcher::new(bot, Mutex::new(Vec::new()));
+        dispatcher
+            .on_dialogue(
+                &dialogues,
+                |s| *s == Signup::Start,
+                Filter::command("signup"),
+                |_, dialogue, _| async move { dialogue.update(Signup::Name).await },
+            )
+            .on_dialogue(
+                &dialogues,
+                |s| *s == Signup::Name,
+                Filter::any(),
+                |cx, dialogue, _| async move {
+                    let name = cx
+                        .message()
+                        .and_then(|m| m.text.clone())
+                        .unwrap_or_default();
+                    dialogue.update(Signup::Age { name }).await
+                },
+            )
+            .on_dialogue(
+                &dialogues,
+                |s| matches!(s, Signup::Age { .. }),
+                Filter::any(),
+                |cx, dialogue, state| async move {
+                    if let Signup::Age { name } = state {
+                        let age = cx
+                            .message()
+                            .and_then(|m| m.text.clone())
+                            .unwrap_or_default();
+                        cx.state.lock().unwrap().push(format!("{name} {age}"));
+                    }
+                    dialogue.exit().await
+                },
+            );
+
+        let handles: Vec<_> = [
+            (1, 10, "hello"),
+            (2, 10, "/signup"),
+            (3, 20, "/signup"),
+            (4, 10, "Ann"),
+            (5, 20, "Bob"),
+            (6, 10, "30"),
+            (7, 20, "41"),
+        ]
+        .into_iter()
+        .map(|(id, chat, text)| dispatcher.dispatch(message_update(id, chat, text)))
+        .collect();
+        for handle in handles {
+            handle.await.unwrap();
+        }
+
+        let mut done = dispatcher.state().lock().unwrap().clone();
+        done.sort();
+        assert_eq!(done, vec!["Ann 30", "Bob 41"]);
+        let key = DialogueKey::from_update(&message_update(8, 10, "")).unwrap();
+        assert_eq!(dialogues.state(&key).await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn json_storage_persists() {
+        let path = temp_file("dialogues-persist");
+        let key = DialogueKey {
+            user_id: Some(UserId(7)),
+            ..DialogueKey::new(-100)
+        };
+        {
+            let dialogues = Dialogues::new(JsonStorage::new(&path).unwrap());
+            let dialogue = dialogues.dialogue(key);
+            assert_eq!(dialogue.get().await.unwrap(), Signup::Start);
+            dialogue
+                .update(Signup::Age {
+                    name: "Ann".to_string(),
+                })
+                .await
+                .unwrap();
+        }
+        let storage = JsonStorage::<Signup>::new(&path).unwrap();
+        let entry = storage.get(&key).await.unwrap().unwrap();
+        assert_eq!(
+            entry.state,
+            Signup::Age {
+                name: "Ann".to_string()
+            }
+        );
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn json_storage_remove() {
+        let path = temp_file("dialogues-remove");
+        let key = DialogueKey::new(-100);
+        let storage = JsonStorage::new(&path).unwrap();
+        storage
+            .set(
+                key,
+                Entry {
+                    state: Signup::Name,
+                    updated_at: 0,
+                },
+            )
+            .await
+            .unwrap();
+        storage.remove(&key).await.unwrap();
+        assert!(JsonStorage::<Signup>::new(&path)
+            .unwrap()
+            .get(&key)
+            .await
+            .unwrap()
+            .is_none());
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn subsecond_timeout() {
+        let mut dialogues = Dialogues::new(MemoryStorage::new());
+        dialogues.timeout(Duration::from_millis(200));
+        let dialogue = dialogues.dialogue(DialogueKey::new(1));
+        dialogue.update(Signup::Name).await.unwrap();
+        assert_eq!(dialogue.get().await.unwrap(), Signup::Name);
+        tokio::time::sleep(Duration::from_millis(250)).await;
+        assert_eq!(dialogue.get().await.unwrap(), Signup::Start);
+    }
+
+    #[tokio::test]
+    async fn json_storage_concurrent_writes() {
+        let path = temp_file("dialogues-concurrent");
+        let dialogues = Dialogues::new(JsonStorage::new(&path).unwrap());
+        let handles: Vec<_> = (1..=20)
+            .map(|chat| {
+                let dialogue = dialogues.dialogue(DialogueKey::new(chat));
+                tokio::spawn(async move { dialogue.update(Signup::Name).await })
+            })
+            .collect();
+        for handle in handles {
+            handle.await.unwrap().unwrap();
+        }
+        let storage = JsonStorage::<Signup>::new(&path).unwrap();
+        for chat in 1..=20 {
+            assert!(storage
+                .get(&DialogueKey::new(chat))
+                .await
+                .unwrap()
+                .is_some());
+        }
+        fs::remove_file(&path).unwrap();
+    }
+}
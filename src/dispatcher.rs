@@ -0,0 +1,754 @@
+use std::{
+    collections::HashMap,
+    future::Future,
+    ops::{BitAnd, BitOr, Not},
+    pin::Pin,
+    sync::{Arc, Mutex, PoisonError},
+    time::{Duration, Instant},
+};
+
+use regex::Regex;
+use tokio::{
+    sync::{oneshot, Semaphore},
+    task::JoinHandle,
+};
+
+use crate::{
+    bot::{Bot, GetUpdates},
+    content::ContentType,
+    error::{Error, Result},
+    ids::{ChatId, MessageId, UserId},
+    inline_mode::InlineQuery,
+    media_group::{Album, MediaGroups},
+    message::Message,
+    types::{CallbackQuery, Chat, ChatType, Update, User},
+    update::AllowedUpdate,
+};
+
+pub(crate) type BoxFuture<T> = Pin<Box<dyn Future<Output = T> + Send>>;
+/// Runs a handler; resolves to None when the handler declines the update and the next matching one should be tried
+pub(crate) type HandlerFn<S> =
+    Arc<dyn Fn(Context<S>) -> BoxFuture<Option<Result<()>>> + Send + Sync>;
+type ErrorFn = Arc<dyn Fn(Error) + Send + Sync>;
+
+const DEFAULT_CONCURRENCY: usize = 32;
+const POLLING_RETRY_DELAY: Duration = Duration::from_secs(1);
+const CHATS_CLEANUP_THRESHOLD: usize = 1024;
+
+/// Everything a handler gets: its own copy of the bot, the shared application state and the update.
+pub struct Context<S> {
+    pub bot: Bot,
+    pub state: Arc<S>,
+    /// For an album, the update of its first part
+    pub update: Arc<Update>,
+    /// The album, when media groups are collected with `Dispatcher::media_groups`
+    pub album: Option<Arc<Album>>,
+}
+
+impl<S> Clone for Context<S> {
+    fn clone(&self) -> Self {
+        Context {
+            bot: self.bot.clone(),
+            state: self.state.clone(),
+            update: self.update.clone(),
+            album: self.album.clone(),
+        }
+    }
+}
+
+impl<S> Context<S> {
+    pub fn message(&self) -> Option<&Message> {
+        self.update.any_message()
+    }
+
+    pub fn callback_query(&self) -> Option<&CallbackQuery> {
+        self.update.callback_query.as_ref()
+    }
+
+    pub fn chat(&self) -> Option<&Chat> {
+        self.update.chat()
+    }
+
+    pub fn user(&self) -> Option<&User> {
+        self.update.user()
+    }
+}
+
+/// A composable predicate over updates. Filters are combined with `&`, `|` and `!`.
+#[derive(Clone)]
+pub struct Filter(Arc<dyn Fn(&Update) -> bool + Send + Sync>);
+
+impl Filter {
+    pub fn new<F>(f: F) -> Self
+    where
+        F: Fn(&Update) -> bool + Send + Sync + 'static,
+    {
+        Filter(Arc::new(f))
+    }
+
+    /// Matches every update
+    pub fn any() -> Self {
+        Filter::new(|_| true)
+    }
+
+    pub fn matches(&self, update: &Update) -> bool {
+        (self.0)(update)
+    }
+
+    pub fn and(self, other: Filter) -> Self {
+        Filter::new(move |u| self.matches(u) && other.matches(u))
+    }
+
+    pub fn or(self, other: Filter) -> Self {
+        Filter::new(move |u| self.matches(u) || other.matches(u))
+    }
+
+    /// Matches updates of the given kind
+    pub fn kind(kind: AllowedUpdate) -> Self {
+        Filter::new(move |u| u.allowed_update() == kind)
+    }
+
+    /// Matches updates from chats of the given type
+    pub fn chat_type(kind: ChatType) -> Self {
+        Filter::new(move |u| u.chat().is_some_and(|chat| chat.kind == kind))
+    }
+
+    pub fn chat_id(id: impl Into<ChatId>) -> Self {
+        let id = id.into();
+        Filter::new(move |u| u.chat().is_some_and(|chat| chat.id == id))
+    }
+
+    pub fn user_id(id: impl Into<UserId>) -> Self {
+        let id = id.into();
+        Filter::new(move |u| u.user().is_some_and(|user| user.id == id))
+    }
+
+    /// Matches messages satisfying the predicate
+    pub fn message<F>(f: F) -> Self
+    where
+        F: Fn(&Message) -> bool + Send + Sync + 'static,
+    {
+        Filter::new(move |u| u.any_message().is_some_and(&f))
+    }
+
+    /// Matches messages whose text or caption satisfies the predicate
+    pub fn text<F>(f: F) -> Self
+    where
+        F: Fn(&str) -> bool + Send + Sync + 'static,
+    {
+        Filter::message(move |m| m.text.as_ref().or(m.caption.as_ref()).is_some_and(|t| f(t)))
+    }
+
+    /// Matches messages whose text or caption matches the regular expression
+    pub fn text_regex(re: Regex) -> Self {
+        Filter::text(move |t| re.is_match(t))
+    }
+
+    /// Matches the “/name” command, also when addressed to a bot as “/name@bot_username”
+    pub fn command(name: &str) -> Self {
+        let name = name.to_string();
+        Filter::message(move |m| command_of(m).is_some_and(|(command, _)| command == name))
+    }
+
+    /// Matches the “/name” command unless it is addressed to a bot other than `username`
+    pub fn command_for(name: &str, username: &str) -> Self {
+        let name = name.to_string();
+        let username = username.trim_start_matches('@').to_string();
+        Filter::message(move |m| {
+            command_of(m).is_some_and(|(command, mention)| {
+                command == name && mention.is_none_or(|bot| bot.eq_ignore_ascii_case(&username))
+            })
+        })
+    }
+
+    /// Matches callback queries whose data starts with the prefix
+    pub fn callback_data_prefix(prefix: &str) -> Self {
+        let prefix = prefix.to_string();
+        Filter::new(move |u| {
+            u.callback_query
+                .as_ref()
+                .and_then(|query| query.data.as_ref())
+                .is_some_and(|data| data.starts_with(&prefix))
+        })
+    }
+
+    /// Matches messages with the given kind of content
+    pub fn content(kind: ContentType) -> Self {
+        Filter::message(move |m| m.content().content_type() == kind)
+    }
+}
+
+fn command_of(message: &Message) -> Option<(&str, Option<&str>)> {
+    let word = message.text.as_deref()?.split_whitespace().next()?;
+    let command = word.strip_prefix('/')?;
+    Some(match command.split_once('@') {
+        Some((command, bot)) => (command, Some(bot)),
+        None => (command, None),
+    })
+}
+
+impl BitAnd for Filter {
+    type Output = Filter;
+
+    fn bitand(self, rhs: Filter) -> Filter {
+        self.and(rhs)
+    }
+}
+
+impl BitOr for Filter {
+    type Output = Filter;
+
+    fn bitor(self, rhs: Filter) -> Filter {
+        self.or(rhs)
+    }
+}
+
+impl Not for Filter {
+    type Output = Filter;
+
+    fn not(self) -> Filter {
+        Filter::new(move |u| !self.matches(u))
+    }
+}
+
+struct Handler<S> {
+    filter: Filter,
+    run: HandlerFn<S>,
+}
+
+impl<S> Clone for Handler<S> {
+    fn clone(&self) -> Self {
+        Handler {
+            filter: self.filter.clone(),
+            run: self.run.clone(),
+        }
+    }
+}
+
+/// Routes updates to the first registered handler whose filter matches (and, for dialogue handlers, whose state matches).
+///
+/// Handlers run concurrently up to the configured limit, while updates from the same chat (or from the same user, for updates without a chat) are always handled one at a time in the order they were dispatched.
+pub struct Dispatcher<S> {
+    bot: Bot,
+    state: Arc<S>,
+    handlers: Arc<Vec<Handler<S>>>,
+    on_error: ErrorFn,
+    semaphore: Arc<Semaphore>,
+    chats: Arc<Mutex<HashMap<ChatId, oneshot::Receiver<()>>>>,
+    media_groups: Option<Arc<Mutex<Albums>>>,
+}
+
+/// Media groups being collected by a dispatcher, with the updates of their parts
+struct Albums {
+    groups: MediaGroups,
+    updates: HashMap<(ChatId, MessageId), Update>,
+    /// The place of each album in the order of its chat, taken when its first part arrived
+    slots: HashMap<(ChatId, String), Slot>,
+}
+
+/// The turn of an update in its chat: the update before it, and the sender finishing the turn
+type Slot = (Option<oneshot::Receiver<()>>, oneshot::Sender<()>);
+
+impl<S> Clone for Dispatcher<S> {
+    fn clone(&self) -> Self {
+        Dispatcher {
+            bot: self.bot.clone(),
+            state: self.state.clone(),
+            handlers: self.handlers.clone(),
+            on_error: self.on_error.clone(),
+            semaphore: self.semaphore.clone(),
+            chats: self.chats.clone(),
+            media_groups: self.media_groups.clone(),
+        }
+    }
+}
+
+impl<S: Send + Sync + 'static> Dispatcher<S> {
+    pub fn new(bot: Bot, state: S) -> Self {
+        Dispatcher {
+            bot,
+            state: Arc::new(state),
+            handlers: Arc::new(Vec::new()),
+            on_error: Arc::new(|_| {}),
+            semaphore: Arc::new(Semaphore::new(DEFAULT_CONCURRENCY)),
+            chats: Arc::new(Mutex::new(HashMap::new())),
+            media_groups: None,
+        }
+    }
+
+    pub fn state(&self) -> &Arc<S> {
+        &self.state
+    }
+
+    /// Maximum number of handlers running at the same time. Defaults to 32.
+    pub fn concurrency(&mut self, limit: usize) -> &mut Self {
+        self.semaphore = Arc::new(Semaphore::new(limit.max(1)));
+        self
+    }
+
+    /// Collects the parts of albums and dispatches each album once, after no part arrived for `debounce`.
+    ///
+    /// Albums are collected from messages, channel posts and business messages and handled by `on_album` handlers;
+    /// other handlers see an album as the update of its first part. Edits of the parts are dispatched one by one.
+    pub fn media_groups(&mut self, debounce: Duration) -> &mut Self {
+        self.media_groups = Some(Arc::new(Mutex::new(Albums {
+            groups: MediaGroups::new(debounce),
+            updates: HashMap::new(),
+            slots: HashMap::new(),
+        })));
+        self
+    }
+
+    /// Sets the callback receiving errors returned by handlers and by polling. Errors are dropped until it is set.
+    pub fn on_error<F>(&mut self, f: F) -> &mut Self
+    where
+        F: Fn(Error) + Send + Sync + 'static,
+    {
+        self.on_error = Arc::new(f);
+        self
+    }
+
+    /// Registers a handler for any update matching the filter
+    pub fn on<F, Fut>(&mut self, filter: Filter, handler: F) -> &mut Self
+    where
+        F: Fn(Context<S>) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<()>> + Send + 'static,
+    {
+        self.push(
+            filter,
+            Arc::new(move |cx| {
+                let fut = handler(cx);
+                Box::pin(async move { Some(fut.await) })
+            }),
+        )
+    }
+
+    /// Registers a handler for new messages matching the filter
+    pub fn on_message<F, Fut>(&mut self, filter: Filter, handler: F) -> &mut Self
+    where
+        F: Fn(Context<S>, Message) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<()>> + Send + 'static,
+    {
+        let filter = (Filter::kind(AllowedUpdate::Message)
+            | Filter::kind(AllowedUpdate::ChannelPost)
+            | Filter::kind(AllowedUpdate::BusinessMessage))
+            & filter;
+        self.push(
+            filter,
+            Arc::new(move |cx| match cx.message().cloned() {
+                Some(payload) => {
+                    let fut = handler(cx, payload);
+                    Box::pin(async move { Some(fut.await) })
+                }
+                None => Box::pin(async { None }),
+            }),
+        )
+    }
+
+    /// Registers a handler for callback queries matching the filter
+    pub fn on_callback_query<F, Fut>(&mut self, filter: Filter, handler: F) -> &mut Self
+    where
+        F: Fn(Context<S>, CallbackQuery) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<()>> + Send + 'static,
+    {
+        let filter = Filter::kind(AllowedUpdate::CallbackQuery) & filter;
+        self.push(
+            filter,
+            Arc::new(move |cx| match cx.update.callback_query.clone() {
+                Some(payload) => {
+                    let fut = handler(cx, payload);
+                    Box::pin(async move { Some(fut.await) })
+                }
+                None => Box::pin(async { None }),
+            }),
+        )
+    }
+
+    /// Registers a handler for inline queries matching the filter
+    pub fn on_inline_query<F, Fut>(&mut self, filter: Filter, handler: F) -> &mut Self
+    where
+        F: Fn(Context<S>, InlineQuery) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<()>> + Send + 'static,
+    {
+        let filter = Filter::kind(AllowedUpdate::InlineQuery) & filter;
+        self.push(
+            filter,
+            Arc::new(move |cx| match cx.update.inline_query.clone() {
+                Some(payload) => {
+                    let fut = handler(cx, payload);
+                    Box::pin(async move { Some(fut.await) })
+                }
+                None => Box::pin(async { None }),
+            }),
+        )
+    }
+
+    /// Registers a handler for albums matching the filter, see `media_groups`
+    pub fn on_album<F, Fut>(&mut self, filter: Filter, handler: F) -> &mut Self
+    where
+        F: Fn(Context<S>, Arc<Album>) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<()>> + Send + 'static,
+    {
+        let filter = (Filter::kind(AllowedUpdate::Message)
+            | Filter::kind(AllowedUpdate::ChannelPost)
+            | Filter::kind(AllowedUpdate::BusinessMessage))
+            & filter;
+        self.push(
+            filter,
+            Arc::new(move |cx| match cx.album.clone() {
+                Some(album) => {
+                    let fut = handler(cx, album);
+                    Box::pin(async move { Some(fut.await) })
+                }
+                None => Box::pin(async { None }),
+            }),
+        )
+    }
+
+    pub(crate) fn push(&mut self, filter: Filter, run: HandlerFn<S>) -> &mut Self {
+        Arc::make_mut(&mut self.handlers).push(Handler { filter, run });
+        self
+    }
+
+    /// Handles a single update, for example one received by a webhook. Must be called from a tokio runtime.
+    ///
+    /// With `media_groups` the parts of an album are buffered: the handle of the first part finishes when the album
+    /// is handled, and updates of the chat dispatched after the first part are handled after the album.
+    pub fn dispatch(&self, update: Update) -> JoinHandle<()> {
+        let Some(albums) = &self.media_groups else {
+            return self.route(update, None, None);
+        };
+        let Some(message) = album_part(&update).cloned() else {
+            return self.route(update, None, None);
+        };
+        let group = (
+            message.chat.id,
+            message.media_group_id.clone().unwrap_or_default(),
+        );
+        let mut albums = lock(albums);
+        let first = !albums.slots.contains_key(&group);
+        if first {
+            let slot = self.enqueue(group.0);
+            albums.slots.insert(group.clone(), slot);
+        }
+        albums
+            .updates
+            .insert((message.chat.id, message.message_id), update);
+        albums.groups.push(message, Instant::now());
+        let handles = self.route_albums(&mut albums, |groups| groups.ready(Instant::now()));
+        drop(albums);
+        let dispatcher = self.clone();
+        tokio::spawn(async move {
+            if first {
+                dispatcher.collect(&group).await;
+            }
+            for handle in handles {
+                let _ = handle.await;
+            }
+        })
+    }
+
+    /// Dispatches the albums that become ready until the album `group` is dispatched, sleeping until the next
+    /// deadline of the media groups in between
+    async fn collect(&self, group: &(ChatId, String)) {
+        let Some(albums) = &self.media_groups else {
+            return;
+        };
+        let mut handles = Vec::new();
+        loop {
+            let deadline = {
+                let albums = lock(albums);
+                if !albums.slots.contains_key(group) {
+                    break;
+                }
+                albums.groups.next_deadline()
+            };
+            let Some(deadline) = deadline else {
+                break;
+            };
+            tokio::time::sleep_until(deadline.into()).await;
+            let mut albums = lock(albums);
+            handles.extend(self.route_albums(&mut albums, |groups| groups.ready(Instant::now())));
+        }
+        for handle in handles {
+            let _ = handle.await;
+        }
+    }
+
+    /// Takes albums from the media groups and dispatches each one as the update of its first part, in the place of
+    /// its chat taken by that part
+    fn route_albums(
+        &self,
+        albums: &mut Albums,
+        take: impl FnOnce(&mut MediaGroups) -> Vec<Album>,
+    ) -> Vec<JoinHandle<()>> {
+        take(&mut albums.groups)
+            .into_iter()
+            .map(|album| {
+                let updates: Vec<Update> = album
+                    .messages
+                    .iter()
+                    .filter_map(|message| {
+                        albums.updates.remove(&(album.chat_id, message.message_id))
+                    })
+                    .collect();
+                let update = updates.into_iter().next().unwrap_or_else(|| Update {
+                    message: album.messages.first().cloned(),
+                    ..Update::default()
+                });
+                let slot = albums
+                    .slots
+                    .remove(&(album.chat_id, album.media_group_id.clone()));
+                self.route(update, Some(Arc::new(album)), slot)
+            })
+            .collect()
+    }
+
+    /// Handles an update in its turn in the chat, given by `slot` or taken now
+    fn route(
+        &self,
+        update: Update,
+        album: Option<Arc<Album>>,
+        slot: Option<Slot>,
+    ) -> JoinHandle<()> {
+        let key = update
+            .chat()
+            .map(|chat| chat.id)
+            .or_else(|| update.user().map(|user| user.id.into()));
+        let (previous, done) = match slot.or_else(|| key.map(|key| self.enqueue(key))) {
+            Some((previous, done)) => (previous, Some(done)),
+            None => (None, None),
+        };
+        let handlers = self.handlers.clone();
+        let cx = Context {
+            bot: self.bot.clone(),
+            state: self.state.clone(),
+            update: Arc::new(update),
+            album,
+        };
+        let semaphore = self.semaphore.clone();
+        let on_error = self.on_error.clone();
+        tokio::spawn(async move {
+            if let Some(previous) = previous {
+                let _ = previous.await;
+            }
+            let _permit = semaphore.acquire_owned().await;
+            for handler in handlers.iter() {
+                if !handler.filter.matches(&cx.update) {
+                    continue;
+                }
+                match (handler.run)(cx.clone()).await {
+                    Some(Err(err)) => on_error(err),
+                    Some(Ok(())) => {}
+                    None => continue,
+                }
+                break;
+            }
+            drop(done);
+        })
+    }
+
+    /// Receives updates with long polling and dispatches them, forever. The offset of `params` is advanced after every batch.
+    ///
+    /// Updates that can't be decoded are skipped and passed to the error handler as `Error::Update`.
+    pub async fn polling(&self, mut params: GetUpdates) {
+        let mut bot = self.bot.clone();
+        loop {
+            match bot.get_updates_lenient(&params).await {
+                Ok(updates) => {
+                    for update in updates {
+                        match update {
+                            Ok(update) => {
+                                params.offset(Some(update.update_id + 1));
+                                self.dispatch(update);
+                            }
+                            Err(err) => {
+                                if let Some(update_id) = err.update_id() {
+                                    params.offset(Some(update_id + 1));
+                                }
+                                (self.on_error)(err);
+                            }
+                        }
+                    }
+                }
+                Err(err) => {
+                    (self.on_error)(err);
+                    tokio::time::sleep(POLLING_RETRY_DELAY).await;
+                }
+            }
+        }
+    }
+
+    /// Dispatches the albums still being collected, for example before shutting down
+    pub fn flush_media_groups(&self) -> Vec<JoinHandle<()>> {
+        match &self.media_groups {
+            Some(albums) => self.route_albums(&mut lock(albums), MediaGroups::flush),
+            None => Vec::new(),
+        }
+    }
+
+    fn enqueue(&self, key: ChatId) -> Slot {
+        let (done, finished) = oneshot::channel();
+        let mut chats = lock(&self.chats);
+        if chats.len() >= CHATS_CLEANUP_THRESHOLD {
+            chats.retain(|_, finished| {
+                !matches!(
+                    finished.try_recv(),
+                    Err(oneshot::error::TryRecvError::Closed)
+                )
+            });
+        }
+        (chats.insert(key, finished), done)
+    }
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> std::sync::MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// The message of an update if it is a new part of an album
+fn album_part(update: &Update) -> Option<&Message> {
+    update
+        .message
+        .as_ref()
+        .or(update.channel_post.as_ref())
+        .or(update.business_message.as_ref())
+        .filter(|message| message.media_group_id.is_some())
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::test_util::{album_part, message_update};
+
+    fn album_dispatcher(debounce: Duration) -> Dispatcher<Mutex<Vec<String>>> {
+        let bot = Bot::new("123:abc").unwrap();
+        let mut dispatcher = Dispatcher::new(bot, Mutex::new(Vec::new()));
+        dispatcher
+            .media_groups(debounce)
+            .on_album(Filter::any(), |cx, album| async move {
+                let ids: Vec<_> = album.messages.iter().map(|m| m.message_id.0).collect();
+                cx.state.lock().unwrap().push(format!(
+                    "album {} {ids:?} {:?} update {}",
+                    album.media_group_id,
+                    album.caption(),
+                    cx.update.update_id
+                ));
+                Ok(())
+            })
+            .on_message(Filter::any(), |cx, msg| async move {
+                cx.state
+                    .lock()
+                    .unwrap()
+                    .push(format!("message {}", msg.message_id.0));
+                Ok(())
+            });
+        dispatcher
+    }
+
+    #[tokio::test]
+    async fn album_is_handled_once_in_its_place() {
+        let dispatcher = album_dispatcher(Duration::from_millis(50));
+        // The parts arrive in two batches
+        let mut handles = vec![
+            dispatcher.dispatch(album_part(6, 10, "a", None)),
+            dispatcher.dispatch(album_part(5, 10, "a", Some("Trip"))),
+        ];
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        handles.push(dispatcher.dispatch(album_part(7, 10, "a", None)));
+        handles.push(dispatcher.dispatch(message_update(8, 10, "text")));
+        for handle in handles {
+            handle.await.unwrap();
+        }
+        assert_eq!(
+            *dispatcher.state().lock().unwrap(),
+            vec![
+                r#"album a [5, 6, 7] Some("Trip") update 5"#.to_string(),
+                "message 8".to_string()
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn flush_dispatches_pending_albums() {
+        let dispatcher = album_dispatcher(Duration::from_secs(60));
+        dispatcher.dispatch(album_part(9, 10, "b", None));
+        for handle in dispatcher.flush_media_groups() {
+            handle.await.unwrap();
+        }
+        assert_eq!(
+            *dispatcher.state().lock().unwrap(),
+            vec!["album b [9] None update 9".to_string()]
+        );
+    }
+
+    #[tokio::test]
+    async fn channel_post_album_keeps_its_update() {
+        let bot = Bot::new("123:abc").unwrap();
+        let mut dispatcher = Dispatcher::new(bot, Mutex::new(Vec::new()));
+        dispatcher.media_groups(Duration::from_millis(20)).on_album(
+            Filter::any(),
+            |cx, album| async move {
+                cx.state.lock().unwrap().push(format!(
+                    "{} parts, channel post {:?}, extra {:?}",
+                    album.messages.len(),
+                    cx.update
+                        .channel_post
+                        .as_ref()
+                        .map(|post| post.message_id.0),
+                    cx.update.extra.get("future_field")
+                ));
+                Ok(())
+            },
+        );
+        let post = |id| {
+            let mut update = album_part(id, 30, "c", None);
+            update.channel_post = update.message.take();
+            update.extra.insert("future_field".into(), json!(1));
+            update
+        };
+        let handles = vec![dispatcher.dispatch(post(2)), dispatcher.dispatch(post(1))];
+        for handle in handles {
+            handle.await.unwrap();
+        }
+        assert_eq!(
+            *dispatcher.state().lock().unwrap(),
+            vec!["2 parts, channel post Some(1), extra Some(Number(1))".to_string()]
+        );
+    }
+
+    #[tokio::test]
+    async fn channel_posts_reach_message_handlers() {
+        let bot = Bot::new("123:abc").unwrap();
+        let mut dispatcher = Dispatcher::new(bot, Mutex::new(Vec::new()));
+        dispatcher.on_message(Filter::text(|t| t == "news"), |cx, msg| async move {
+            cx.state
+                .lock()
+                .unwrap()
+                .push(format!("{} in {}", msg.message_id.0, msg.chat.id.0));
+            Ok(())
+        });
+        let mut post = message_update(3, -100, "news");
+        post.channel_post = post.message.take();
+        let mut business = message_update(4, 20, "news");
+        business.business_message = business.message.take();
+        let handles = vec![dispatcher.dispatch(post), dispatcher.dispatch(business)];
+        for handle in handles {
+            handle.await.unwrap();
+        }
+        assert_eq!(
+            *dispatcher.state().lock().unwrap(),
+            vec!["3 in -100", "4 in 20"]
+        );
+    }
+
+    #[test]
+    fn content_filter() {
+        let photo = album_part(1, 10, "a", None);
+        assert!(Filter::content(ContentType::Photo).matches(&photo));
+        assert!(!Filter::content(ContentType::Text).matches(&photo));
+        assert!(Filter::content(ContentType::Text).matches(&message_update(2, 10, "hi")));
+    }
+}
@@ -0,0 +1,297 @@
+use crate::{
+    games::Game,
+    message::{ExternalReplyInfo, Message, MessageEntity, MessageEntityKind, TextQuote},
+    poll::{Poll, PollOption},
+    types::{Integer, User},
+};
+
+/// Byte range of the UTF-16 span `offset..offset + length` in `text`, None if it is out of bounds or splits a character
+fn byte_range(text: &str, offset: Integer, length: Integer) -> Option<(usize, usize)> {
+    if offset < 0 || length < 0 {
+        return None;
+    }
+    let end = offset + length;
+    let mut units: Integer = 0;
+    let mut start_byte = None;
+    for (i, c) in text.char_indices() {
+        if units == offset {
+            start_byte = Some(i);
+        }
+        if units == end {
+            return Some((start_byte?, i));
+        }
+        units += c.len_utf16() as Integer;
+        if units > end {
+            return None;
+        }
+    }
+    if units == offset {
+        start_byte = Some(text.len());
+    }
+    (units == end).then_some((start_byte?, text.len()))
+}
+
+/// A text with its entities. Entity offsets and lengths are in UTF-16 code units, this view maps them to slices of the text.
+#[derive(Clone, Copy, Debug)]
+pub struct Entities<'a> {
+    text: &'a str,
+    entities: &'a [MessageEntity],
+}
+
+impl<'a> Entities<'a> {
+    pub fn new(text: &'a str, entities: &'a [MessageEntity]) -> Self {
+        Entities { text, entities }
+    }
+
+    fn from_parts(text: Option<&'a str>, entities: Option<&'a Vec<MessageEntity>>) -> Self {
+        Entities {
+            text: text.unwrap_or_default(),
+            entities: entities.map_or(&[], Vec::as_slice),
+        }
+    }
+
+    pub fn text(&self) -> &'a str {
+        self.text
+    }
+
+    pub fn entities(&self) -> &'a [MessageEntity] {
+        self.entities
+    }
+
+    /// Part of the text covered by the entity, None if the entity doesn't fit the text
+    pub fn entity_text(&self, entity: &MessageEntity) -> Option<&'a str> {
+        let (start, end) = byte_range(self.text, entity.offset, entity.length)?;
+        Some(&self.text[start..end])
+    }
+
+    /// Entities with the text they cover, skipping the ones that don't fit the text
+    pub fn iter(&self) -> impl Iterator<Item = (&'a MessageEntity, &'a str)> + 'a {
+        let this = *self;
+        self.entities
+            .iter()
+            .filter_map(move |entity| Some((entity, this.entity_text(entity)?)))
+    }
+
+    /// Texts of the entities of the given type, which must be a type without data (e.g. `MessageEntityKind::Cashtag`)
+    pub fn of_kind(&self, kind: MessageEntityKind) -> impl Iterator<Item = &'a str> + 'a {
+        self.iter()
+            .filter(move |(entity, _)| entity.kind == kind)
+            .map(|(_, text)| text)
+    }
+
+    /// Usernames mentioned as `@username`, without the `@`
+    pub fn mentions(&self) -> impl Iterator<Item = &'a str> + 'a {
+        self.of_kind(MessageEntityKind::Mention)
+            .map(|text| text.strip_prefix('@').unwrap_or(text))
+    }
+
+    /// Hashtags without the `#`
+    pub fn hashtags(&self) -> impl Iterator<Item = &'a str> + 'a {
+        self.of_kind(MessageEntityKind::Hashtag)
+            .map(|text| text.strip_prefix('#').unwrap_or(text))
+    }
+
+    /// URLs written in the text and the targets of text links, in the order they appear
+    pub fn urls(&self) -> impl Iterator<Item = &'a str> + 'a {
+        self.iter().filter_map(|(entity, text)| match &entity.kind {
+            MessageEntityKind::Url => Some(text),
+            MessageEntityKind::TextLink { url } => Some(url.as_str()),
+            _ => None,
+        })
+    }
+
+    /// Bot commands as the command without the `/` and the bot username it is addressed to, if any
+    pub fn bot_commands(&self) -> impl Iterator<Item = (&'a str, Option<&'a str>)> + 'a {
+        self.of_kind(MessageEntityKind::BotCommand).map(|text| {
+            let text = text.strip_prefix('/').unwrap_or(text);
+            match text.split_once('@') {
+                Some((command, bot)) => (command, Some(bot)),
+                None => (text, None),
+            }
+        })
+    }
+
+    /// Users mentioned without a username, with the text of the mention
+    pub fn text_mentions(&self) -> impl Iterator<Item = (&'a User, &'a str)> + 'a {
+        self.iter().filter_map(|(entity, text)| match &entity.kind {
+            MessageEntityKind::TextMention { user } => Some((user, text)),
+            _ => None,
+        })
+    }
+}
+
+impl Message {
+    /// Entities of the message text
+    pub fn parse_entities(&self) -> Entities<'_> {
+        Entities::from_parts(self.text.as_deref(), self.entities.as_ref())
+    }
+
+    /// Entities of the message caption
+    pub fn parse_caption_entities(&self) -> Entities<'_> {
+        Entities::from_parts(self.caption.as_deref(), self.caption_entities.as_ref())
+    }
+
+    /// Part of the message text covered by the entity
+    pub fn entity_text(&self, entity: &MessageEntity) -> Option<&str> {
+        self.parse_entities().entity_text(entity)
+    }
+
+    /// Part of the message caption covered by the entity
+    pub fn caption_entity_text(&self, entity: &MessageEntity) -> Option<&str> {
+        self.parse_caption_entities().entity_text(entity)
+    }
+
+    /// Usernames mentioned in the text or caption, without the `@`
+    pub fn mentions(&self) -> impl Iterator<Item = &str> {
+        self.parse_entities()
+            .mentions()
+            .chain(self.parse_caption_entities().mentions())
+    }
+
+    /// Hashtags in the text or caption, without the `#`
+    pub fn hashtags(&self) -> impl Iterator<Item = &str> {
+        self.parse_entities()
+            .hashtags()
+            .chain(self.parse_caption_entities().hashtags())
+    }
+
+    /// URLs and text link targets in the text or caption
+    pub fn urls(&self) -> impl Iterator<Item = &str> {
+        self.parse_entities()
+            .urls()
+            .chain(self.parse_caption_entities().urls())
+    }
+
+    /// Bot commands in the text or caption, with the bot username they are addressed to
+    pub fn bot_commands(&self) -> impl Iterator<Item = (&str, Option<&str>)> {
+        self.parse_entities()
+            .bot_commands()
+            .chain(self.parse_caption_entities().bot_commands())
+    }
+
+    /// Users mentioned without a username in the text or caption
+    pub fn text_mentions(&self) -> impl Iterator<Item = (&User, &str)> {
+        self.parse_entities()
+            .text_mentions()
+            .chain(self.parse_caption_entities().text_mentions())
+    }
+}
+
+impl TextQuote {
+    pub fn parse_entities(&self) -> Entities<'_> {
+        Entities::from_parts(Some(&self.text), self.entities.as_ref())
+    }
+
+    /// Part of the quote covered by the entity
+    pub fn entity_text(&self, entity: &MessageEntity) -> Option<&str> {
+        self.parse_entities().entity_text(entity)
+    }
+}
+
+impl ExternalReplyInfo {
+    /// Entities of the text carried by the replied message: the game text or the poll question.
+    /// The quoted part of a text message is in `Message::quote`.
+    pub fn parse_entities(&self) -> Entities<'_> {
+        match (&self.game, &self.poll) {
+            (Some(game), _) => game.parse_entities(),
+            (_, Some(poll)) => poll.parse_question_entities(),
+            _ => Entities::new("", &[]),
+        }
+    }
+}
+
+impl Game {
+    pub fn parse_entities(&self) -> Entities<'_> {
+        Entities::from_parts(self.text.as_deref(), self.text_entities.as_ref())
+    }
+}
+
+impl Poll {
+    pub fn parse_question_entities(&self) -> Entities<'_> {
+        Entities::from_parts(Some(&self.question), self.question_entities.as_ref())
+    }
+
+    pub fn parse_explanation_entities(&self) -> Entities<'_> {
+        Entities::from_parts(
+            self.explanation.as_deref(),
+            self.explanation_entities.as_ref(),
+        )
+    }
+}
+
+impl PollOption {
+    pub fn parse_entities(&self) -> Entities<'_> {
+        Entities::from_parts(Some(&self.text), self.text_entities.as_ref())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::ids::UserId;
+
+    fn message() -> Message {
+        serde_json::from_value(json!({
+            "message_id": 1,
+            "date": 0,
+            "chat": {"id": 1, "type": "private"},
+            "text": "😀 @ann #tag /start@bot https://x.y 👍🏽link",
+            "entities": [
+                {"type": "mention", "offset": 3, "length": 4},
+                {"type": "hashtag", "offset": 8, "length": 4},
+                {"type": "bot_command", "offset": 13, "length": 10},
+                {"type": "url", "offset": 24, "length": 11},
+                {"type": "text_link", "offset": 36, "length": 8, "url": "https://t.me"},
+                {"type": "bold", "offset": 1, "length": 2},
+                {"type": "italic", "offset": 40, "length": 9}
+            ],
+            "quote": {
+                "text": "ü😀x",
+                "entities": [{"type": "text_mention", "offset": 1, "length": 3,
+                              "user": {"id": 9, "is_bot": false, "first_name": "Q"}}],
+                "position": 0
+            }
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn entity_text_in_utf16() {
+        let msg = message();
+        let entities = msg.entities.as_ref().unwrap();
+        assert_eq!(msg.entity_text(&entities[4]), Some("👍🏽link"));
+        // Starts inside the surrogate pair of 😀
+        assert_eq!(msg.entity_text(&entities[5]), None);
+        assert_eq!(msg.entity_text(&entities[6]), None);
+        assert_eq!(msg.parse_entities().iter().count(), 5);
+    }
+
+    #[test]
+    fn extract_entities_by_kind() {
+        let msg = message();
+        assert_eq!(msg.mentions().collect::<Vec<_>>(), vec!["ann"]);
+        assert_eq!(msg.hashtags().collect::<Vec<_>>(), vec!["tag"]);
+        assert_eq!(
+            msg.bot_commands().collect::<Vec<_>>(),
+            vec![("start", Some("bot"))]
+        );
+        assert_eq!(
+            msg.urls().collect::<Vec<_>>(),
+            vec!["https://x.y", "https://t.me"]
+        );
+    }
+
+    #[test]
+    fn quote_entities() {
+        let msg = message();
+        let quote = msg.quote.as_ref().unwrap();
+        let mentions: Vec<_> = quote
+            .parse_entities()
+            .text_mentions()
+            .map(|(user, text)| (user.id, text))
+            .collect();
+        assert_eq!(mentions, vec![(UserId(9), "😀x")]);
+    }
+}
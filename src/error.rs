@@ -1,7 +1,12 @@
-use std::{io, result};
+use std::{io, result, time::Duration};
 
 use thiserror::Error;
 
+use crate::{
+    formatting::ParseError,
+    types::{Integer, ResponseParameters},
+};
+
 pub type Result<T> = result::Result<T, Error>;
 
 #[derive(Error, Debug)]
@@ -20,6 +25,117 @@ pub enum Error {
     NetC(#[from] netc::error::Error),
     #[error("Error Response: {0}")]
     Description(String),
+    #[error("Error Response {code}: {description}")]
+    Api {
+        code: Integer,
+        description: String,
+        parameters: Option<ResponseParameters>,
+    },
     #[error("Error Response with no description")]
     NoDescription,
+    #[error("invalid bot token: {0}")]
+    InvalidToken(String),
+    #[error("can't decode update {update_id}: {source}")]
+    Update {
+        update_id: Integer,
+        source: serde_json::Error,
+    },
+    #[error("invalid {field}: {reason}")]
+    Invalid { field: String, reason: String },
+    #[error("markup error: {0}")]
+    Markup(#[from] ParseError),
+    /// The message of an error that contained the bot token and can't be rebuilt with the token removed
+    #[error("{0}")]
+    Redacted(String),
+}
+
+impl Error {
+    /// True for the error returned when an edit leaves the message as it was
+    pub fn is_message_not_modified(&self) -> bool {
+        self.description()
+            .is_some_and(|description| description.contains("message is not modified"))
+    }
+
+    /// The description of an error response of the Bot API
+    pub fn description(&self) -> Option<&str> {
+        match self {
+            Error::Description(description) | Error::Api { description, .. } => Some(description),
+            _ => None,
+        }
+    }
+
+    /// The error code of an error response of the Bot API, e.g. 400, 403 or 429
+    pub fn code(&self) -> Option<Integer> {
+        match self {
+            Error::Api { code, .. } => Some(*code),
+            _ => None,
+        }
+    }
+
+    /// How long to wait before repeating a request that exceeded flood control
+    pub fn retry_after(&self) -> Option<Duration> {
+        match self {
+            Error::Api {
+                parameters: Some(parameters),
+                ..
+            } => parameters
+                .retry_after
+                .map(|seconds| Duration::from_secs(seconds.max(0) as u64)),
+            _ => None,
+        }
+    }
+
+    /// The id of the update that couldn't be decoded
+    pub fn update_id(&self) -> Option<Integer> {
+        match self {
+            Error::Update { update_id, .. } => Some(*update_id),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn message_not_modified() {
+        assert!(Error::Description(
+            "Bad Request: message is not modified: specified new message content and reply markup are exactly the same".to_string()
+        )
+        .is_message_not_modified());
+        assert!(
+            !Error::Description("Bad Request: chat not found".to_string())
+                .is_message_not_modified()
+        );
+    }
+
+    #[test]
+    fn flood_control_error() {
+        let flood = Error::Api {
+            code: 429,
+            description: "Too Many Requests: retry after 7".to_string(),
+            parameters: serde_json::from_value(json!({"retry_after": 7})).unwrap(),
+        };
+        assert_eq!(flood.code(), Some(429));
+        assert_eq!(flood.retry_after(), Some(Duration::from_secs(7)));
+        assert_eq!(
+            flood.to_string(),
+            "Error Response 429: Too Many Requests: retry after 7"
+        );
+    }
+
+    #[test]
+    fn api_error_without_parameters() {
+        let not_modified = Error::Api {
+            code: 400,
+            description: "Bad Request: message is not modified".to_string(),
+            parameters: None,
+        };
+        assert!(not_modified.is_message_not_modified());
+        assert_eq!(not_modified.retry_after(), None);
+        assert_eq!(Error::NoResult.code(), None);
+    }
 }
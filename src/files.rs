@@ -1,12 +1,13 @@
 use serde::{Deserialize, Serialize};
 
+use crate::ids::FileId;
 use crate::types::Integer;
 
 /// This object represents one size of a photo or a file / sticker thumbnail.
 #[derive(Clone, Serialize, Deserialize, Debug)]
 pub struct PhotoSize {
     /// Identifier for this file, which can be used to download or reuse the file
-    pub file_id: String,
+    pub file_id: FileId,
     /// Unique identifier for this file, which is supposed to be the same over time and for different bots. Can't be used to download or reuse the file.
     pub file_unique_id: String,
     /// Photo width
@@ -22,7 +23,7 @@ pub struct PhotoSize {
 #[derive(Clone, Serialize, Deserialize, Debug)]
 pub struct Animation {
     /// Identifier for this file, which can be used to download or reuse the file
-    pub file_id: String,
+    pub file_id: FileId,
     /// Unique identifier for this file, which is supposed to be the same over time and for different bots. Can't be used to download or reuse the file.
     pub file_unique_id: String,
     /// Video width as defined by sender
@@ -49,7 +50,7 @@ pub struct Animation {
 #[derive(Clone, Serialize, Deserialize, Debug)]
 pub struct Audio {
     /// Identifier for this file, which can be used to download or reuse the file
-    pub file_id: String,
+    pub file_id: FileId,
     /// Unique identifier for this file, which is supposed to be the same over time and for different bots. Can't be used to download or reuse the file.
     pub file_unique_id: String,
     /// Duration of the audio in seconds as defined by sender
@@ -78,7 +79,7 @@ pub struct Audio {
 #[derive(Clone, Serialize, Deserialize, Debug)]
 pub struct Document {
     /// Identifier for this file, which can be used to download or reuse the file
-    pub file_id: String,
+    pub file_id: FileId,
     /// Unique identifier for this file, which is supposed to be the same over time and for different bots. Can't be used to download or reuse the file.
     pub file_unique_id: String,
     /// Optional. Document thumbnail as defined by sender
@@ -99,7 +100,7 @@ pub struct Document {
 #[derive(Clone, Serialize, Deserialize, Debug)]
 pub struct Video {
     /// Identifier for this file, which can be used to download or reuse the file
-    pub file_id: String,
+    pub file_id: FileId,
     /// Unique identifier for this file, which is supposed to be the same over time and for different bots. Can't be used to download or reuse the file.
     pub file_unique_id: String,
     /// Video width as defined by sender
@@ -126,7 +127,7 @@ pub struct Video {
 #[derive(Clone, Serialize, Deserialize, Debug)]
 pub struct VideoNote {
     /// Identifier for this file, which can be used to download or reuse the file
-    pub file_id: String,
+    pub file_id: FileId,
     /// Unique identifier for this file, which is supposed to be the same over time and for different bots. Can't be used to download or reuse the file.
     pub file_unique_id: String,
     /// Video width and height (diameter of the video message) as defined by sender
@@ -144,7 +145,7 @@ pub struct VideoNote {
 /// This object represents a voice note.
 #[derive(Clone, Serialize, Deserialize, Debug)]
 pub struct Voice {
-    pub file_id: String,
+    pub file_id: FileId,
     pub file_unique_id: String,
     pub duration: Integer,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -156,7 +157,7 @@ pub struct Voice {
 /// This object represents a file ready to be downloaded. The file can be downloaded via the link [https://api.telegram.org/file/bot<token>/<file_path>](https://api.telegram.org/file/bot<token>/<file_path>). It is guaranteed that the link will be valid for at least 1 hour. When the link expires, a new one can be requested by calling getFile. Maximum file size to download is 20 MB
 #[derive(Clone, Serialize, Deserialize, Debug)]
 pub struct File {
-    pub file_id: String,
+    pub file_id: FileId,
     pub file_unique_id: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub file_size: Option<Integer>,
@@ -0,0 +1,1552 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::{
+    message::{Message, MessageEntity, MessageEntityKind},
+    types::{Integer, User},
+};
+
+/// Mode for parsing entities in the text. See formatting options for more details.
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Clone, Copy, Serialize, Deserialize, Debug, PartialEq, Eq, Hash)]
+pub enum ParseMode {
+    MarkdownV2,
+    HTML,
+    /// Legacy mode, kept for backward compatibility
+    Markdown,
+}
+
+impl fmt::Display for ParseMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            ParseMode::MarkdownV2 => "MarkdownV2",
+            ParseMode::HTML => "HTML",
+            ParseMode::Markdown => "Markdown",
+        })
+    }
+}
+
+/// Length of the text in UTF-16 code units, the unit of entity offsets and lengths
+pub fn utf16_len(text: &str) -> Integer {
+    text.encode_utf16().count() as Integer
+}
+
+/// Builds a text together with its formatting entities, so that no parse mode or escaping is needed.
+///
+/// Formatted parts accept plain strings or another `RichText`, which allows nesting:
+/// `RichText::new().text("Hi ").bold(RichText::new().italic("there"))`.
+#[derive(Clone, Debug, Default)]
+pub struct RichText {
+    text: String,
+    entities: Vec<MessageEntity>,
+    len: Integer,
+}
+
+impl RichText {
+    pub fn new() -> Self {
+        RichText::default()
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    pub fn entities(&self) -> &[MessageEntity] {
+        &self.entities
+    }
+
+    /// Length of the text in UTF-16 code units
+    pub fn len_utf16(&self) -> Integer {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.text.is_empty()
+    }
+
+    /// The text and the entities to send, e.g. as `text` and `entities` of `SendMessage`
+    pub fn build(&self) -> (String, Vec<MessageEntity>) {
+        (self.text.clone(), self.entities.clone())
+    }
+
+    /// Appends plain text, or another `RichText` with its entities
+    pub fn text(&mut self, content: impl Into<RichText>) -> &mut Self {
+        let content = content.into();
+        self.append(content);
+        self
+    }
+
+    pub fn bold(&mut self, content: impl Into<RichText>) -> &mut Self {
+        self.entity(MessageEntityKind::Bold, content)
+    }
+
+    pub fn italic(&mut self, content: impl Into<RichText>) -> &mut Self {
+        self.entity(MessageEntityKind::Italic, content)
+    }
+
+    pub fn underline(&mut self, content: impl Into<RichText>) -> &mut Self {
+        self.entity(MessageEntityKind::Underline, content)
+    }
+
+    pub fn strikethrough(&mut self, content: impl Into<RichText>) -> &mut Self {
+        self.entity(MessageEntityKind::Strikethrough, content)
+    }
+
+    pub fn spoiler(&mut self, content: impl Into<RichText>) -> &mut Self {
+        self.entity(MessageEntityKind::Spoiler, content)
+    }
+
+    /// Monowidth string. Entities can't be nested inside code, so the content is plain text.
+    pub fn code(&mut self, content: &str) -> &mut Self {
+        self.entity(MessageEntityKind::Code, content)
+    }
+
+    /// Monowidth block, optionally highlighted as the given programming language
+    pub fn pre(&mut self, content: &str, language: Option<&str>) -> &mut Self {
+        let language = language.map(str::to_string);
+        self.entity(MessageEntityKind::Pre { language }, content)
+    }
+
+    /// Clickable text opening `url`
+    pub fn text_link(&mut self, content: impl Into<RichText>, url: &str) -> &mut Self {
+        let url = url.to_string();
+        self.entity(MessageEntityKind::TextLink { url }, content)
+    }
+
+    /// Mention of a user without a username
+    pub fn text_mention(&mut self, content: impl Into<RichText>, user: User) -> &mut Self {
+        self.entity(MessageEntityKind::TextMention { user }, content)
+    }
+
+    /// Custom emoji shown in place of `emoji`, which must be a single regular emoji
+    pub fn custom_emoji(&mut self, emoji: &str, custom_emoji_id: &str) -> &mut Self {
+        let custom_emoji_id = custom_emoji_id.to_string();
+        self.entity(MessageEntityKind::CustomEmoji { custom_emoji_id }, emoji)
+    }
+
+    pub fn blockquote(&mut self, content: impl Into<RichText>) -> &mut Self {
+        self.entity(MessageEntityKind::Blockquote, content)
+    }
+
+    /// Blockquote collapsed by default
+    pub fn expandable_blockquote(&mut self, content: impl Into<RichText>) -> &mut Self {
+        self.entity(MessageEntityKind::ExpandableBlockquote, content)
+    }
+
+    /// Appends the content covered by an entity of the given type. Empty content gets no entity.
+    pub fn entity(&mut self, kind: MessageEntityKind, content: impl Into<RichText>) -> &mut Self {
+        let content = content.into();
+        if content.len > 0 {
+            self.entities
+                .push(MessageEntity::new(kind, self.len, content.len));
+        }
+        self.append(content);
+        self
+    }
+
+    fn append(&mut self, content: RichText) {
+        let offset = self.len;
+        self.text.push_str(&content.text);
+        self.entities
+            .extend(content.entities.into_iter().map(|mut entity| {
+                entity.offset += offset;
+                entity
+            }));
+        self.len += content.len;
+    }
+}
+
+impl From<&str> for RichText {
+    fn from(text: &str) -> Self {
+        RichText {
+            text: text.to_string(),
+            entities: Vec::new(),
+            len: utf16_len(text),
+        }
+    }
+}
+
+impl From<String> for RichText {
+    fn from(text: String) -> Self {
+        let len = utf16_len(&text);
+        RichText {
+            text,
+            entities: Vec::new(),
+            len,
+        }
+    }
+}
+
+impl From<&mut RichText> for RichText {
+    fn from(text: &mut RichText) -> Self {
+        text.clone()
+    }
+}
+
+impl From<RichText> for (String, Vec<MessageEntity>) {
+    fn from(text: RichText) -> Self {
+        (text.text, text.entities)
+    }
+}
+
+const MARKDOWN_V2_RESERVED: &str = "_*[]()~`>#+-=|{}.!\\";
+
+/// Escapes `text` so that it is shown as is when sent with the given parse mode
+pub fn escape(text: &str, mode: ParseMode) -> String {
+    match mode {
+        ParseMode::MarkdownV2 => escape_markdown_v2(text),
+        ParseMode::HTML => escape_html(text),
+        ParseMode::Markdown => escape_with(text, "_*`[", '\\'),
+    }
+}
+
+/// Escapes every MarkdownV2 reserved character outside of code entities
+pub fn escape_markdown_v2(text: &str) -> String {
+    escape_with(text, MARKDOWN_V2_RESERVED, '\\')
+}
+
+/// Escapes text inside MarkdownV2 `pre` and `code` entities
+pub fn escape_markdown_v2_code(text: &str) -> String {
+    escape_with(text, "`\\", '\\')
+}
+
+/// Escapes the url part of a MarkdownV2 inline link
+pub fn escape_markdown_v2_url(text: &str) -> String {
+    escape_with(text, ")\\", '\\')
+}
+
+/// Escapes `<`, `>`, `&` and `"`, so the text can be used both as content and as an attribute value
+pub fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '&' => escaped.push_str("&amp;"),
+            '"' => escaped.push_str("&quot;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+fn escape_with(text: &str, reserved: &str, escape: char) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if reserved.contains(c) {
+            escaped.push(escape);
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// Renders a text with its entities (e.g. `Message::text` and `Message::entities`) as HTML markup
+pub fn to_html(text: &str, entities: &[MessageEntity]) -> String {
+    Renderer::new(ParseMode::HTML).render(text, entities)
+}
+
+/// Renders a text with its entities (e.g. `Message::text` and `Message::entities`) as MarkdownV2 markup
+pub fn to_markdown_v2(text: &str, entities: &[MessageEntity]) -> String {
+    Renderer::new(ParseMode::MarkdownV2).render(text, entities)
+}
+
+struct Renderer<'a> {
+    mode: ParseMode,
+    out: String,
+    open: Vec<&'a MessageEntity>,
+    // The last thing written was a MarkdownV2 marker ending with `_`
+    underscore: bool,
+}
+
+fn end(entity: &MessageEntity) -> Integer {
+    entity.offset + entity.length
+}
+
+impl<'a> Renderer<'a> {
+    fn new(mode: ParseMode) -> Self {
+        Renderer {
+            mode,
+            out: String::new(),
+            open: Vec::new(),
+            underscore: false,
+        }
+    }
+
+    fn render(mut self, text: &str, entities: &'a [MessageEntity]) -> String {
+        let mut sorted: Vec<&MessageEntity> = entities.iter().filter(|e| e.length > 0).collect();
+        sorted.sort_by_key(|e| (e.offset, -e.length));
+        let mut pending = sorted.into_iter().peekable();
+        let mut pos: Integer = 0;
+        for c in text.chars() {
+            self.close_until(pos);
+            while let Some(entity) = pending.next_if(|e| e.offset <= pos) {
+                self.open(entity);
+            }
+            self.push_char(c);
+            pos += c.len_utf16() as Integer;
+        }
+        self.close_until(Integer::MAX);
+        self.out
+    }
+
+    /// Closes the entities ending at or before `pos`, reopening the ones nested in them which continue
+    fn close_until(&mut self, pos: Integer) {
+        while let Some(i) = self.open.iter().position(|e| end(e) <= pos) {
+            let reopen = self.open.split_off(i + 1);
+            for entity in reopen.iter().rev() {
+                self.close(entity);
+            }
+            let entity = self.open.pop().expect("position is in bounds");
+            self.close(entity);
+            for entity in reopen.into_iter().filter(|e| end(e) > pos) {
+                self.open(entity);
+            }
+        }
+    }
+
+    fn in_code(&self) -> bool {
+        self.open.iter().any(|e| {
+            matches!(
+                e.kind,
+                MessageEntityKind::Code | MessageEntityKind::Pre { .. }
+            )
+        })
+    }
+
+    fn in_blockquote(&self) -> bool {
+        self.open.iter().any(|e| {
+            matches!(
+                e.kind,
+                MessageEntityKind::Blockquote | MessageEntityKind::ExpandableBlockquote
+            )
+        })
+    }
+
+    fn push_char(&mut self, c: char) {
+        self.underscore = false;
+        let mut buf = [0; 4];
+        let c = &*c.encode_utf8(&mut buf);
+        match self.mode {
+            ParseMode::HTML => self.out.push_str(&escape_html(c)),
+            _ if self.in_code() => self.out.push_str(&escape_markdown_v2_code(c)),
+            _ => {
+                self.out.push_str(&escape_markdown_v2(c));
+                if c == "\n" && self.in_blockquote() {
+                    self.out.push('>');
+                }
+            }
+        }
+    }
+
+    fn marker(&mut self, marker: &str) {
+        if self.underscore && marker.starts_with('_') {
+            // Separates italic from underline, Telegram ignores the carriage return
+            self.out.push('\r');
+        }
+        self.out.push_str(marker);
+        self.underscore = marker.ends_with('_');
+    }
+
+    fn open(&mut self, entity: &'a MessageEntity) {
+        let markup = match self.mode {
+            ParseMode::HTML => html_open(&entity.kind),
+            _ => markdown_v2_open(&entity.kind),
+        };
+        if let Some(markup) = markup {
+            self.marker(&markup);
+        }
+        self.open.push(entity);
+    }
+
+    fn close(&mut self, entity: &MessageEntity) {
+        let markup = match self.mode {
+            ParseMode::HTML => html_close(&entity.kind),
+            _ => markdown_v2_close(&entity.kind),
+        };
+        if let Some(markup) = markup {
+            self.marker(&markup);
+        }
+    }
+}
+
+fn mention_url(user: &User) -> String {
+    format!("tg://user?id={}", user.id)
+}
+
+fn html_open(kind: &MessageEntityKind) -> Option<String> {
+    let tag = match kind {
+        MessageEntityKind::Bold => "<b>".to_string(),
+        MessageEntityKind::Italic => "<i>".to_string(),
+        MessageEntityKind::Underline => "<u>".to_string(),
+        MessageEntityKind::Strikethrough => "<s>".to_string(),
+        MessageEntityKind::Spoiler => "<tg-spoiler>".to_string(),
+        MessageEntityKind::Code => "<code>".to_string(),
+        MessageEntityKind::Pre {
+            language: Some(language),
+        } => format!("<pre><code class=\"language-{}\">", escape_html(language)),
+        MessageEntityKind::Pre { language: None } => "<pre>".to_string(),
+        MessageEntityKind::TextLink { url } => format!("<a href=\"{}\">", escape_html(url)),
+        MessageEntityKind::TextMention { user } => format!("<a href=\"{}\">", mention_url(user)),
+        MessageEntityKind::CustomEmoji { custom_emoji_id } => {
+            format!("<tg-emoji emoji-id=\"{}\">", escape_html(custom_emoji_id))
+        }
+        MessageEntityKind::Blockquote => "<blockquote>".to_string(),
+        MessageEntityKind::ExpandableBlockquote => "<blockquote expandable>".to_string(),
+        _ => return None,
+    };
+    Some(tag)
+}
+
+fn html_close(kind: &MessageEntityKind) -> Option<String> {
+    let tag = match kind {
+        MessageEntityKind::Bold => "</b>",
+        MessageEntityKind::Italic => "</i>",
+        MessageEntityKind::Underline => "</u>",
+        MessageEntityKind::Strikethrough => "</s>",
+        MessageEntityKind::Spoiler => "</tg-spoiler>",
+        MessageEntityKind::Code => "</code>",
+        MessageEntityKind::Pre { language: Some(_) } => "</code></pre>",
+        MessageEntityKind::Pre { language: None } => "</pre>",
+        MessageEntityKind::TextLink { .. } | MessageEntityKind::TextMention { .. } => "</a>",
+        MessageEntityKind::CustomEmoji { .. } => "</tg-emoji>",
+        MessageEntityKind::Blockquote | MessageEntityKind::ExpandableBlockquote => "</blockquote>",
+        _ => return None,
+    };
+    Some(tag.to_string())
+}
+
+fn markdown_v2_open(kind: &MessageEntityKind) -> Option<String> {
+    let marker = match kind {
+        MessageEntityKind::Bold => "*",
+        MessageEntityKind::Italic => "_",
+        MessageEntityKind::Underline => "__",
+        MessageEntityKind::Strikethrough => "~",
+        MessageEntityKind::Spoiler => "||",
+        MessageEntityKind::Code => "`",
+        MessageEntityKind::Pre { language } => {
+            return Some(format!("```{}\n", language.as_deref().unwrap_or_default()))
+        }
+        MessageEntityKind::TextLink { .. } | MessageEntityKind::TextMention { .. } => "[",
+        MessageEntityKind::CustomEmoji { .. } => "![",
+        MessageEntityKind::Blockquote => ">",
+        MessageEntityKind::ExpandableBlockquote => "**>",
+        _ => return None,
+    };
+    Some(marker.to_string())
+}
+
+fn markdown_v2_close(kind: &MessageEntityKind) -> Option<String> {
+    let marker = match kind {
+        MessageEntityKind::Bold => "*".to_string(),
+        MessageEntityKind::Italic => "_".to_string(),
+        MessageEntityKind::Underline => "__".to_string(),
+        MessageEntityKind::Strikethrough => "~".to_string(),
+        MessageEntityKind::Spoiler | MessageEntityKind::ExpandableBlockquote => "||".to_string(),
+        MessageEntityKind::Code => "`".to_string(),
+        MessageEntityKind::Pre { .. } => "```".to_string(),
+        MessageEntityKind::TextLink { url } => format!("]({})", escape_markdown_v2_url(url)),
+        MessageEntityKind::TextMention { user } => format!("]({})", mention_url(user)),
+        MessageEntityKind::CustomEmoji { custom_emoji_id } => format!(
+            "](tg://emoji?id={})",
+            escape_markdown_v2_url(custom_emoji_id)
+        ),
+        _ => return None,
+    };
+    Some(marker)
+}
+
+impl Message {
+    /// The text of the message with its formatting as HTML markup
+    pub fn html_text(&self) -> Option<String> {
+        let text = self.text.as_deref()?;
+        Some(to_html(text, self.entities.as_deref().unwrap_or_default()))
+    }
+
+    /// The text of the message with its formatting as MarkdownV2 markup
+    pub fn markdown_v2_text(&self) -> Option<String> {
+        let text = self.text.as_deref()?;
+        Some(to_markdown_v2(
+            text,
+            self.entities.as_deref().unwrap_or_default(),
+        ))
+    }
+
+    /// The caption of the message with its formatting as HTML markup
+    pub fn html_caption(&self) -> Option<String> {
+        let caption = self.caption.as_deref()?;
+        Some(to_html(
+            caption,
+            self.caption_entities.as_deref().unwrap_or_default(),
+        ))
+    }
+
+    /// The caption of the message with its formatting as MarkdownV2 markup
+    pub fn markdown_v2_caption(&self) -> Option<String> {
+        let caption = self.caption.as_deref()?;
+        Some(to_markdown_v2(
+            caption,
+            self.caption_entities.as_deref().unwrap_or_default(),
+        ))
+    }
+}
+
+/// Error in a formatted text, found where the Bot API would answer "can't parse entities"
+#[derive(Clone, Debug, PartialEq, Eq, Error)]
+#[error("{message} at byte offset {offset}")]
+pub struct ParseError {
+    /// Offset in bytes in the formatted text
+    pub offset: usize,
+    pub message: String,
+}
+
+fn error<T>(offset: usize, message: impl Into<String>) -> Result<T, ParseError> {
+    Err(ParseError {
+        offset,
+        message: message.into(),
+    })
+}
+
+/// Entity type named as in the Bot API, its data is filled in when the end of the entity is parsed
+fn kind_named(name: &str) -> MessageEntityKind {
+    match name {
+        "bold" => MessageEntityKind::Bold,
+        "italic" => MessageEntityKind::Italic,
+        "underline" => MessageEntityKind::Underline,
+        "strikethrough" => MessageEntityKind::Strikethrough,
+        "spoiler" => MessageEntityKind::Spoiler,
+        "code" => MessageEntityKind::Code,
+        "pre" => MessageEntityKind::Pre { language: None },
+        "text_link" => MessageEntityKind::TextLink { url: String::new() },
+        "custom_emoji" => MessageEntityKind::CustomEmoji {
+            custom_emoji_id: String::new(),
+        },
+        "blockquote" => MessageEntityKind::Blockquote,
+        "expandable_blockquote" => MessageEntityKind::ExpandableBlockquote,
+        name => MessageEntityKind::Unknown(name.to_string()),
+    }
+}
+
+/// Parses a text formatted with the given parse mode into the plain text and its entities, like the Bot API does
+pub fn parse(markup: &str, mode: ParseMode) -> Result<(String, Vec<MessageEntity>), ParseError> {
+    match mode {
+        ParseMode::MarkdownV2 => parse_markdown_v2(markup),
+        ParseMode::HTML => parse_html(markup),
+        ParseMode::Markdown => parse_markdown(markup),
+    }
+}
+
+/// Plain text being collected by a parser. Entities are added when they start, so outer entities come first.
+#[derive(Default)]
+struct Parsed {
+    text: String,
+    len: Integer,
+    entities: Vec<MessageEntity>,
+}
+
+impl Parsed {
+    fn push(&mut self, c: char) {
+        // Telegram drops carriage returns
+        if c != '\r' {
+            self.text.push(c);
+            self.len += c.len_utf16() as Integer;
+        }
+    }
+
+    fn start(&mut self, kind: &str) -> usize {
+        self.entities
+            .push(MessageEntity::new(kind_named(kind), self.len, 0));
+        self.entities.len() - 1
+    }
+
+    fn end(&mut self, index: usize) -> &mut MessageEntity {
+        let entity = &mut self.entities[index];
+        entity.length = self.len - entity.offset;
+        entity
+    }
+
+    /// Text since the start of the entity
+    fn since(&self, begin: usize) -> &str {
+        &self.text[begin..]
+    }
+
+    fn finish(mut self) -> (String, Vec<MessageEntity>) {
+        self.entities.retain(|e| e.length > 0);
+        self.entities.sort_by_key(|e| (e.offset, -e.length));
+        (self.text, self.entities)
+    }
+}
+
+fn char_at(text: &str, i: usize) -> char {
+    text[i..].chars().next().unwrap_or_default()
+}
+
+fn mention(url: &str) -> Option<User> {
+    let id = url.strip_prefix("tg://user?id=")?.parse().ok()?;
+    Some(User {
+        id,
+        ..User::default()
+    })
+}
+
+/// Sets the link target, turning `tg://user?id=` links into text mentions. Links without a target are dropped.
+fn set_link(entity: &mut MessageEntity, url: String) {
+    if let Some(user) = mention(&url) {
+        entity.kind = MessageEntityKind::TextMention { user };
+    } else if url.is_empty() {
+        entity.length = 0;
+    } else {
+        entity.kind = MessageEntityKind::TextLink { url };
+    }
+}
+
+struct Open {
+    index: usize,
+    kind: &'static str,
+    // Where the entity starts in the formatted text and in the plain text
+    markup_offset: usize,
+    text_offset: usize,
+}
+
+/// Parses a MarkdownV2 formatted text
+pub fn parse_markdown_v2(markup: &str) -> Result<(String, Vec<MessageEntity>), ParseError> {
+    let bytes = markup.as_bytes();
+    let at = |i: usize| bytes.get(i).copied().unwrap_or_default();
+    let mut parsed = Parsed::default();
+    let mut open: Vec<Open> = Vec::new();
+    // Index of the current blockquote and whether it is expandable
+    let mut quote: Option<(usize, bool)> = None;
+    let mut i = 0;
+    while i < bytes.len() {
+        let c = bytes[i];
+        let in_code = open
+            .last()
+            .is_some_and(|o| o.kind == "code" || o.kind == "pre");
+        if !in_code && (i == 0 || bytes[i - 1] == b'\n') {
+            if c == b'>' {
+                if quote.is_none() {
+                    quote = Some((parsed.start("blockquote"), false));
+                }
+                i += 1;
+                continue;
+            }
+            if quote.is_none() && markup[i..].starts_with("**>") {
+                quote = Some((parsed.start("expandable_blockquote"), true));
+                i += 3;
+                continue;
+            }
+        }
+        if c == b'\\' && (1..=126).contains(&at(i + 1)) {
+            parsed.push(at(i + 1) as char);
+            i += 2;
+            continue;
+        }
+        let reserved = if in_code { "`" } else { "_*[]()~`>#+-=|{}.!" };
+        if !c.is_ascii() || !reserved.contains(c as char) {
+            let ch = char_at(markup, i);
+            if ch == '\n' && !in_code && at(i + 1) != b'>' {
+                if let Some((index, _)) = quote.take() {
+                    parsed.end(index);
+                }
+            }
+            parsed.push(ch);
+            i += ch.len_utf8();
+            continue;
+        }
+        // The end of an expandable blockquote is at the end of its last line
+        let ends_quote = match at(i + 2) {
+            0 => true,
+            b'\n' => at(i + 3) != b'>',
+            _ => false,
+        };
+        if let Some((index, true)) = quote {
+            let in_spoiler = open.last().is_some_and(|o| o.kind == "spoiler");
+            if markup[i..].starts_with("||") && ends_quote && !in_spoiler {
+                parsed.end(index);
+                quote = None;
+                i += 2;
+                continue;
+            }
+        }
+        let is_end = open.last().is_some_and(|o| match o.kind {
+            "bold" => c == b'*',
+            "italic" => c == b'_' && at(i + 1) != b'_',
+            "underline" => markup[i..].starts_with("__"),
+            "strikethrough" => c == b'~',
+            "spoiler" => markup[i..].starts_with("||"),
+            "code" => c == b'`',
+            "pre" => markup[i..].starts_with("```"),
+            _ => c == b']',
+        });
+        if !is_end {
+            let markup_offset = i;
+            let kind = match c {
+                b'_' if at(i + 1) == b'_' => {
+                    i += 2;
+                    "underline"
+                }
+                b'_' => {
+                    i += 1;
+                    "italic"
+                }
+                b'*' => {
+                    i += 1;
+                    "bold"
+                }
+                b'~' => {
+                    i += 1;
+                    "strikethrough"
+                }
+                b'|' if at(i + 1) == b'|' => {
+                    i += 2;
+                    "spoiler"
+                }
+                b'[' => {
+                    i += 1;
+                    "text_link"
+                }
+                b'!' if at(i + 1) == b'[' => {
+                    i += 2;
+                    "custom_emoji"
+                }
+                b'`' if markup[i..].starts_with("```") => {
+                    i += 3;
+                    "pre"
+                }
+                b'`' => {
+                    i += 1;
+                    "code"
+                }
+                c => {
+                    return error(
+                        i,
+                        format!(
+                        "Character '{}' is reserved and must be escaped with the preceding '\\'",
+                        c as char
+                    ),
+                    )
+                }
+            };
+            let index = parsed.start(kind);
+            if kind == "pre" {
+                let language_end = markup[i..]
+                    .find(|c: char| c.is_whitespace() || c == '`')
+                    .map_or(bytes.len(), |n| i + n);
+                if language_end != i && language_end < bytes.len() && bytes[language_end] != b'`' {
+                    let language = Some(markup[i..language_end].to_string());
+                    parsed.entities[index].kind = MessageEntityKind::Pre { language };
+                    i = language_end;
+                }
+                // One line break after the opening backticks is not a part of the text
+                match (at(i), at(i + 1)) {
+                    (b'\n', b'\r') | (b'\r', b'\n') => i += 2,
+                    (b'\n' | b'\r', _) => i += 1,
+                    _ => (),
+                }
+            }
+            open.push(Open {
+                index,
+                kind,
+                markup_offset,
+                text_offset: parsed.text.len(),
+            });
+            continue;
+        }
+        let Open {
+            index,
+            kind,
+            text_offset,
+            ..
+        } = open.pop().expect("is_end checked the last entity");
+        i += match kind {
+            "underline" | "spoiler" => 2,
+            "pre" => 3,
+            _ => 1,
+        };
+        if kind != "text_link" && kind != "custom_emoji" {
+            parsed.end(index);
+            continue;
+        }
+        let url = if at(i) == b'(' {
+            let url_offset = i + 1;
+            let mut url = String::new();
+            i += 1;
+            while at(i) != b')' {
+                if i >= bytes.len() {
+                    return error(url_offset, "Can't find end of a URL");
+                }
+                if at(i) == b'\\' && (1..=126).contains(&at(i + 1)) {
+                    i += 1;
+                }
+                let ch = char_at(markup, i);
+                url.push(ch);
+                i += ch.len_utf8();
+            }
+            i += 1;
+            Some((url_offset, url))
+        } else {
+            None
+        };
+        if kind == "text_link" {
+            let url = match url {
+                Some((_, url)) => url,
+                None => parsed.since(text_offset).to_string(),
+            };
+            let entity = parsed.end(index);
+            set_link(entity, url);
+            continue;
+        }
+        let Some((url_offset, url)) = url else {
+            return error(i, "Custom emoji entity must contain a tg://emoji URL");
+        };
+        match url.strip_prefix("tg://emoji?id=") {
+            Some(id) if !id.is_empty() && id.bytes().all(|b| b.is_ascii_digit()) => {
+                let custom_emoji_id = id.to_string();
+                parsed.end(index).kind = MessageEntityKind::CustomEmoji { custom_emoji_id };
+            }
+            _ => return error(url_offset, "Invalid custom emoji URL specified"),
+        }
+    }
+    if let Some(o) = open.last() {
+        return error(
+            o.markup_offset,
+            format!("Can't find end of {} entity", o.kind),
+        );
+    }
+    if let Some((index, _)) = quote {
+        parsed.end(index);
+    }
+    Ok(parsed.finish())
+}
+
+/// Length of the longest HTML character reference, `&#1114111;`
+const MAX_HTML_ENTITY_LEN: usize = 10;
+
+/// Decodes the HTML character reference at the start of `text`, returning the character and the length of the reference
+fn html_entity(text: &str) -> Option<(char, usize)> {
+    let bytes = text.as_bytes();
+    if bytes.first() != Some(&b'&') {
+        return None;
+    }
+    let end = bytes
+        .iter()
+        .take(MAX_HTML_ENTITY_LEN)
+        .position(|b| *b == b';')?;
+    let name = &text[1..end];
+    let c = match name {
+        "lt" => '<',
+        "gt" => '>',
+        "amp" => '&',
+        "quot" => '"',
+        _ => {
+            let code = match name.strip_prefix("#x").or_else(|| name.strip_prefix("#X")) {
+                Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                None => name.strip_prefix('#')?.parse().ok()?,
+            };
+            char::from_u32(code).filter(|c| *c != '\0')?
+        }
+    };
+    Some((c, end + 1))
+}
+
+const HTML_TAGS: [&str; 16] = [
+    "a",
+    "b",
+    "strong",
+    "i",
+    "em",
+    "s",
+    "strike",
+    "del",
+    "u",
+    "ins",
+    "tg-spoiler",
+    "tg-emoji",
+    "span",
+    "code",
+    "pre",
+    "blockquote",
+];
+
+struct Tag {
+    name: String,
+    argument: Option<String>,
+    index: usize,
+    markup_offset: usize,
+    text_offset: usize,
+}
+
+/// Parses an HTML formatted text, with the tags supported by the Bot API
+pub fn parse_html(markup: &str) -> Result<(String, Vec<MessageEntity>), ParseError> {
+    let bytes = markup.as_bytes();
+    let at = |i: usize| bytes.get(i).copied().unwrap_or_default();
+    let is_space = |b: u8| b.is_ascii_whitespace();
+    let mut parsed = Parsed::default();
+    let mut open: Vec<Tag> = Vec::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'&' => {
+                match html_entity(&markup[i..]) {
+                    Some((c, len)) => {
+                        parsed.push(c);
+                        i += len;
+                    }
+                    None => {
+                        parsed.push('&');
+                        i += 1;
+                    }
+                }
+                continue;
+            }
+            b'<' => (),
+            _ => {
+                let c = char_at(markup, i);
+                parsed.push(c);
+                i += c.len_utf8();
+                continue;
+            }
+        }
+        let begin = i;
+        i += 1;
+        if at(i) == b'/' {
+            i += 1;
+            let name_begin = i;
+            while i < bytes.len() && !is_space(bytes[i]) && bytes[i] != b'>' {
+                i += 1;
+            }
+            let name = markup[name_begin..i].to_lowercase();
+            let Some(tag) = open.pop() else {
+                return error(begin, "Unexpected end tag");
+            };
+            if !name.is_empty() && name != tag.name {
+                return error(
+                    begin,
+                    format!(
+                        "Unmatched end tag, expected \"</{}>\", found \"</{}>\"",
+                        tag.name, name
+                    ),
+                );
+            }
+            while is_space(at(i)) {
+                i += 1;
+            }
+            if at(i) != b'>' {
+                return error(begin, "Unclosed end tag");
+            }
+            i += 1;
+            close_html_tag(&mut parsed, &mut open, tag)?;
+            continue;
+        }
+        let name_begin = i;
+        while i < bytes.len() && !is_space(bytes[i]) && bytes[i] != b'>' {
+            i += 1;
+        }
+        if i >= bytes.len() {
+            return error(begin, "Unclosed start tag");
+        }
+        let name = markup[name_begin..i].to_lowercase();
+        if !HTML_TAGS.contains(&name.as_str()) {
+            return error(begin, format!("Unsupported start tag \"{name}\""));
+        }
+        let mut argument = None;
+        loop {
+            while is_space(at(i)) {
+                i += 1;
+            }
+            if at(i) == b'>' {
+                i += 1;
+                break;
+            }
+            let attribute_begin = i;
+            while i < bytes.len() && !is_space(bytes[i]) && bytes[i] != b'=' && bytes[i] != b'>' {
+                i += 1;
+            }
+            let attribute = markup[attribute_begin..i].to_lowercase();
+            if attribute.is_empty() {
+                return error(begin, format!("Empty attribute name in the tag \"{name}\""));
+            }
+            while is_space(at(i)) {
+                i += 1;
+            }
+            if at(i) != b'=' {
+                if name == "blockquote" && attribute == "expandable" {
+                    argument = Some(String::new());
+                    continue;
+                }
+                if i >= bytes.len() {
+                    return error(begin, "Unclosed start tag");
+                }
+                return error(
+                    begin,
+                    format!(
+                        "Expected equal sign in declaration of an attribute of the tag \"{name}\""
+                    ),
+                );
+            }
+            i += 1;
+            while is_space(at(i)) {
+                i += 1;
+            }
+            if i >= bytes.len() {
+                return error(begin, "Unclosed start tag");
+            }
+            let mut value = String::new();
+            if at(i) == b'"' || at(i) == b'\'' {
+                let quote = bytes[i];
+                i += 1;
+                while i < bytes.len() && bytes[i] != quote {
+                    let entity = match bytes[i] {
+                        b'&' => html_entity(&markup[i..]),
+                        _ => None,
+                    };
+                    match entity {
+                        Some((c, len)) => {
+                            value.push(c);
+                            i += len;
+                        }
+                        None => {
+                            let c = char_at(markup, i);
+                            value.push(c);
+                            i += c.len_utf8();
+                        }
+                    }
+                }
+                i += 1;
+            } else {
+                let token_begin = i;
+                while at(i).is_ascii_alphanumeric() || at(i) == b'.' || at(i) == b'-' {
+                    i += 1;
+                }
+                value = markup[token_begin..i].to_lowercase();
+                if !is_space(at(i)) && at(i) != b'>' {
+                    return error(token_begin, "Unexpected end of name token");
+                }
+            }
+            if i >= bytes.len() {
+                return error(begin, "Unclosed start tag");
+            }
+            argument = match (name.as_str(), attribute.as_str()) {
+                ("a", "href") => Some(value),
+                ("code", "class") => value.strip_prefix("language-").map(str::to_string),
+                ("span", "class") => value.strip_prefix("tg-").map(str::to_string),
+                ("tg-emoji", "emoji-id") => Some(value),
+                _ => argument,
+            };
+        }
+        if name == "span" && argument.as_deref() != Some("spoiler") {
+            return error(begin, "Tag \"span\" must have class \"tg-spoiler\"");
+        }
+        let kind = match name.as_str() {
+            "a" => "text_link",
+            "b" | "strong" => "bold",
+            "i" | "em" => "italic",
+            "s" | "strike" | "del" => "strikethrough",
+            "u" | "ins" => "underline",
+            "tg-spoiler" | "span" => "spoiler",
+            "tg-emoji" => "custom_emoji",
+            "code" => "code",
+            "pre" => "pre",
+            _ if argument.is_some() => "expandable_blockquote",
+            _ => "blockquote",
+        };
+        let index = parsed.start(kind);
+        open.push(Tag {
+            name,
+            argument,
+            index,
+            markup_offset: begin,
+            text_offset: parsed.text.len(),
+        });
+    }
+    if let Some(tag) = open.last() {
+        return error(
+            tag.markup_offset,
+            format!(
+                "Can't find end tag corresponding to start tag \"{}\"",
+                tag.name
+            ),
+        );
+    }
+    Ok(parsed.finish())
+}
+
+fn close_html_tag(parsed: &mut Parsed, open: &mut [Tag], tag: Tag) -> Result<(), ParseError> {
+    let url = match tag.name.as_str() {
+        "a" => match &tag.argument {
+            Some(url) => url.clone(),
+            None => parsed.since(tag.text_offset).to_string(),
+        },
+        _ => String::new(),
+    };
+    let entity = parsed.end(tag.index);
+    match tag.name.as_str() {
+        "a" => set_link(entity, url),
+        "tg-emoji" => match tag.argument {
+            Some(custom_emoji_id)
+                if !custom_emoji_id.is_empty()
+                    && custom_emoji_id.bytes().all(|b| b.is_ascii_digit()) =>
+            {
+                entity.kind = MessageEntityKind::CustomEmoji { custom_emoji_id }
+            }
+            _ => {
+                return error(
+                    tag.markup_offset,
+                    "Invalid custom emoji identifier specified",
+                )
+            }
+        },
+        // The language of <pre><code class="language-..."> is kept by the pre until it is closed
+        "code" if open.last().is_some_and(|parent| parent.name == "pre") => {
+            let parent = open.len() - 1;
+            open[parent].argument = tag.argument;
+        }
+        "pre" => {
+            let (offset, length) = (entity.offset, entity.length);
+            let code = parsed.entities.get_mut(tag.index + 1).filter(|code| {
+                code.kind == MessageEntityKind::Code
+                    && code.offset == offset
+                    && code.length == length
+            });
+            if let (Some(code), Some(language)) = (code, tag.argument) {
+                code.length = 0;
+                parsed.entities[tag.index].kind = MessageEntityKind::Pre {
+                    language: Some(language),
+                };
+            }
+        }
+        _ => (),
+    }
+    Ok(())
+}
+
+/// Parses a text formatted with the legacy Markdown mode. Entities can't be nested in it.
+pub fn parse_markdown(markup: &str) -> Result<(String, Vec<MessageEntity>), ParseError> {
+    let bytes = markup.as_bytes();
+    let at = |i: usize| bytes.get(i).copied().unwrap_or_default();
+    let mut parsed = Parsed::default();
+    let mut i = 0;
+    while i < bytes.len() {
+        let c = bytes[i];
+        if c == b'\\' && matches!(at(i + 1), b'_' | b'*' | b'`' | b'[') {
+            parsed.push(at(i + 1) as char);
+            i += 2;
+            continue;
+        }
+        if !matches!(c, b'_' | b'*' | b'`' | b'[') {
+            let ch = char_at(markup, i);
+            parsed.push(ch);
+            i += ch.len_utf8();
+            continue;
+        }
+        let begin = i;
+        let end = if c == b'[' { b']' } else { c };
+        let is_pre = markup[i..].starts_with("```");
+        i += 1;
+        let mut language = None;
+        if is_pre {
+            i += 2;
+            let language_end = markup[i..]
+                .find(|c: char| c.is_whitespace() || c == '`')
+                .map_or(bytes.len(), |n| i + n);
+            if language_end != i && language_end < bytes.len() && bytes[language_end] != b'`' {
+                language = Some(markup[i..language_end].to_string());
+                i = language_end;
+            }
+            match (at(i), at(i + 1)) {
+                (b'\n', b'\r') | (b'\r', b'\n') => i += 2,
+                (b'\n' | b'\r', _) => i += 1,
+                _ => (),
+            }
+        }
+        let kind = match c {
+            b'_' => "italic",
+            b'*' => "bold",
+            b'[' => "text_link",
+            _ if is_pre => "pre",
+            _ => "code",
+        };
+        let index = parsed.start(kind);
+        let text_offset = parsed.text.len();
+        while i < bytes.len() && (bytes[i] != end || (is_pre && !markup[i..].starts_with("```"))) {
+            let ch = char_at(markup, i);
+            parsed.push(ch);
+            i += ch.len_utf8();
+        }
+        if i >= bytes.len() {
+            return error(begin, "Can't find end of the entity starting");
+        }
+        let entity = parsed.end(index);
+        if is_pre {
+            entity.kind = MessageEntityKind::Pre { language };
+        }
+        i += if is_pre { 3 } else { 1 };
+        if kind == "text_link" {
+            let url = if at(i) == b'(' {
+                let url_offset = i + 1;
+                let Some(len) = markup[url_offset..].find(')') else {
+                    return error(url_offset, "Can't find end of a URL");
+                };
+                i = url_offset + len + 1;
+                markup[url_offset..url_offset + len].to_string()
+            } else {
+                parsed.since(text_offset).to_string()
+            };
+            set_link(&mut parsed.entities[index], url);
+        }
+    }
+    Ok(parsed.finish())
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+    use serde_json::json;
+
+    use super::*;
+    use crate::{bot::SendMessage, ids::UserId, types::ChatID};
+
+    fn sample() -> (String, Vec<MessageEntity>) {
+        let user =
+            serde_json::from_value(json!({"id": 7, "is_bot": false, "first_name": "Ann"})).unwrap();
+        RichText::new()
+            .text("😀 ")
+            .bold(RichText::new().text("a ").italic("ü"))
+            .text(" ")
+            .pre("let x;", Some("rust"))
+            .text_mention("Ann", user)
+            .code("")
+            .build()
+    }
+
+    #[test]
+    fn rich_text_offsets_in_utf16() {
+        let (text, entities) = sample();
+        assert_eq!(text, "😀 a ü let x;Ann");
+        let spans: Vec<_> = entities
+            .iter()
+            .map(|e| (e.kind.name(), e.offset, e.length))
+            .collect();
+        assert_eq!(
+            spans,
+            vec![
+                ("bold", 3, 3),
+                ("italic", 5, 1),
+                ("pre", 7, 6),
+                ("text_mention", 13, 3)
+            ]
+        );
+    }
+
+    #[test]
+    fn rich_text_entity_fields() {
+        let (_, entities) = sample();
+        assert_eq!(
+            entities[2].kind,
+            MessageEntityKind::Pre {
+                language: Some("rust".to_string())
+            }
+        );
+        assert!(
+            matches!(&entities[3].kind, MessageEntityKind::TextMention { user } if user.id == UserId(7))
+        );
+    }
+
+    #[test]
+    fn parse_mode_in_request() {
+        let mut msg = SendMessage::new(ChatID::from(1), "x".to_string());
+        msg.parse_mode(Some(ParseMode::HTML));
+        assert!(msg.to_string().contains(r#""parse_mode":"HTML""#));
+    }
+
+    #[test]
+    fn escape_per_mode() {
+        assert_eq!(
+            escape("1.5 * (a_b) [x]!", ParseMode::MarkdownV2),
+            r"1\.5 \* \(a\_b\) \[x\]\!"
+        );
+        assert_eq!(
+            escape(r#"<a href="x">&</a>"#, ParseMode::HTML),
+            "&lt;a href=&quot;x&quot;&gt;&amp;&lt;/a&gt;"
+        );
+        assert_eq!(escape("*_`[", ParseMode::Markdown), r"\*\_\`\[");
+    }
+
+    #[test]
+    fn render_nested_entities() {
+        let (text, entities) = RichText::new()
+            .bold("b<")
+            .text(" ")
+            .italic(RichText::new().underline("iu"))
+            .text(" ")
+            .text_link("l)", "https://e.com/(x)")
+            .text(" ")
+            .pre("a`b\\c.", Some("rust"))
+            .build();
+        assert_eq!(
+            to_html(&text, &entities),
+            "<b>b&lt;</b> <i><u>iu</u></i> <a href=\"https://e.com/(x)\">l)</a> \
+             <pre><code class=\"language-rust\">a`b\\c.</code></pre>"
+        );
+        assert_eq!(
+            to_markdown_v2(&text, &entities),
+            "*b<* _\r__iu__\r_ [l\\)](https://e.com/(x\\)) ```rust\na\\`b\\\\c.```"
+        );
+    }
+
+    #[test]
+    fn render_overlapping_entities() {
+        let overlap: Vec<MessageEntity> = serde_json::from_value(json!([
+            {"type": "bold", "offset": 0, "length": 3},
+            {"type": "italic", "offset": 2, "length": 3},
+            {"type": "blockquote", "offset": 6, "length": 3}
+        ]))
+        .unwrap();
+        assert_eq!(
+            to_html("abcde\nq\nr", &overlap),
+            "<b>ab<i>c</i></b><i>de</i>\n<blockquote>q\nr</blockquote>"
+        );
+        assert_eq!(
+            to_markdown_v2("abcde\nq\nr", &overlap),
+            "*ab_c_*_de_\n>q\n>r"
+        );
+    }
+
+    fn spans(entities: &[MessageEntity]) -> Vec<(&str, Integer, Integer)> {
+        entities
+            .iter()
+            .map(|e| (e.kind.name(), e.offset, e.length))
+            .collect()
+    }
+
+    #[test]
+    fn parse_markdown_v2_entities() {
+        let (text, entities) = parse_markdown_v2("😀*b _i_*\\.\n```rust\nlet x;```").unwrap();
+        assert_eq!(text, "😀b i.\nlet x;");
+        assert_eq!(
+            spans(&entities),
+            vec![("bold", 2, 3), ("italic", 4, 1), ("pre", 7, 6)]
+        );
+        assert_eq!(
+            entities[2].kind,
+            MessageEntityKind::Pre {
+                language: Some("rust".to_string())
+            }
+        );
+    }
+
+    #[test]
+    fn parse_html_entities() {
+        let (text, entities) = parse_html(
+            r#"<a href="tg://user?id=5">u</a> &lt;3 <pre><code class="language-c">x</code></pre>"#,
+        )
+        .unwrap();
+        assert_eq!(text, "u <3 x");
+        assert!(
+            matches!(&entities[0].kind, MessageEntityKind::TextMention { user } if user.id == UserId(5))
+        );
+        assert_eq!(spans(&entities[1..]), vec![("pre", 5, 1)]);
+        assert_eq!(
+            entities[1].kind,
+            MessageEntityKind::Pre {
+                language: Some("c".to_string())
+            }
+        );
+    }
+
+    #[test]
+    fn parse_legacy_markdown() {
+        let (text, entities) = parse_markdown("*bold* [x](http://a.b)").unwrap();
+        assert_eq!(text, "bold x");
+        assert_eq!(
+            entities[1].kind,
+            MessageEntityKind::TextLink {
+                url: "http://a.b".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn parse_error_offsets() {
+        let offset = |result: Result<(String, Vec<_>), ParseError>| result.unwrap_err().offset;
+        assert_eq!(offset(parse_markdown_v2("a *b")), 2);
+        assert_eq!(offset(parse_markdown_v2("ü 1.5")), 4);
+        assert_eq!(offset(parse_markdown_v2("[a](http://x")), 4);
+        assert_eq!(offset(parse_html("<b>x</i>")), 4);
+        assert_eq!(offset(parse_html("x <foo>")), 2);
+        assert_eq!(offset(parse_html("<i>x")), 0);
+        assert_eq!(offset(parse(" _x", ParseMode::Markdown)), 1);
+        assert_eq!(
+            parse_markdown_v2("ü 1.5").unwrap_err().to_string(),
+            "Character '.' is reserved and must be escaped with the preceding '\\' at byte offset 4"
+        );
+    }
+
+    #[test]
+    fn html_attribute_with_non_ascii_value() {
+        let (text, entities) = parse_html("<a href=\"https://x/é;\">t</a>").unwrap();
+        assert_eq!(text, "t");
+        assert_eq!(
+            entities[0].kind,
+            MessageEntityKind::TextLink {
+                url: "https://x/é;".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn html_entity_search_is_bounded() {
+        assert_eq!(html_entity("&amp;"), Some(('&', 5)));
+        assert_eq!(html_entity("&#1114111;"), Some(('\u{10FFFF}', 10)));
+        assert_eq!(html_entity("&aaaaaaaaaaaaaaaaaa;"), None);
+        assert_eq!(html_entity("é;"), None);
+    }
+
+    #[derive(Clone, Debug)]
+    enum Node {
+        Text(String),
+        Wrap(&'static str, Vec<Node>),
+        Code(String),
+        Pre(String, Option<String>),
+        Link(Vec<Node>, String),
+        Mention(Vec<Node>, i64),
+        Emoji(u64),
+    }
+
+    fn node() -> impl Strategy<Value = Node> {
+        let text = "[a-z_*\\[\\]()~`>#+=|{}.!\\\\<&\"ü😀 \n-]{1,6}";
+        let leaf = prop_oneof![
+            text.prop_map(Node::Text),
+            text.prop_map(Node::Code),
+            (text, proptest::option::of("[a-z]{1,5}")).prop_map(|(t, l)| Node::Pre(t, l)),
+            (1..1000u64).prop_map(Node::Emoji),
+        ];
+        leaf.prop_recursive(3, 24, 4, |inner| {
+            let children = prop::collection::vec(inner, 1..4);
+            prop_oneof![
+                (
+                    prop::sample::select(vec![
+                        "bold",
+                        "italic",
+                        "underline",
+                        "strikethrough",
+                        "spoiler"
+                    ]),
+                    children.clone()
+                )
+                    .prop_map(|(kind, nodes)| Node::Wrap(kind, nodes)),
+                (children.clone(), "https://e\\.com/[a-z()\\\\]{0,4}")
+                    .prop_map(|(nodes, url)| Node::Link(nodes, url)),
+                (children, 1..1000i64).prop_map(|(nodes, id)| Node::Mention(nodes, id)),
+            ]
+        })
+    }
+
+    fn build(rt: &mut RichText, nodes: &[Node], parents: &[&str]) {
+        for node in nodes {
+            let (kind, children) = match node {
+                Node::Text(t) => {
+                    rt.text(t.as_str());
+                    continue;
+                }
+                Node::Code(t) => {
+                    rt.code(t);
+                    continue;
+                }
+                Node::Pre(t, language) => {
+                    rt.pre(t, language.as_deref());
+                    continue;
+                }
+                Node::Emoji(id) => {
+                    rt.custom_emoji("👍", &id.to_string());
+                    continue;
+                }
+                Node::Wrap(kind, children) => (*kind, children),
+                Node::Link(children, _) | Node::Mention(children, _) => ("link", children),
+            };
+            let mut inner = RichText::new();
+            let mut nested = parents.to_vec();
+            nested.push(kind);
+            build(&mut inner, children, &nested);
+            // Telegram doesn't allow an entity inside another one of the same kind
+            if parents.contains(&kind) {
+                rt.text(inner);
+                continue;
+            }
+            match node {
+                Node::Link(_, url) => rt.text_link(inner, url),
+                Node::Mention(_, id) => rt.text_mention(
+                    inner,
+                    serde_json::from_value(json!({"id": id, "is_bot": false, "first_name": ""}))
+                        .unwrap(),
+                ),
+                _ => match kind {
+                    "bold" => rt.bold(inner),
+                    "italic" => rt.italic(inner),
+                    "underline" => rt.underline(inner),
+                    "strikethrough" => rt.strikethrough(inner),
+                    _ => rt.spoiler(inner),
+                },
+            };
+        }
+    }
+
+    fn arb_rich_text() -> impl Strategy<Value = RichText> {
+        let part = (prop::collection::vec(node(), 1..4), 0..3u8);
+        prop::collection::vec(part, 1..4).prop_map(|parts| {
+            let mut rt = RichText::new();
+            for (i, (nodes, quote)) in parts.iter().enumerate() {
+                let mut inner = RichText::new();
+                build(&mut inner, nodes, &[]);
+                if *quote == 0 {
+                    rt.text(inner);
+                    continue;
+                }
+                // Blockquotes take whole lines
+                if i > 0 {
+                    rt.text("\n");
+                }
+                if *quote == 1 {
+                    rt.blockquote(inner);
+                } else {
+                    rt.expandable_blockquote(inner);
+                }
+                rt.text("\n");
+            }
+            rt
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn markup_round_trip(rt in arb_rich_text()) {
+            let (text, entities) = rt.build();
+            let expected = serde_json::to_value(&entities).unwrap();
+            for mode in [ParseMode::MarkdownV2, ParseMode::HTML] {
+                let markup = match mode {
+                    ParseMode::HTML => to_html(&text, &entities),
+                    _ => to_markdown_v2(&text, &entities),
+                };
+                let (parsed_text, parsed) = parse(&markup, mode)
+                    .map_err(|e| TestCaseError::fail(format!("{e} in {markup:?}")))?;
+                prop_assert_eq!(&parsed_text, &text, "{:?}", markup);
+                prop_assert_eq!(serde_json::to_value(&parsed).unwrap(), expected.clone(), "{:?}", markup);
+            }
+        }
+
+        #[test]
+        fn parse_errors_point_into_markup(markup in "[a-z_*\\[\\]()~`>|!\\\\<>/&;#=\" \nü]{0,24}") {
+            for mode in [ParseMode::MarkdownV2, ParseMode::HTML, ParseMode::Markdown] {
+                match parse(&markup, mode) {
+                    Ok((text, entities)) => {
+                        let len = text.encode_utf16().count() as i64;
+                        prop_assert!(entities.iter().all(|e| e.length > 0 && e.offset + e.length <= len));
+                    }
+                    Err(e) => prop_assert!(markup.is_char_boundary(e.offset)),
+                }
+            }
+        }
+    }
+}
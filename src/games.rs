@@ -14,7 +14,8 @@ pub struct Game {
     pub photo: Vec<PhotoSize>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub text: Option<String>,
-    pub text_entities: Vec<Option<MessageEntity>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text_entities: Option<Vec<MessageEntity>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub animation: Option<Animation>,
 }
@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 use crate::{
+    ids::MessageId,
     message::Message,
     types::{Boolean, Chat, Integer, User},
 };
@@ -41,7 +42,7 @@ pub struct GiveawayWinners {
     /// The chat that created the giveaway
     pub chat: Chat,
     /// Identifier of the messsage with the giveaway in the chat
-    pub giveaway_message_id: Integer,
+    pub giveaway_message_id: MessageId,
     /// Point in time (Unix timestamp) when winners of the giveaway were selected
     pub winners_selection_date: Integer,
     /// Total number of winners in the giveaway
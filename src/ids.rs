@@ -0,0 +1,259 @@
+use std::{fmt, num::ParseIntError, str::FromStr};
+
+use serde::{Deserialize, Deserializer, Serialize};
+
+use crate::types::{ChatID, InputFileString, Integer};
+
+/// Chat ids of supergroups and channels are their bare ids subtracted from this, e.g. 1234567890 becomes -1001234567890
+const CHANNEL_ID_OFFSET: Integer = -1_000_000_000_000;
+
+macro_rules! integer_id {
+    ($name:ident) => {
+        impl From<Integer> for $name {
+            fn from(id: Integer) -> Self {
+                $name(id)
+            }
+        }
+
+        impl From<$name> for Integer {
+            fn from(id: $name) -> Self {
+                id.0
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                self.0.fmt(f)
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = ParseIntError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                s.parse().map($name)
+            }
+        }
+    };
+}
+
+/// Unique identifier of a user or a bot.
+#[derive(
+    Clone, Copy, Serialize, Deserialize, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash,
+)]
+#[serde(transparent)]
+pub struct UserId(pub Integer);
+
+integer_id!(UserId);
+
+/// Unique identifier of a chat. Private chats have the id of the user, groups have negative ids and supergroups and
+/// channels have ids in the `-100…` form.
+#[derive(
+    Clone, Copy, Serialize, Deserialize, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash,
+)]
+#[serde(transparent)]
+pub struct ChatId(pub Integer);
+
+integer_id!(ChatId);
+
+impl ChatId {
+    /// The chat id of a supergroup or channel with the bare id used by other Telegram clients, e.g. 1234567890 gives -1001234567890
+    pub fn channel(bare_id: Integer) -> Self {
+        ChatId(CHANNEL_ID_OFFSET - bare_id)
+    }
+
+    /// True for private chats with users
+    pub fn is_user(&self) -> bool {
+        self.0 > 0
+    }
+
+    /// True for basic groups
+    pub fn is_group(&self) -> bool {
+        CHANNEL_ID_OFFSET < self.0 && self.0 < 0
+    }
+
+    /// True for supergroups and channels
+    pub fn is_channel_or_supergroup(&self) -> bool {
+        self.0 < CHANNEL_ID_OFFSET
+    }
+
+    /// The id without the `-` or `-100` prefix, as used in t.me/c/ links and by other Telegram clients
+    pub fn bare_id(&self) -> Integer {
+        if self.is_channel_or_supergroup() {
+            CHANNEL_ID_OFFSET - self.0
+        } else {
+            self.0.abs()
+        }
+    }
+
+    /// The user of a private chat
+    pub fn as_user(&self) -> Option<UserId> {
+        self.is_user().then_some(UserId(self.0))
+    }
+}
+
+impl From<UserId> for ChatId {
+    fn from(id: UserId) -> Self {
+        ChatId(id.0)
+    }
+}
+
+impl From<ChatId> for ChatID {
+    fn from(id: ChatId) -> Self {
+        ChatID::Integer(id.0)
+    }
+}
+
+impl From<UserId> for ChatID {
+    fn from(id: UserId) -> Self {
+        ChatID::Integer(id.0)
+    }
+}
+
+/// Unique identifier of a message inside its chat.
+///
+/// It is written as a plain integer and read from an integer or from a MessageId object (`{"message_id": 123}`), which
+/// is what copyMessage, copyMessages and forwardMessages return.
+#[derive(Clone, Copy, Serialize, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[serde(transparent)]
+pub struct MessageId(pub Integer);
+
+integer_id!(MessageId);
+
+impl<'de> Deserialize<'de> for MessageId {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Repr {
+            Id(Integer),
+            Object { message_id: Integer },
+        }
+        match Repr::deserialize(deserializer)? {
+            Repr::Id(id) | Repr::Object { message_id: id } => Ok(MessageId(id)),
+        }
+    }
+}
+
+/// Identifier of a file, which can be used to download or reuse it.
+#[derive(Clone, Serialize, Deserialize, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[serde(transparent)]
+pub struct FileId(pub String);
+
+impl FileId {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<String> for FileId {
+    fn from(id: String) -> Self {
+        FileId(id)
+    }
+}
+
+impl From<&str> for FileId {
+    fn from(id: &str) -> Self {
+        FileId(id.to_string())
+    }
+}
+
+impl From<FileId> for String {
+    fn from(id: FileId) -> Self {
+        id.0
+    }
+}
+
+impl AsRef<str> for FileId {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for FileId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl From<FileId> for InputFileString {
+    fn from(id: FileId) -> Self {
+        InputFileString::String(id.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::{bot::DeleteMessages, message::Message};
+
+    const SUPERGROUP: Integer = -1001234567890;
+
+    #[test]
+    fn ids_in_message() {
+        let message: Message = serde_json::from_value(json!({
+            "message_id": 5,
+            "date": 0,
+            "chat": {"id": SUPERGROUP, "type": "supergroup"},
+            "from": {"id": 42, "is_bot": false, "first_name": "A"},
+            "photo": [{"file_id": "AgAD", "file_unique_id": "u", "width": 1, "height": 1}]
+        }))
+        .unwrap();
+        assert_eq!(message.message_id, MessageId(5));
+        assert_eq!(message.chat.id, ChatId(SUPERGROUP));
+        assert_eq!(message.from.as_ref().unwrap().id, UserId(42));
+        assert_eq!(
+            message.photo.as_ref().unwrap()[0].file_id,
+            FileId::from("AgAD")
+        );
+        let value = serde_json::to_value(&message).unwrap();
+        assert_eq!(value["message_id"], 5);
+        assert_eq!(value["chat"]["id"], SUPERGROUP);
+    }
+
+    #[test]
+    fn message_id_objects() {
+        // copyMessage returns a MessageId object
+        let copied: MessageId = serde_json::from_value(json!({"message_id": 9})).unwrap();
+        assert_eq!(copied, MessageId(9));
+        let copied: Vec<MessageId> = serde_json::from_value(json!([{"message_id": 1}, 2])).unwrap();
+        assert_eq!(copied, [MessageId(1), MessageId(2)]);
+        assert_eq!(MessageId(7).to_string(), "7");
+    }
+
+    #[test]
+    fn chat_id_kinds() {
+        let chat = ChatId(SUPERGROUP);
+        assert!(chat.is_channel_or_supergroup() && !chat.is_group() && !chat.is_user());
+        assert_eq!(chat.bare_id(), 1234567890);
+        assert_eq!(ChatId::channel(1234567890), chat);
+        assert!(ChatId(-123).is_group());
+        assert_eq!(ChatId(-123).bare_id(), 123);
+        assert_eq!(ChatId(42).as_user(), Some(UserId(42)));
+        assert_eq!(chat.as_user(), None);
+    }
+
+    #[test]
+    fn chat_id_conversions() {
+        assert_eq!(ChatId::from(UserId(42)), ChatId(42));
+        assert!(matches!(
+            ChatID::from(ChatId(SUPERGROUP)),
+            ChatID::Integer(SUPERGROUP)
+        ));
+        assert!(matches!(ChatID::from(UserId(42)), ChatID::Integer(42)));
+        assert_eq!("-5".parse::<ChatId>(), Ok(ChatId(-5)));
+    }
+
+    #[test]
+    fn ids_in_requests() {
+        assert_eq!(
+            serde_json::to_string(&DeleteMessages::new(
+                ChatID::from(ChatId(SUPERGROUP)),
+                vec![MessageId(1), MessageId(2)]
+            ))
+            .unwrap(),
+            r#"{"chat_id":-1001234567890,"message_ids":[1,2]}"#
+        );
+    }
+}
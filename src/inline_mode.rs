@@ -1,6 +1,8 @@
 use serde::{Deserialize, Serialize};
 
 use crate::{
+    formatting::ParseMode,
+    ids::FileId,
     message::MessageEntity,
     payments::LabeledPrice,
     types::{
@@ -42,134 +44,131 @@ pub struct InlineQueryResultsButton {
     pub start_parameter: Option<String>,
 }
 
-/// This object represents one result of an inline query. Telegram clients currently support results of the following 20 types:
+//Represents the content of a location message to be sent as the result of an inline query.
+/// Represents a result of an inline query that was chosen by the user and sent to their chat partner.
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct ChosenInlineResult {
+    pub result_id: String,
+    pub from: User,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<Location>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inline_message_id: Option<String>,
+    pub query: String,
+}
+
+/// Contains information about an inline message sent by a [webapps](https://core.telegram.org/bots/webapps) on behalf of a user.
 #[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct SentWebAppMessage {
+    /// Optional. Identifier of the sent inline message. Available only if there is an inline keyboard attached to the message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inline_message_id: Option<String>,
+}
+
+// The types below are generated from spec/bot_api.json by `cargo run -p rutel_codegen`, edit the spec instead.
+
+/// This object represents one result of an inline query. Telegram clients currently support results of the following 20 types:
+///
+/// Some objects share a tag value, so the type is only serialized.
+#[derive(Clone, Serialize, Debug)]
+#[serde(tag = "type")]
 pub enum InlineQueryResult {
-    InlineQueryResultCachedAudio,
-    InlineQueryResultCachedDocument,
-    InlineQueryResultCachedGif,
-    InlineQueryResultCachedMpeg4Gif,
-    InlineQueryResultCachedPhoto,
-    InlineQueryResultCachedSticker,
-    InlineQueryResultCachedVideo,
-    InlineQueryResultCachedVoice,
-    InlineQueryResultArticle,
-    InlineQueryResultAudio,
-    InlineQueryResultContact,
-    InlineQueryResultGame,
-    InlineQueryResultDocument,
-    InlineQueryResultGif,
-    InlineQueryResultLocation,
-    InlineQueryResultMpeg4Gif,
-    InlineQueryResultPhoto,
-    InlineQueryResultVenue,
-    InlineQueryResultVideo,
-    InlineQueryResultVoice,
+    #[serde(rename = "audio")]
+    InlineQueryResultCachedAudio(InlineQueryResultCachedAudio),
+    #[serde(rename = "document")]
+    InlineQueryResultCachedDocument(InlineQueryResultCachedDocument),
+    #[serde(rename = "gif")]
+    InlineQueryResultCachedGif(InlineQueryResultCachedGif),
+    #[serde(rename = "mpeg4_gif")]
+    InlineQueryResultCachedMpeg4Gif(InlineQueryResultCachedMpeg4Gif),
+    #[serde(rename = "photo")]
+    InlineQueryResultCachedPhoto(InlineQueryResultCachedPhoto),
+    #[serde(rename = "sticker")]
+    InlineQueryResultCachedSticker(InlineQueryResultCachedSticker),
+    #[serde(rename = "video")]
+    InlineQueryResultCachedVideo(InlineQueryResultCachedVideo),
+    #[serde(rename = "voice")]
+    InlineQueryResultCachedVoice(InlineQueryResultCachedVoice),
+    #[serde(rename = "article")]
+    InlineQueryResultArticle(InlineQueryResultArticle),
+    #[serde(rename = "audio")]
+    InlineQueryResultAudio(InlineQueryResultAudio),
+    #[serde(rename = "contact")]
+    InlineQueryResultContact(InlineQueryResultContact),
+    #[serde(rename = "game")]
+    InlineQueryResultGame(InlineQueryResultGame),
+    #[serde(rename = "document")]
+    InlineQueryResultDocument(InlineQueryResultDocument),
+    #[serde(rename = "gif")]
+    InlineQueryResultGif(InlineQueryResultGif),
+    #[serde(rename = "location")]
+    InlineQueryResultLocation(InlineQueryResultLocation),
+    #[serde(rename = "mpeg4_gif")]
+    InlineQueryResultMpeg4Gif(InlineQueryResultMpeg4Gif),
+    #[serde(rename = "photo")]
+    InlineQueryResultPhoto(InlineQueryResultPhoto),
+    #[serde(rename = "venue")]
+    InlineQueryResultVenue(InlineQueryResultVenue),
+    #[serde(rename = "video")]
+    InlineQueryResultVideo(InlineQueryResultVideo),
+    #[serde(rename = "voice")]
+    InlineQueryResultVoice(InlineQueryResultVoice),
 }
 
-/// Represents a link to an article or web page.
+/// Represents a link to an mp3 audio file stored on the Telegram servers. By default, this audio file will be sent by the user. Alternatively, you can use input_message_content to send a message with the specified content instead of the audio.
 #[derive(Clone, Serialize, Deserialize, Debug)]
-pub struct InlineQueryResultArticle {
-    /// Type of the result, must be article
-    #[serde(rename = "type")]
-    pub kind: String,
-    /// Unique identifier for this result, 1-64 Bytes
+pub struct InlineQueryResultCachedAudio {
     pub id: String,
-    /// Title of the result
-    pub title: String,
-    /// Content of the message to be sent
-    pub input_message_content: InputMessageContent,
-    /// Optional. Inline keyboard attached to the message
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub reply_markup: Option<InlineKeyboardMarkup>,
-    /// Optional. URL of the result
+    pub audio_file_id: FileId,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub url: Option<String>,
-    /// Optional. Pass True if you don't want the URL to be shown in the message
+    pub caption: Option<String>,
+    /// Optional. Mode for parsing entities in the photo caption. See formatting options for more details.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub hide_url: Option<Boolean>,
-    /// Optional. Short description of the result
+    pub parse_mode: Option<ParseMode>,
+    /// Optional. List of special entities that appear in the caption, which can be specified instead of parse_mode
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub description: Option<String>,
-    /// Optional. Url of the thumbnail for the result
+    pub caption_entities: Option<Vec<MessageEntity>>,
+    /// Optional. True, if the caption must be shown above the message media
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub thumbnail_url: Option<String>,
-    /// Optional. Thumbnail width
+    pub show_caption_above_media: Option<Boolean>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub thumbnail_width: Option<Integer>,
-    /// Optional. Thumbnail height
+    pub reply_markup: Option<InlineKeyboardMarkup>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub thumbnail_height: Option<Integer>,
+    pub input_message_content: Option<InputMessageContent>,
 }
 
-/// Represents a link to a photo. By default, this photo will be sent by the user with optional caption. Alternatively, you can use input_message_content to send a message with the specified content instead of the photo.
+/// Represents a link to a file stored on the Telegram servers. By default, this file will be sent by the user with an optional caption. Alternatively, you can use input_message_content to send a message with the specified content instead of the file.
 #[derive(Clone, Serialize, Deserialize, Debug)]
-pub struct InlineQueryResultPhoto {
-    /// Type of the result, must be photo
-    #[serde(rename = "type")]
-    pub kind: String,
-    /// Unique identifier for this result, 1-64 bytes
+pub struct InlineQueryResultCachedDocument {
     pub id: String,
-    /// A valid URL of the photo. Photo must be in JPEG format. Photo size must not exceed 5MB
-    pub photo_url: String,
-    /// URL of the thumbnail for the photo
-    pub thumbnail_url: String,
-    /// Optional. Width of the photo
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub photo_width: Option<Integer>,
-    /// Optional. Height of the photo
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub photo_height: Option<Integer>,
-    /// Optional. Title for the result
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub title: Option<String>,
-    /// Optional. Short description of the result
+    pub title: String,
+    pub document_file_id: FileId,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
-    /// Optional. Caption of the photo to be sent, 0-1024 characters after entities parsing
     #[serde(skip_serializing_if = "Option::is_none")]
     pub caption: Option<String>,
     /// Optional. Mode for parsing entities in the photo caption. See formatting options for more details.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub parse_mode: Option<String>,
+    pub parse_mode: Option<ParseMode>,
     /// Optional. List of special entities that appear in the caption, which can be specified instead of parse_mode
     #[serde(skip_serializing_if = "Option::is_none")]
     pub caption_entities: Option<Vec<MessageEntity>>,
     /// Optional. True, if the caption must be shown above the message media
     #[serde(skip_serializing_if = "Option::is_none")]
     pub show_caption_above_media: Option<Boolean>,
-    /// Optional. Inline keyboard attached to the message
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_markup: Option<InlineKeyboardMarkup>,
-    /// Optional. Content of the message to be sent instead of the photo
     #[serde(skip_serializing_if = "Option::is_none")]
     pub input_message_content: Option<InputMessageContent>,
 }
 
-/// Represents a link to an animated GIF file. By default, this animated GIF file will be sent by the user with optional caption. Alternatively, you can use input_message_content to send a message with the specified content instead of the animation.
+/// Represents a link to an animated GIF file stored on the Telegram servers. By default, this animated GIF file will be sent by the user with an optional caption. Alternatively, you can use input_message_content to send a message with specified content instead of the animation.
 #[derive(Clone, Serialize, Deserialize, Debug)]
-pub struct InlineQueryResultGif {
-    /// Type of the result, must be gif
-    #[serde(rename = "type")]
-    pub kind: String,
+pub struct InlineQueryResultCachedGif {
     /// Unique identifier for this result, 1-64 bytes
     pub id: String,
-    /// A valid URL for the GIF file. File size must not exceed 1MB
-    pub gif_url: String,
-    /// Optional. Width of the GIF
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub gif_width: Option<Integer>,
-    /// Optional. Height of the GIF
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub gif_height: Option<Integer>,
-    /// Optional. Duration of the GIF in seconds
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub gif_duration: Option<Integer>,
-    /// URL of the static (JPEG or GIF) or animated (MPEG4) thumbnail for the result
-    pub thumbnail_url: String,
-    /// Optional. MIME type of the thumbnail, must be one of “image/jpeg”, “image/gif”, or “video/mp4”. Defaults to “image/jpeg”
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub thumbnail_mime_type: Option<String>,
+    /// A valid file identifier for the GIF file
+    pub gif_file_id: FileId,
     /// Optional. Title for the result
     #[serde(skip_serializing_if = "Option::is_none")]
     pub title: Option<String>,
@@ -178,7 +177,7 @@ pub struct InlineQueryResultGif {
     pub caption: Option<String>,
     /// Optional. Mode for parsing entities in the photo caption. See formatting options for more details.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub parse_mode: Option<String>,
+    pub parse_mode: Option<ParseMode>,
     /// Optional. List of special entities that appear in the caption, which can be specified instead of parse_mode
     #[serde(skip_serializing_if = "Option::is_none")]
     pub caption_entities: Option<Vec<MessageEntity>>,
@@ -193,30 +192,13 @@ pub struct InlineQueryResultGif {
     pub input_message_content: Option<InputMessageContent>,
 }
 
-/// Represents a link to a video animation (H.264/MPEG-4 AVC video without sound). By default, this animated MPEG-4 file will be sent by the user with optional caption. Alternatively, you can use input_message_content to send a message with the specified content instead of the animation.
+/// Represents a link to a video animation (H.264/MPEG-4 AVC video without sound) stored on the Telegram servers. By default, this animated MPEG-4 file will be sent by the user with an optional caption. Alternatively, you can use input_message_content to send a message with the specified content instead of the animation.
 #[derive(Clone, Serialize, Deserialize, Debug)]
-pub struct InlineQueryResultMpeg4Gif {
-    /// Type of the result, must be mpeg4_gif
-    #[serde(rename = "type")]
-    pub kind: String,
+pub struct InlineQueryResultCachedMpeg4Gif {
     /// Unique identifier for this result, 1-64 bytes
     pub id: String,
-    /// A valid URL for the MPEG4 file. File size must not exceed 1MB
-    pub mpeg4_url: String,
-    /// Optional. Video width
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub mpeg4_width: Option<Integer>,
-    /// Optional. Video height
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub mpeg4_height: Option<Integer>,
-    /// Optional. Video duration in seconds
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub mpeg4_duration: Option<Integer>,
-    /// URL of the static (JPEG or GIF) or animated (MPEG4) thumbnail for the result
-    pub thumbnail_url: String,
-    /// Optional. MIME type of the thumbnail, must be one of “image/jpeg”, “image/gif”, or “video/mp4”. Defaults to “image/jpeg”
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub thumbnail_mime_type: Option<String>,
+    /// A valid file identifier for the MPEG4 file
+    pub mpeg4_file_id: FileId,
     /// Optional. Title for the result
     #[serde(skip_serializing_if = "Option::is_none")]
     pub title: Option<String>,
@@ -225,7 +207,7 @@ pub struct InlineQueryResultMpeg4Gif {
     pub caption: Option<String>,
     /// Optional. Mode for parsing entities in the photo caption. See formatting options for more details.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub parse_mode: Option<String>,
+    pub parse_mode: Option<ParseMode>,
     /// Optional. List of special entities that appear in the caption, which can be specified instead of parse_mode
     #[serde(skip_serializing_if = "Option::is_none")]
     pub caption_entities: Option<Vec<MessageEntity>>,
@@ -240,67 +222,56 @@ pub struct InlineQueryResultMpeg4Gif {
     pub input_message_content: Option<InputMessageContent>,
 }
 
-/// Represents a link to a page containing an embedded video player or a video file. By default, this video file will be sent by the user with an optional caption. Alternatively, you can use input_message_content to send a message with the specified content instead of the video. If an InlineQueryResultVideo message contains an embedded video (e.g., YouTube), you must replace its content using input_message_content.
+/// Represents a link to a photo stored on the Telegram servers. By default, this photo will be sent by the user with an optional caption. Alternatively, you can use input_message_content to send a message with the specified content instead of the photo.
 #[derive(Clone, Serialize, Deserialize, Debug)]
-pub struct InlineQueryResultVideo {
-    /// Type of the result, must be video
-    #[serde(rename = "type")]
-    pub kind: String,
-    /// Unique identifier for this result, 1-64 bytes
+pub struct InlineQueryResultCachedPhoto {
     pub id: String,
-    /// A valid URL for the embedded video player or video file
-    pub video_url: String,
-    /// MIME type of the content of the video URL, “text/html” or “video/mp4”
-    pub mime_type: String,
-    /// URL of the thumbnail (JPEG only) for the video
-    pub thumbnail_url: String,
-    /// Title for the result
-    pub title: String,
-    /// Optional. Caption of the video to be sent, 0-1024 characters after entities parsing
+    pub photo_file_id: FileId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub caption: Option<String>,
     /// Optional. Mode for parsing entities in the photo caption. See formatting options for more details.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub parse_mode: Option<String>,
+    pub parse_mode: Option<ParseMode>,
     /// Optional. List of special entities that appear in the caption, which can be specified instead of parse_mode
     #[serde(skip_serializing_if = "Option::is_none")]
     pub caption_entities: Option<Vec<MessageEntity>>,
     /// Optional. True, if the caption must be shown above the message media
     #[serde(skip_serializing_if = "Option::is_none")]
     pub show_caption_above_media: Option<Boolean>,
-    /// Optional. Video width
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub video_width: Option<Integer>,
-    /// Optional. Video height
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub video_height: Option<Integer>,
-    /// Optional. Video duration in seconds
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub video_duration: Option<Integer>,
-    /// Optional. Short description of the result
+    pub reply_markup: Option<InlineKeyboardMarkup>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub description: Option<String>,
-    /// Optional. Inline keyboard attached to the message
+    pub input_message_content: Option<InputMessageContent>,
+}
+
+/// Represents a link to a sticker stored on the Telegram servers. By default, this sticker will be sent by the user. Alternatively, you can use input_message_content to send a message with the specified content instead of the sticker.
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct InlineQueryResultCachedSticker {
+    pub id: String,
+    pub sticker_file_id: FileId,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_markup: Option<InlineKeyboardMarkup>,
-    /// Optional. Content of the message to be sent instead of the video. This field is required if InlineQueryResultVideo is used to send an HTML-page as a result (e.g., a YouTube video).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub input_message_content: Option<InputMessageContent>,
 }
 
-/// Represents a link to an mp3 audio file. By default, this audio file will be sent by the user. Alternatively, you can use input_message_content to send a message with the specified content instead of the audio.
+/// Represents a link to a video file stored on the Telegram servers. By default, this video file will be sent by the user with an optional caption. Alternatively, you can use input_message_content to send a message with the specified content instead of the video.
 #[derive(Clone, Serialize, Deserialize, Debug)]
-pub struct InlineQueryResultAudio {
-    #[serde(rename = "type")]
-    pub kind: String,
+pub struct InlineQueryResultCachedVideo {
     pub id: String,
-    pub audio_url: String,
+    pub video_file_id: FileId,
     pub title: String,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub caption: Option<String>,
     /// Optional. Mode for parsing entities in the photo caption. See formatting options for more details.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub parse_mode: Option<String>,
+    pub parse_mode: Option<ParseMode>,
     /// Optional. List of special entities that appear in the caption, which can be specified instead of parse_mode
     #[serde(skip_serializing_if = "Option::is_none")]
     pub caption_entities: Option<Vec<MessageEntity>>,
@@ -308,28 +279,22 @@ pub struct InlineQueryResultAudio {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub show_caption_above_media: Option<Boolean>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub performer: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub audio_duration: Option<Integer>,
-    #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_markup: Option<InlineKeyboardMarkup>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub input_message_content: Option<InputMessageContent>,
 }
 
-/// Represents a link to a voice recording in an .ogg container encoded with OPUS. By default, this voice recording will be sent by the user. Alternatively, you can use input_message_content to send a message with the specified content instead of the the voice message.
+/// Represents a link to a voice message stored on the Telegram servers. By default, this voice message will be sent by the user. Alternatively, you can use input_message_content to send a message with the specified content instead of the voice message.
 #[derive(Clone, Serialize, Deserialize, Debug)]
-pub struct InlineQueryResultVoice {
-    #[serde(rename = "type")]
-    pub kind: String,
+pub struct InlineQueryResultCachedVoice {
     pub id: String,
-    pub voice_url: String,
+    pub voice_file_id: FileId,
     pub title: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub caption: Option<String>,
     /// Optional. Mode for parsing entities in the photo caption. See formatting options for more details.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub parse_mode: Option<String>,
+    pub parse_mode: Option<ParseMode>,
     /// Optional. List of special entities that appear in the caption, which can be specified instead of parse_mode
     #[serde(skip_serializing_if = "Option::is_none")]
     pub caption_entities: Option<Vec<MessageEntity>>,
@@ -337,49 +302,33 @@ pub struct InlineQueryResultVoice {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub show_caption_above_media: Option<Boolean>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub voice_duration: Option<Integer>,
-    #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_markup: Option<InlineKeyboardMarkup>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub input_message_content: Option<InputMessageContent>,
 }
 
-/// Represents a link to a file. By default, this file will be sent by the user with an optional caption. Alternatively, you can use input_message_content to send a message with the specified content instead of the file. Currently, only .PDF and .ZIP files can be sent using this method.
+/// Represents a link to an article or web page.
 #[derive(Clone, Serialize, Deserialize, Debug)]
-pub struct InlineQueryResultDocument {
-    /// Type of the result, must be document
-    #[serde(rename = "type")]
-    pub kind: String,
-    /// Unique identifier for this result, 1-64 bytes
+pub struct InlineQueryResultArticle {
+    /// Unique identifier for this result, 1-64 Bytes
     pub id: String,
-    /// Title for the result
+    /// Title of the result
     pub title: String,
-    /// Optional. Caption of the document to be sent, 0-1024 characters after entities parsing
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub caption: Option<String>,
-    /// Optional. Mode for parsing entities in the photo caption. See formatting options for more details.
+    /// Content of the message to be sent
+    pub input_message_content: InputMessageContent,
+    /// Optional. Inline keyboard attached to the message
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub parse_mode: Option<String>,
-    /// Optional. List of special entities that appear in the caption, which can be specified instead of parse_mode
+    pub reply_markup: Option<InlineKeyboardMarkup>,
+    /// Optional. URL of the result
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub caption_entities: Option<Vec<MessageEntity>>,
-    /// Optional. True, if the caption must be shown above the message media
+    pub url: Option<String>,
+    /// Optional. Pass True if you don't want the URL to be shown in the message
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub show_caption_above_media: Option<Boolean>,
-    /// A valid URL for the file
-    pub document_url: String,
-    /// MIME type of the content of the file, either “application/pdf” or “application/zip”
-    pub mime_type: String,
+    pub hide_url: Option<Boolean>,
     /// Optional. Short description of the result
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
-    /// Optional. Inline keyboard attached to the message
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub reply_markup: Option<InlineKeyboardMarkup>,
-    /// Optional. Content of the message to be sent instead of the file
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub input_message_content: Option<InputMessageContent>,
-    /// Optional. URL of the thumbnail (JPEG only) for the file
+    /// Optional. Url of the thumbnail for the result
     #[serde(skip_serializing_if = "Option::is_none")]
     pub thumbnail_url: Option<String>,
     /// Optional. Thumbnail width
@@ -390,81 +339,51 @@ pub struct InlineQueryResultDocument {
     pub thumbnail_height: Option<Integer>,
 }
 
-/// Represents a location on a map. By default, the location will be sent by the user. Alternatively, you can use input_message_content to send a message with the specified content instead of the location.
+/// Represents a link to an mp3 audio file. By default, this audio file will be sent by the user. Alternatively, you can use input_message_content to send a message with the specified content instead of the audio.
 #[derive(Clone, Serialize, Deserialize, Debug)]
-pub struct InlineQueryResultLocation {
-    /// Type of the result, must be location
-    #[serde(rename = "type")]
-    pub kind: String,
-    /// Unique identifier for this result, 1-64 Bytes
+pub struct InlineQueryResultAudio {
     pub id: String,
-    /// Location latitude in degrees
-    pub latitude: Float,
-    /// Location longitude in degrees
-    pub longitude: Float,
-    /// Location title
+    pub audio_url: String,
     pub title: String,
-    /// Optional. The radius of uncertainty for the location, measured in meters; 0-1500
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub horizontal_accuracy: Option<Float>,
-    /// Optional. Period in seconds for which the location can be updated, should be between 60 and 86400.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub live_period: Option<Integer>,
-    /// Optional. For live locations, a direction in which the user is moving, in degrees. Must be between 1 and 360 if specified.
+    pub caption: Option<String>,
+    /// Optional. Mode for parsing entities in the photo caption. See formatting options for more details.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub heading: Option<Integer>,
-    /// Optional. For live locations, a maximum distance for proximity alerts about approaching another chat member, in meters. Must be between 1 and 100000 if specified.
+    pub parse_mode: Option<ParseMode>,
+    /// Optional. List of special entities that appear in the caption, which can be specified instead of parse_mode
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub proximity_alert_radius: Option<Integer>,
-    /// Optional. Inline keyboard attached to the message
+    pub caption_entities: Option<Vec<MessageEntity>>,
+    /// Optional. True, if the caption must be shown above the message media
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub reply_markup: Option<InlineKeyboardMarkup>,
-    /// Optional. Content of the message to be sent instead of the location
+    pub show_caption_above_media: Option<Boolean>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub input_message_content: Option<InputMessageContent>,
-    /// Optional. Url of the thumbnail for the result
+    pub performer: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub thumbnail_url: Option<String>,
-    /// Optional. Thumbnail width
+    pub audio_duration: Option<Integer>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub thumbnail_width: Option<Integer>,
-    /// Optional. Thumbnail height
+    pub reply_markup: Option<InlineKeyboardMarkup>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub thumbnail_height: Option<Integer>,
+    pub input_message_content: Option<InputMessageContent>,
 }
 
-/// Represents a venue. By default, the venue will be sent by the user. Alternatively, you can use input_message_content to send a message with the specified content instead of the venue.
+/// Represents a contact with a phone number. By default, this contact will be sent by the user. Alternatively, you can use input_message_content to send a message with the specified content instead of the contact.
 #[derive(Clone, Serialize, Deserialize, Debug)]
-pub struct InlineQueryResultVenue {
-    /// Type of the result, must be venue
-    #[serde(rename = "type")]
-    pub kind: String,
+pub struct InlineQueryResultContact {
     /// Unique identifier for this result, 1-64 Bytes
     pub id: String,
-    /// Latitude of the venue location in degrees
-    pub latitude: Float,
-    /// Longitude of the venue location in degrees
-    pub longitude: Float,
-    /// Title of the venue
-    pub title: String,
-    /// Address of the venue
-    pub address: String,
-    /// Optional. Foursquare identifier of the venue if known
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub foursquare_id: Option<String>,
-    /// Optional. Foursquare type of the venue, if known. (For example, “arts_entertainment/default”, “arts_entertainment/aquarium” or “food/icecream”.)
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub foursquare_type: Option<String>,
-    /// Optional. Google Places identifier of the venue
+    /// Contact's phone number
+    pub phone_number: String,
+    /// Contact's first name
+    pub first_name: String,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub google_place_id: Option<String>,
-    /// Optional. Google Places type of the venue. (See supported types.)
+    pub last_name: Option<String>,
+    /// Optional. Additional data about the contact in the form of a vCard, 0-2048 bytes
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub google_place_type: Option<String>,
+    pub vcard: Option<String>,
     /// Optional. Inline keyboard attached to the message
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_markup: Option<InlineKeyboardMarkup>,
-    /// Optional. Content of the message to be sent instead of the venue
+    /// Optional. Content of the message to be sent instead of the contact
     #[serde(skip_serializing_if = "Option::is_none")]
     pub input_message_content: Option<InputMessageContent>,
     /// Optional. Url of the thumbnail for the result
@@ -478,31 +397,48 @@ pub struct InlineQueryResultVenue {
     pub thumbnail_height: Option<Integer>,
 }
 
-/// Represents a contact with a phone number. By default, this contact will be sent by the user. Alternatively, you can use input_message_content to send a message with the specified content instead of the contact.
+/// Represents a Game.
 #[derive(Clone, Serialize, Deserialize, Debug)]
-pub struct InlineQueryResultContact {
-    /// Type of the result, must be contact
-    #[serde(rename = "type")]
-    pub kind: String,
-    /// Unique identifier for this result, 1-64 Bytes
+pub struct InlineQueryResultGame {
     pub id: String,
-    /// Contact's phone number
-    pub phone_number: String,
-    /// Contact's first name
-    pub first_name: String,
-    // Optional. Contact's last name
+    pub game_short_name: String,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_name: Option<String>,
-    /// Optional. Additional data about the contact in the form of a vCard, 0-2048 bytes
+    pub reply_markup: Option<InlineKeyboardMarkup>,
+}
+
+/// Represents a link to a file. By default, this file will be sent by the user with an optional caption. Alternatively, you can use input_message_content to send a message with the specified content instead of the file. Currently, only .PDF and .ZIP files can be sent using this method.
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct InlineQueryResultDocument {
+    /// Unique identifier for this result, 1-64 bytes
+    pub id: String,
+    /// Title for the result
+    pub title: String,
+    /// Optional. Caption of the document to be sent, 0-1024 characters after entities parsing
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+    /// Optional. Mode for parsing entities in the photo caption. See formatting options for more details.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+    /// Optional. List of special entities that appear in the caption, which can be specified instead of parse_mode
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<MessageEntity>>,
+    /// Optional. True, if the caption must be shown above the message media
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub show_caption_above_media: Option<Boolean>,
+    /// A valid URL for the file
+    pub document_url: String,
+    /// MIME type of the content of the file, either “application/pdf” or “application/zip”
+    pub mime_type: String,
+    /// Optional. Short description of the result
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub vcard: Option<String>,
+    pub description: Option<String>,
     /// Optional. Inline keyboard attached to the message
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_markup: Option<InlineKeyboardMarkup>,
-    /// Optional. Content of the message to be sent instead of the contact
+    /// Optional. Content of the message to be sent instead of the file
     #[serde(skip_serializing_if = "Option::is_none")]
     pub input_message_content: Option<InputMessageContent>,
-    /// Optional. Url of the thumbnail for the result
+    /// Optional. URL of the thumbnail (JPEG only) for the file
     #[serde(skip_serializing_if = "Option::is_none")]
     pub thumbnail_url: Option<String>,
     /// Optional. Thumbnail width
@@ -513,88 +449,111 @@ pub struct InlineQueryResultContact {
     pub thumbnail_height: Option<Integer>,
 }
 
-/// Represents a Game.
+/// Represents a link to an animated GIF file. By default, this animated GIF file will be sent by the user with optional caption. Alternatively, you can use input_message_content to send a message with the specified content instead of the animation.
 #[derive(Clone, Serialize, Deserialize, Debug)]
-pub struct InlineQueryResultGame {
-    #[serde(rename = "type")]
-    pub kind: String,
+pub struct InlineQueryResultGif {
+    /// Unique identifier for this result, 1-64 bytes
     pub id: String,
-    pub game_short_name: String,
+    /// A valid URL for the GIF file. File size must not exceed 1MB
+    pub gif_url: String,
+    /// Optional. Width of the GIF
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub reply_markup: Option<InlineKeyboardMarkup>,
-}
-
-/// Represents a link to a photo stored on the Telegram servers. By default, this photo will be sent by the user with an optional caption. Alternatively, you can use input_message_content to send a message with the specified content instead of the photo.
-#[derive(Clone, Serialize, Deserialize, Debug)]
-pub struct InlineQueryResultCachedPhoto {
-    #[serde(rename = "type")]
-    pub kind: String,
-    pub id: String,
-    pub photo_file_id: String,
+    pub gif_width: Option<Integer>,
+    /// Optional. Height of the GIF
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub title: Option<String>,
+    pub gif_height: Option<Integer>,
+    /// Optional. Duration of the GIF in seconds
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub description: Option<String>,
+    pub gif_duration: Option<Integer>,
+    /// URL of the static (JPEG or GIF) or animated (MPEG4) thumbnail for the result
+    pub thumbnail_url: String,
+    /// Optional. MIME type of the thumbnail, must be one of “image/jpeg”, “image/gif”, or “video/mp4”. Defaults to “image/jpeg”
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail_mime_type: Option<String>,
+    /// Optional. Title for the result
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// Optional. Caption of the GIF file to be sent, 0-1024 characters after entities parsing
     #[serde(skip_serializing_if = "Option::is_none")]
     pub caption: Option<String>,
     /// Optional. Mode for parsing entities in the photo caption. See formatting options for more details.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub parse_mode: Option<String>,
+    pub parse_mode: Option<ParseMode>,
     /// Optional. List of special entities that appear in the caption, which can be specified instead of parse_mode
     #[serde(skip_serializing_if = "Option::is_none")]
     pub caption_entities: Option<Vec<MessageEntity>>,
     /// Optional. True, if the caption must be shown above the message media
     #[serde(skip_serializing_if = "Option::is_none")]
     pub show_caption_above_media: Option<Boolean>,
+    /// Optional. Inline keyboard attached to the message
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_markup: Option<InlineKeyboardMarkup>,
+    /// Optional. Content of the message to be sent instead of the GIF animation
     #[serde(skip_serializing_if = "Option::is_none")]
     pub input_message_content: Option<InputMessageContent>,
 }
 
-/// Represents a link to an animated GIF file stored on the Telegram servers. By default, this animated GIF file will be sent by the user with an optional caption. Alternatively, you can use input_message_content to send a message with specified content instead of the animation.
+/// Represents a location on a map. By default, the location will be sent by the user. Alternatively, you can use input_message_content to send a message with the specified content instead of the location.
 #[derive(Clone, Serialize, Deserialize, Debug)]
-pub struct InlineQueryResultCachedGif {
-    /// Type of the result, must be gif
-    #[serde(rename = "type")]
-    pub kind: String,
-    /// Unique identifier for this result, 1-64 bytes
+pub struct InlineQueryResultLocation {
+    /// Unique identifier for this result, 1-64 Bytes
     pub id: String,
-    /// A valid file identifier for the GIF file
-    pub gif_file_id: String,
-    /// Optional. Title for the result
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub title: Option<String>,
-    /// Optional. Caption of the GIF file to be sent, 0-1024 characters after entities parsing
+    /// Location latitude in degrees
+    pub latitude: Float,
+    /// Location longitude in degrees
+    pub longitude: Float,
+    /// Location title
+    pub title: String,
+    /// Optional. The radius of uncertainty for the location, measured in meters; 0-1500
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub caption: Option<String>,
-    /// Optional. Mode for parsing entities in the photo caption. See formatting options for more details.
+    pub horizontal_accuracy: Option<Float>,
+    /// Optional. Period in seconds for which the location can be updated, should be between 60 and 86400.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub parse_mode: Option<String>,
-    /// Optional. List of special entities that appear in the caption, which can be specified instead of parse_mode
+    pub live_period: Option<Integer>,
+    /// Optional. For live locations, a direction in which the user is moving, in degrees. Must be between 1 and 360 if specified.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub caption_entities: Option<Vec<MessageEntity>>,
-    /// Optional. True, if the caption must be shown above the message media
+    pub heading: Option<Integer>,
+    /// Optional. For live locations, a maximum distance for proximity alerts about approaching another chat member, in meters. Must be between 1 and 100000 if specified.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub show_caption_above_media: Option<Boolean>,
+    pub proximity_alert_radius: Option<Integer>,
     /// Optional. Inline keyboard attached to the message
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_markup: Option<InlineKeyboardMarkup>,
-    /// Optional. Content of the message to be sent instead of the GIF animation
+    /// Optional. Content of the message to be sent instead of the location
     #[serde(skip_serializing_if = "Option::is_none")]
     pub input_message_content: Option<InputMessageContent>,
+    /// Optional. Url of the thumbnail for the result
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail_url: Option<String>,
+    /// Optional. Thumbnail width
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail_width: Option<Integer>,
+    /// Optional. Thumbnail height
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail_height: Option<Integer>,
 }
 
-/// Represents a link to a video animation (H.264/MPEG-4 AVC video without sound) stored on the Telegram servers. By default, this animated MPEG-4 file will be sent by the user with an optional caption. Alternatively, you can use input_message_content to send a message with the specified content instead of the animation.
+/// Represents a link to a video animation (H.264/MPEG-4 AVC video without sound). By default, this animated MPEG-4 file will be sent by the user with optional caption. Alternatively, you can use input_message_content to send a message with the specified content instead of the animation.
 #[derive(Clone, Serialize, Deserialize, Debug)]
-pub struct InlineQueryResultCachedMpeg4Gif {
-    /// Type of the result, must be mpeg4_gif
-    #[serde(rename = "type")]
-    pub kind: String,
+pub struct InlineQueryResultMpeg4Gif {
     /// Unique identifier for this result, 1-64 bytes
     pub id: String,
-    /// A valid file identifier for the MPEG4 file
-    pub mpeg4_file_id: String,
+    /// A valid URL for the MPEG4 file. File size must not exceed 1MB
+    pub mpeg4_url: String,
+    /// Optional. Video width
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mpeg4_width: Option<Integer>,
+    /// Optional. Video height
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mpeg4_height: Option<Integer>,
+    /// Optional. Video duration in seconds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mpeg4_duration: Option<Integer>,
+    /// URL of the static (JPEG or GIF) or animated (MPEG4) thumbnail for the result
+    pub thumbnail_url: String,
+    /// Optional. MIME type of the thumbnail, must be one of “image/jpeg”, “image/gif”, or “video/mp4”. Defaults to “image/jpeg”
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail_mime_type: Option<String>,
     /// Optional. Title for the result
     #[serde(skip_serializing_if = "Option::is_none")]
     pub title: Option<String>,
@@ -603,7 +562,7 @@ pub struct InlineQueryResultCachedMpeg4Gif {
     pub caption: Option<String>,
     /// Optional. Mode for parsing entities in the photo caption. See formatting options for more details.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub parse_mode: Option<String>,
+    pub parse_mode: Option<ParseMode>,
     /// Optional. List of special entities that appear in the caption, which can be specified instead of parse_mode
     #[serde(skip_serializing_if = "Option::is_none")]
     pub caption_entities: Option<Vec<MessageEntity>>,
@@ -618,110 +577,145 @@ pub struct InlineQueryResultCachedMpeg4Gif {
     pub input_message_content: Option<InputMessageContent>,
 }
 
-/// Represents a link to a sticker stored on the Telegram servers. By default, this sticker will be sent by the user. Alternatively, you can use input_message_content to send a message with the specified content instead of the sticker.
+/// Represents a link to a photo. By default, this photo will be sent by the user with optional caption. Alternatively, you can use input_message_content to send a message with the specified content instead of the photo.
 #[derive(Clone, Serialize, Deserialize, Debug)]
-pub struct InlineQueryResultCachedSticker {
-    #[serde(rename = "type")]
-    pub kind: String,
+pub struct InlineQueryResultPhoto {
+    /// Unique identifier for this result, 1-64 bytes
     pub id: String,
-    pub sticker_file_id: String,
+    /// A valid URL of the photo. Photo must be in JPEG format. Photo size must not exceed 5MB
+    pub photo_url: String,
+    /// URL of the thumbnail for the photo
+    pub thumbnail_url: String,
+    /// Optional. Width of the photo
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub reply_markup: Option<InlineKeyboardMarkup>,
+    pub photo_width: Option<Integer>,
+    /// Optional. Height of the photo
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub input_message_content: Option<InputMessageContent>,
-}
-
-/// Represents a link to a file stored on the Telegram servers. By default, this file will be sent by the user with an optional caption. Alternatively, you can use input_message_content to send a message with the specified content instead of the file.
-#[derive(Clone, Serialize, Deserialize, Debug)]
-pub struct InlineQueryResultCachedDocument {
-    #[serde(rename = "type")]
-    pub kind: String,
-    pub id: String,
-    pub title: String,
-    pub document_file_id: String,
+    pub photo_height: Option<Integer>,
+    /// Optional. Title for the result
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// Optional. Short description of the result
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
+    /// Optional. Caption of the photo to be sent, 0-1024 characters after entities parsing
     #[serde(skip_serializing_if = "Option::is_none")]
     pub caption: Option<String>,
     /// Optional. Mode for parsing entities in the photo caption. See formatting options for more details.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub parse_mode: Option<String>,
+    pub parse_mode: Option<ParseMode>,
     /// Optional. List of special entities that appear in the caption, which can be specified instead of parse_mode
     #[serde(skip_serializing_if = "Option::is_none")]
     pub caption_entities: Option<Vec<MessageEntity>>,
     /// Optional. True, if the caption must be shown above the message media
     #[serde(skip_serializing_if = "Option::is_none")]
     pub show_caption_above_media: Option<Boolean>,
+    /// Optional. Inline keyboard attached to the message
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_markup: Option<InlineKeyboardMarkup>,
+    /// Optional. Content of the message to be sent instead of the photo
     #[serde(skip_serializing_if = "Option::is_none")]
     pub input_message_content: Option<InputMessageContent>,
 }
 
-/// Represents a link to a video file stored on the Telegram servers. By default, this video file will be sent by the user with an optional caption. Alternatively, you can use input_message_content to send a message with the specified content instead of the video.
+/// Represents a venue. By default, the venue will be sent by the user. Alternatively, you can use input_message_content to send a message with the specified content instead of the venue.
 #[derive(Clone, Serialize, Deserialize, Debug)]
-pub struct InlineQueryResultCachedVideo {
-    #[serde(rename = "type")]
-    pub kind: String,
+pub struct InlineQueryResultVenue {
+    /// Unique identifier for this result, 1-64 Bytes
     pub id: String,
-    pub video_file_id: String,
+    /// Latitude of the venue location in degrees
+    pub latitude: Float,
+    /// Longitude of the venue location in degrees
+    pub longitude: Float,
+    /// Title of the venue
     pub title: String,
+    /// Address of the venue
+    pub address: String,
+    /// Optional. Foursquare identifier of the venue if known
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub description: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub caption: Option<String>,
-    /// Optional. Mode for parsing entities in the photo caption. See formatting options for more details.
+    pub foursquare_id: Option<String>,
+    /// Optional. Foursquare type of the venue, if known. (For example, “arts_entertainment/default”, “arts_entertainment/aquarium” or “food/icecream”.)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub parse_mode: Option<String>,
-    /// Optional. List of special entities that appear in the caption, which can be specified instead of parse_mode
+    pub foursquare_type: Option<String>,
+    /// Optional. Google Places identifier of the venue
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub caption_entities: Option<Vec<MessageEntity>>,
-    /// Optional. True, if the caption must be shown above the message media
+    pub google_place_id: Option<String>,
+    /// Optional. Google Places type of the venue. (See supported types.)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub show_caption_above_media: Option<Boolean>,
+    pub google_place_type: Option<String>,
+    /// Optional. Inline keyboard attached to the message
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_markup: Option<InlineKeyboardMarkup>,
+    /// Optional. Content of the message to be sent instead of the venue
     #[serde(skip_serializing_if = "Option::is_none")]
     pub input_message_content: Option<InputMessageContent>,
+    /// Optional. Url of the thumbnail for the result
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail_url: Option<String>,
+    /// Optional. Thumbnail width
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail_width: Option<Integer>,
+    /// Optional. Thumbnail height
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail_height: Option<Integer>,
 }
 
-/// Represents a link to a voice message stored on the Telegram servers. By default, this voice message will be sent by the user. Alternatively, you can use input_message_content to send a message with the specified content instead of the voice message.
+/// Represents a link to a page containing an embedded video player or a video file. By default, this video file will be sent by the user with an optional caption. Alternatively, you can use input_message_content to send a message with the specified content instead of the video. If an InlineQueryResultVideo message contains an embedded video (e.g., YouTube), you must replace its content using input_message_content.
 #[derive(Clone, Serialize, Deserialize, Debug)]
-pub struct InlineQueryResultCachedVoice {
-    #[serde(rename = "type")]
-    pub kind: String,
+pub struct InlineQueryResultVideo {
+    /// Unique identifier for this result, 1-64 bytes
     pub id: String,
-    pub voice_file_id: String,
+    /// A valid URL for the embedded video player or video file
+    pub video_url: String,
+    /// MIME type of the content of the video URL, “text/html” or “video/mp4”
+    pub mime_type: String,
+    /// URL of the thumbnail (JPEG only) for the video
+    pub thumbnail_url: String,
+    /// Title for the result
     pub title: String,
+    /// Optional. Caption of the video to be sent, 0-1024 characters after entities parsing
     #[serde(skip_serializing_if = "Option::is_none")]
     pub caption: Option<String>,
     /// Optional. Mode for parsing entities in the photo caption. See formatting options for more details.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub parse_mode: Option<String>,
+    pub parse_mode: Option<ParseMode>,
     /// Optional. List of special entities that appear in the caption, which can be specified instead of parse_mode
     #[serde(skip_serializing_if = "Option::is_none")]
     pub caption_entities: Option<Vec<MessageEntity>>,
     /// Optional. True, if the caption must be shown above the message media
     #[serde(skip_serializing_if = "Option::is_none")]
     pub show_caption_above_media: Option<Boolean>,
+    /// Optional. Video width
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub video_width: Option<Integer>,
+    /// Optional. Video height
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub video_height: Option<Integer>,
+    /// Optional. Video duration in seconds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub video_duration: Option<Integer>,
+    /// Optional. Short description of the result
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Optional. Inline keyboard attached to the message
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_markup: Option<InlineKeyboardMarkup>,
+    /// Optional. Content of the message to be sent instead of the video. This field is required if InlineQueryResultVideo is used to send an HTML-page as a result (e.g., a YouTube video).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub input_message_content: Option<InputMessageContent>,
 }
 
-/// Represents a link to an mp3 audio file stored on the Telegram servers. By default, this audio file will be sent by the user. Alternatively, you can use input_message_content to send a message with the specified content instead of the audio.
+/// Represents a link to a voice recording in an .ogg container encoded with OPUS. By default, this voice recording will be sent by the user. Alternatively, you can use input_message_content to send a message with the specified content instead of the the voice message.
 #[derive(Clone, Serialize, Deserialize, Debug)]
-pub struct InlineQueryResultCachedAudio {
-    #[serde(rename = "type")]
-    pub kind: String,
+pub struct InlineQueryResultVoice {
     pub id: String,
-    pub audio_file_id: String,
+    pub voice_url: String,
+    pub title: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub caption: Option<String>,
     /// Optional. Mode for parsing entities in the photo caption. See formatting options for more details.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub parse_mode: Option<String>,
+    pub parse_mode: Option<ParseMode>,
     /// Optional. List of special entities that appear in the caption, which can be specified instead of parse_mode
     #[serde(skip_serializing_if = "Option::is_none")]
     pub caption_entities: Option<Vec<MessageEntity>>,
@@ -729,6 +723,8 @@ pub struct InlineQueryResultCachedAudio {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub show_caption_above_media: Option<Boolean>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub voice_duration: Option<Integer>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_markup: Option<InlineKeyboardMarkup>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub input_message_content: Option<InputMessageContent>,
@@ -736,11 +732,13 @@ pub struct InlineQueryResultCachedAudio {
 
 /// This object represents the content of a message to be sent as a result of an inline query. Telegram clients currently support the following 4 types:
 #[derive(Clone, Serialize, Deserialize, Debug)]
+#[serde(untagged)]
 pub enum InputMessageContent {
-    InputTextMessageContent,
-    InputLocationMessageContent,
-    InputVenueMessageContent,
-    InputContactMessageContent,
+    InputTextMessageContent(InputTextMessageContent),
+    InputVenueMessageContent(InputVenueMessageContent),
+    InputLocationMessageContent(InputLocationMessageContent),
+    InputContactMessageContent(InputContactMessageContent),
+    InputInvoiceMessageContent(InputInvoiceMessageContent),
 }
 
 /// Represents the content of a text message to be sent as the result of an inline query.
@@ -749,7 +747,7 @@ pub struct InputTextMessageContent {
     pub message_text: String,
     /// Optional. Mode for parsing entities in the photo caption. See formatting options for more details.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub parse_mode: Option<String>,
+    pub parse_mode: Option<ParseMode>,
     /// Optional. List of special entities that appear in the caption, which can be specified instead of parse_mode
     #[serde(skip_serializing_if = "Option::is_none")]
     pub entities: Option<Vec<MessageEntity>>,
@@ -758,21 +756,6 @@ pub struct InputTextMessageContent {
     pub link_preview_options: Option<LinkPreviewOptions>,
 }
 
-//Represents the content of a location message to be sent as the result of an inline query.
-#[derive(Clone, Serialize, Deserialize, Debug)]
-pub struct InputLocationMessageContent {
-    pub latitude: Float,
-    pub longitude: Float,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub horizontal_accuracy: Option<Float>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub live_period: Option<Integer>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub heading: Option<Integer>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub proximity_alert_radius: Option<Integer>,
-}
-
 /// Represents the content of a venue message to be sent as the result of an inline query.
 #[derive(Clone, Serialize, Deserialize, Debug)]
 pub struct InputVenueMessageContent {
@@ -792,6 +775,20 @@ pub struct InputVenueMessageContent {
     pub google_place_type: Option<String>,
 }
 
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct InputLocationMessageContent {
+    pub latitude: Float,
+    pub longitude: Float,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub horizontal_accuracy: Option<Float>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub live_period: Option<Integer>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub heading: Option<Integer>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proximity_alert_radius: Option<Integer>,
+}
+
 /// Represents the content of a contact message to be sent as the result of an inline query.
 #[derive(Clone, Serialize, Deserialize, Debug)]
 pub struct InputContactMessageContent {
@@ -866,23 +863,3 @@ pub struct InputInvoiceMessageContent {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub is_flexible: Option<Boolean>,
 }
-
-/// Represents a result of an inline query that was chosen by the user and sent to their chat partner.
-#[derive(Clone, Serialize, Deserialize, Debug)]
-pub struct ChosenInlineResult {
-    pub result_id: String,
-    pub from: User,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub location: Option<Location>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub inline_message_id: Option<String>,
-    pub query: String,
-}
-
-/// Contains information about an inline message sent by a [webapps](https://core.telegram.org/bots/webapps) on behalf of a user.
-#[derive(Clone, Serialize, Deserialize, Debug)]
-pub struct SentWebAppMessage {
-    /// Optional. Identifier of the sent inline message. Available only if there is an inline keyboard attached to the message.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub inline_message_id: Option<String>,
-}
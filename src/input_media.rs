@@ -1,128 +1,91 @@
 use serde::{Deserialize, Serialize};
 
 use crate::{
+    formatting::ParseMode,
     message::MessageEntity,
     types::{Boolean, InputFileString, Integer},
 };
 
+// The types below are generated from spec/bot_api.json by `cargo run -p rutel_codegen`, edit the spec instead.
+
 /// This object represents the content of a media message to be sent. It should be one of InputMediaPhoto InputMediaVideo
 #[derive(Clone, Serialize, Deserialize, Debug)]
+#[serde(tag = "type")]
 pub enum InputMedia {
-    InputMediaAnimation,
-    InputMediaDocument,
-    InputMediaAudio,
-    InputMediaPhoto,
-    InputMediaVideo,
+    #[serde(rename = "animation")]
+    InputMediaAnimation(InputMediaAnimation),
+    #[serde(rename = "document")]
+    InputMediaDocument(InputMediaDocument),
+    #[serde(rename = "audio")]
+    InputMediaAudio(InputMediaAudio),
+    #[serde(rename = "photo")]
+    InputMediaPhoto(InputMediaPhoto),
+    #[serde(rename = "video")]
+    InputMediaVideo(InputMediaVideo),
 }
 
-/// Represents a photo to be sent.
-#[derive(Clone, Serialize, Deserialize, Debug)]
-pub struct InputMediaPhoto {
-    /// Type of the result, must be photo
-    #[serde(rename = "type")]
-    pub kind: String,
-    /// File to send. Pass a file_id to send a file that exists on the Telegram servers (recommended), pass an HTTP URL for Telegram to get a file from the Internet, or pass “attach://<file_attach_name>” to upload a new one using multipart/form-data under <file_attach_name> name. More information on Sending Files »
-    pub media: String,
-    /// Optional. Caption of the photo to be sent, 0-1024 characters after entities parsing
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub caption: Option<String>,
-    /// Optional. Mode for parsing entities in the photo caption. See formatting options for more details.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub parse_mode: Option<String>,
-    /// Optional. List of special entities that appear in the caption, which can be specified instead of parse_mode
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub caption_entities: Option<Vec<MessageEntity>>,
-    /// Optional. True, if the caption must be shown above the message media
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub show_caption_above_media: Option<Boolean>,
-    /// Optional. Pass True if the photo needs to be covered with a spoiler animation
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub has_spoiler: Option<Boolean>,
-}
-
-/// Represents a video to be sent.
+/// Represents an animation file (GIF or H.264/MPEG-4 AVC video without sound) to be sent.
 #[derive(Clone, Serialize, Deserialize, Debug)]
-pub struct InputMediaVideo {
-    /// Type of the result, must be video
-    #[serde(rename = "type")]
-    pub kind: String,
+pub struct InputMediaAnimation {
     /// File to send. Pass a file_id to send a file that exists on the Telegram servers (recommended), pass an HTTP URL for Telegram to get a file from the Internet, or pass “attach://<file_attach_name>” to upload a new one using multipart/form-data under <file_attach_name> name. More information on Sending Files »
     pub media: String,
     /// Optional. Thumbnail of the file sent; can be ignored if thumbnail generation for the file is supported server-side. The thumbnail should be in JPEG format and less than 200 kB in size. A thumbnail's width and height should not exceed 320. Ignored if the file is not uploaded using multipart/form-data. Thumbnails can't be reused and can be only uploaded as a new file, so you can pass “attach://<file_attach_name>” if the thumbnail was uploaded using multipart/form-data under <file_attach_name>. More information on Sending Files »
     #[serde(skip_serializing_if = "Option::is_none")]
     pub thumbnail: Option<InputFileString>,
-    /// Optional. Caption of the video to be sent, 0-1024 characters after entities parsing
+    /// Optional. Caption of the animation to be sent, 0-1024 characters after entities parsing
     #[serde(skip_serializing_if = "Option::is_none")]
     pub caption: Option<String>,
-    /// Optional. Mode for parsing entities in the video caption. See formatting options for more details.
+    /// Optional. Mode for parsing entities in the animation caption. See formatting options for more details.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub parse_mode: Option<String>,
+    pub parse_mode: Option<ParseMode>,
     /// Optional. List of special entities that appear in the caption, which can be specified instead of parse_mode
     #[serde(skip_serializing_if = "Option::is_none")]
     pub caption_entities: Option<Vec<MessageEntity>>,
     /// Optional. True, if the caption must be shown above the message media
     #[serde(skip_serializing_if = "Option::is_none")]
     pub show_caption_above_media: Option<Boolean>,
-    /// Optional. Video width
+    /// Optional. Animation width
     #[serde(skip_serializing_if = "Option::is_none")]
     pub width: Option<Integer>,
-    /// Optional. Video height
+    /// Optional. Animation height
     #[serde(skip_serializing_if = "Option::is_none")]
     pub height: Option<Integer>,
-    /// Optional. Video duration in seconds
+    /// Optional. Animation duration in seconds
     #[serde(skip_serializing_if = "Option::is_none")]
     pub duration: Option<Integer>,
-    /// Optional. Pass True if the uploaded video is suitable for streaming
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub supports_streaming: Option<Boolean>,
-    /// Optional. Pass True if the video needs to be covered with a spoiler animation
+    /// Optional. Pass True if the animation needs to be covered with a spoiler animation
     #[serde(skip_serializing_if = "Option::is_none")]
     pub has_spoiler: Option<Boolean>,
 }
 
-/// Represents an animation file (GIF or H.264/MPEG-4 AVC video without sound) to be sent.
+/// Represents a general file to be sent.
 #[derive(Clone, Serialize, Deserialize, Debug)]
-pub struct InputMediaAnimation {
-    /// Type of the result, must be animation
-    #[serde(rename = "type")]
-    pub kind: String,
+pub struct InputMediaDocument {
     /// File to send. Pass a file_id to send a file that exists on the Telegram servers (recommended), pass an HTTP URL for Telegram to get a file from the Internet, or pass “attach://<file_attach_name>” to upload a new one using multipart/form-data under <file_attach_name> name. More information on Sending Files »
     pub media: String,
     /// Optional. Thumbnail of the file sent; can be ignored if thumbnail generation for the file is supported server-side. The thumbnail should be in JPEG format and less than 200 kB in size. A thumbnail's width and height should not exceed 320. Ignored if the file is not uploaded using multipart/form-data. Thumbnails can't be reused and can be only uploaded as a new file, so you can pass “attach://<file_attach_name>” if the thumbnail was uploaded using multipart/form-data under <file_attach_name>. More information on Sending Files »
     #[serde(skip_serializing_if = "Option::is_none")]
     pub thumbnail: Option<InputFileString>,
-    /// Optional. Caption of the animation to be sent, 0-1024 characters after entities parsing
+    /// Optional. Caption of the document to be sent, 0-1024 characters after entities parsing
     #[serde(skip_serializing_if = "Option::is_none")]
     pub caption: Option<String>,
-    /// Optional. Mode for parsing entities in the animation caption. See formatting options for more details.
+    /// Optional. Mode for parsing entities in the photo caption. See formatting options for more details.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub parse_mode: Option<String>,
+    pub parse_mode: Option<ParseMode>,
     /// Optional. List of special entities that appear in the caption, which can be specified instead of parse_mode
     #[serde(skip_serializing_if = "Option::is_none")]
     pub caption_entities: Option<Vec<MessageEntity>>,
     /// Optional. True, if the caption must be shown above the message media
     #[serde(skip_serializing_if = "Option::is_none")]
     pub show_caption_above_media: Option<Boolean>,
-    /// Optional. Animation width
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub width: Option<Integer>,
-    /// Optional. Animation height
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub height: Option<Integer>,
-    /// Optional. Animation duration in seconds
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub duration: Option<Integer>,
-    /// Optional. Pass True if the animation needs to be covered with a spoiler animation
+    /// Optional. Disables automatic server-side content type detection for files uploaded using multipart/form-data. Always True, if the document is sent as part of an album.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub has_spoiler: Option<Boolean>,
+    pub disable_content_type_detection: Option<Boolean>,
 }
 
 /// Represents an audio file to be treated as music to be sent.
 #[derive(Clone, Serialize, Deserialize, Debug)]
 pub struct InputMediaAudio {
-    /// Type of the result, must be audio
-    #[serde(rename = "type")]
-    pub kind: String,
     /// File to send. Pass a file_id to send a file that exists on the Telegram servers (recommended), pass an HTTP URL for Telegram to get a file from the Internet, or pass “attach://<file_attach_name>” to upload a new one using multipart/form-data under <file_attach_name> name. More information on Sending Files »
     pub media: String,
     /// Optional. Thumbnail of the file sent; can be ignored if thumbnail generation for the file is supported server-side. The thumbnail should be in JPEG format and less than 200 kB in size. A thumbnail's width and height should not exceed 320. Ignored if the file is not uploaded using multipart/form-data. Thumbnails can't be reused and can be only uploaded as a new file, so you can pass “attach://<file_attach_name>” if the thumbnail was uploaded using multipart/form-data under <file_attach_name>. More information on Sending Files »
@@ -133,7 +96,7 @@ pub struct InputMediaAudio {
     pub caption: Option<String>,
     /// Optional. Mode for parsing entities in the photo caption. See formatting options for more details.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub parse_mode: Option<String>,
+    pub parse_mode: Option<ParseMode>,
     /// Optional. List of special entities that appear in the caption, which can be specified instead of parse_mode
     #[serde(skip_serializing_if = "Option::is_none")]
     pub caption_entities: Option<Vec<MessageEntity>>,
@@ -151,30 +114,61 @@ pub struct InputMediaAudio {
     pub title: Option<String>,
 }
 
-/// Represents a general file to be sent.
+/// Represents a photo to be sent.
 #[derive(Clone, Serialize, Deserialize, Debug)]
-pub struct InputMediaDocument {
-    /// Type of the result, must be document
-    #[serde(rename = "type")]
-    pub kind: String,
+pub struct InputMediaPhoto {
+    /// File to send. Pass a file_id to send a file that exists on the Telegram servers (recommended), pass an HTTP URL for Telegram to get a file from the Internet, or pass “attach://<file_attach_name>” to upload a new one using multipart/form-data under <file_attach_name> name. More information on Sending Files »
+    pub media: String,
+    /// Optional. Caption of the photo to be sent, 0-1024 characters after entities parsing
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+    /// Optional. Mode for parsing entities in the photo caption. See formatting options for more details.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+    /// Optional. List of special entities that appear in the caption, which can be specified instead of parse_mode
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<MessageEntity>>,
+    /// Optional. True, if the caption must be shown above the message media
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub show_caption_above_media: Option<Boolean>,
+    /// Optional. Pass True if the photo needs to be covered with a spoiler animation
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_spoiler: Option<Boolean>,
+}
+
+/// Represents a video to be sent.
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct InputMediaVideo {
     /// File to send. Pass a file_id to send a file that exists on the Telegram servers (recommended), pass an HTTP URL for Telegram to get a file from the Internet, or pass “attach://<file_attach_name>” to upload a new one using multipart/form-data under <file_attach_name> name. More information on Sending Files »
     pub media: String,
     /// Optional. Thumbnail of the file sent; can be ignored if thumbnail generation for the file is supported server-side. The thumbnail should be in JPEG format and less than 200 kB in size. A thumbnail's width and height should not exceed 320. Ignored if the file is not uploaded using multipart/form-data. Thumbnails can't be reused and can be only uploaded as a new file, so you can pass “attach://<file_attach_name>” if the thumbnail was uploaded using multipart/form-data under <file_attach_name>. More information on Sending Files »
     #[serde(skip_serializing_if = "Option::is_none")]
     pub thumbnail: Option<InputFileString>,
-    /// Optional. Caption of the document to be sent, 0-1024 characters after entities parsing
+    /// Optional. Caption of the video to be sent, 0-1024 characters after entities parsing
     #[serde(skip_serializing_if = "Option::is_none")]
     pub caption: Option<String>,
-    /// Optional. Mode for parsing entities in the photo caption. See formatting options for more details.
+    /// Optional. Mode for parsing entities in the video caption. See formatting options for more details.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub parse_mode: Option<String>,
+    pub parse_mode: Option<ParseMode>,
     /// Optional. List of special entities that appear in the caption, which can be specified instead of parse_mode
     #[serde(skip_serializing_if = "Option::is_none")]
     pub caption_entities: Option<Vec<MessageEntity>>,
     /// Optional. True, if the caption must be shown above the message media
     #[serde(skip_serializing_if = "Option::is_none")]
     pub show_caption_above_media: Option<Boolean>,
-    /// Optional. Disables automatic server-side content type detection for files uploaded using multipart/form-data. Always True, if the document is sent as part of an album.
+    /// Optional. Video width
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub disable_content_type_detection: Option<Boolean>,
+    pub width: Option<Integer>,
+    /// Optional. Video height
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub height: Option<Integer>,
+    /// Optional. Video duration in seconds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<Integer>,
+    /// Optional. Pass True if the uploaded video is suitable for streaming
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub supports_streaming: Option<Boolean>,
+    /// Optional. Pass True if the video needs to be covered with a spoiler animation
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_spoiler: Option<Boolean>,
 }
@@ -0,0 +1,456 @@
+use crate::{
+    keyboard_button::{InlineKeyboardButton, KeyboardButton},
+    types::{
+        ForceReply, InlineKeyboardMarkup, ReplyKeyboardMarkup, ReplyKeyboardRemove, ReplyMarkup,
+    },
+};
+
+/// Splits items into rows of at most `columns` items, the last row may be shorter
+pub fn grid<T>(items: impl IntoIterator<Item = T>, columns: usize) -> Vec<Vec<T>> {
+    let columns = columns.max(1);
+    let mut rows: Vec<Vec<T>> = Vec::new();
+    for item in items {
+        match rows.last_mut() {
+            Some(row) if row.len() < columns => row.push(item),
+            _ => rows.push(vec![item]),
+        }
+    }
+    rows
+}
+
+/// An inline keyboard button with exactly one action, made by the functions of [`btn`].
+#[derive(Clone, Debug)]
+pub struct InlineButton(InlineKeyboardButton);
+
+impl From<InlineButton> for InlineKeyboardButton {
+    fn from(button: InlineButton) -> Self {
+        button.0
+    }
+}
+
+/// Inline keyboard buttons. Each function sets the one action of the button.
+pub mod btn {
+    use super::InlineButton;
+    use crate::{
+        games::CallbackGame,
+        keyboard_button::InlineKeyboardButton,
+        types::{LoginUrl, SwitchInlineQueryChosenChat, WebAppInfo},
+    };
+
+    fn button(text: &str, set: impl FnOnce(&mut InlineKeyboardButton)) -> InlineButton {
+        let mut button = InlineKeyboardButton {
+            text: text.to_string(),
+            url: None,
+            callback_data: None,
+            web_app: None,
+            login_url: None,
+            switch_inline_query: None,
+            switch_inline_query_current_chat: None,
+            switch_inline_query_chosen_chat: None,
+            callback_game: None,
+            pay: None,
+        };
+        set(&mut button);
+        InlineButton(button)
+    }
+
+    /// Opens an HTTP or tg:// url
+    pub fn url(text: &str, url: &str) -> InlineButton {
+        button(text, |b| b.url = Some(url.to_string()))
+    }
+
+    /// Sends a callback query with the data, 1-64 bytes
+    pub fn callback(text: &str, data: &str) -> InlineButton {
+        button(text, |b| b.callback_data = Some(data.to_string()))
+    }
+
+    /// Launches a Web App, in private chats only
+    pub fn web_app(text: &str, url: &str) -> InlineButton {
+        button(text, |b| {
+            b.web_app = Some(WebAppInfo {
+                url: url.to_string(),
+            })
+        })
+    }
+
+    /// Authorizes the user with a login URL
+    pub fn login_url(text: &str, login_url: LoginUrl) -> InlineButton {
+        button(text, |b| b.login_url = Some(login_url))
+    }
+
+    /// Inserts the bot's username and the query in the input field of a chat chosen by the user
+    pub fn switch_inline_query(text: &str, query: &str) -> InlineButton {
+        button(text, |b| b.switch_inline_query = Some(query.to_string()))
+    }
+
+    /// Inserts the bot's username and the query in the input field of the current chat
+    pub fn switch_inline_query_current_chat(text: &str, query: &str) -> InlineButton {
+        button(text, |b| {
+            b.switch_inline_query_current_chat = Some(query.to_string())
+        })
+    }
+
+    /// Inserts the bot's username and a query in the input field of a chat of the given types chosen by the user
+    pub fn switch_inline_query_chosen_chat(
+        text: &str,
+        chosen_chat: SwitchInlineQueryChosenChat,
+    ) -> InlineButton {
+        button(text, |b| {
+            b.switch_inline_query_chosen_chat = Some(chosen_chat)
+        })
+    }
+
+    /// Launches the game of the message, must be the first button in the first row
+    pub fn game(text: &str) -> InlineButton {
+        button(text, |b| b.callback_game = Some(CallbackGame))
+    }
+
+    /// A Pay button, must be the first button in the first row of an invoice message
+    pub fn pay(text: &str) -> InlineButton {
+        button(text, |b| b.pay = Some(true))
+    }
+}
+
+/// Builds an `InlineKeyboardMarkup` row by row.
+#[derive(Clone, Debug, Default)]
+pub struct InlineKeyboard {
+    rows: Vec<Vec<InlineKeyboardButton>>,
+}
+
+impl InlineKeyboard {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn row(&mut self, buttons: impl IntoIterator<Item = InlineButton>) -> &mut Self {
+        self.rows
+            .push(buttons.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Adds a button to the last row, starting a row if there is none
+    pub fn button(&mut self, button: InlineButton) -> &mut Self {
+        match self.rows.last_mut() {
+            Some(row) => row.push(button.into()),
+            None => self.rows.push(vec![button.into()]),
+        }
+        self
+    }
+
+    /// Adds the buttons in rows of at most `columns` buttons
+    pub fn grid(
+        &mut self,
+        buttons: impl IntoIterator<Item = InlineButton>,
+        columns: usize,
+    ) -> &mut Self {
+        for row in grid(buttons, columns) {
+            self.row(row);
+        }
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rows.iter().all(Vec::is_empty)
+    }
+
+    pub fn build(&self) -> InlineKeyboardMarkup {
+        InlineKeyboardMarkup {
+            inline_keyboard: self.rows.clone(),
+        }
+    }
+}
+
+/// A reply keyboard button, made by the functions of [`key`] or from its text.
+#[derive(Clone, Debug)]
+pub struct ReplyButton(KeyboardButton);
+
+impl From<ReplyButton> for KeyboardButton {
+    fn from(button: ReplyButton) -> Self {
+        button.0
+    }
+}
+
+impl From<&str> for ReplyButton {
+    fn from(text: &str) -> Self {
+        key::text(text)
+    }
+}
+
+impl From<String> for ReplyButton {
+    fn from(text: String) -> Self {
+        key::text(&text)
+    }
+}
+
+/// Reply keyboard buttons. Each function sets at most one request of the button.
+pub mod key {
+    use super::ReplyButton;
+    use crate::{
+        keyboard_button::{
+            KeyboardButton, KeyboardButtonPollType, KeyboardButtonRequestChat,
+            KeyboardButtonRequestUsers,
+        },
+        types::WebAppInfo,
+    };
+
+    fn button(text: &str, set: impl FnOnce(&mut KeyboardButton)) -> ReplyButton {
+        let mut button = KeyboardButton {
+            text: text.to_string(),
+            request_users: None,
+            request_chat: None,
+            request_contact: None,
+            request_location: None,
+            request_poll: None,
+            web_app: None,
+        };
+        set(&mut button);
+        ReplyButton(button)
+    }
+
+    /// Sends its text as a message
+    pub fn text(text: &str) -> ReplyButton {
+        button(text, |_| {})
+    }
+
+    /// Opens a list of users to share with the bot, in private chats only
+    pub fn request_users(text: &str, request: KeyboardButtonRequestUsers) -> ReplyButton {
+        button(text, |b| b.request_users = Some(request))
+    }
+
+    /// Opens a list of chats to share with the bot, in private chats only
+    pub fn request_chat(text: &str, request: KeyboardButtonRequestChat) -> ReplyButton {
+        button(text, |b| b.request_chat = Some(request))
+    }
+
+    /// Sends the user's phone number, in private chats only
+    pub fn request_contact(text: &str) -> ReplyButton {
+        button(text, |b| b.request_contact = Some(true))
+    }
+
+    /// Sends the user's current location, in private chats only
+    pub fn request_location(text: &str) -> ReplyButton {
+        button(text, |b| b.request_location = Some(true))
+    }
+
+    /// Asks the user to create a poll and send it to the bot, in private chats only
+    pub fn request_poll(text: &str, poll_type: KeyboardButtonPollType) -> ReplyButton {
+        button(text, |b| b.request_poll = Some(poll_type))
+    }
+
+    /// Launches a Web App, in private chats only
+    pub fn web_app(text: &str, url: &str) -> ReplyButton {
+        button(text, |b| {
+            b.web_app = Some(WebAppInfo {
+                url: url.to_string(),
+            })
+        })
+    }
+}
+
+/// Builds a `ReplyKeyboardMarkup` row by row.
+#[derive(Clone, Debug)]
+pub struct ReplyKeyboard {
+    markup: ReplyKeyboardMarkup,
+}
+
+impl Default for ReplyKeyboard {
+    fn default() -> Self {
+        ReplyKeyboard {
+            markup: ReplyKeyboardMarkup {
+                keyboard: Vec::new(),
+                is_persistent: None,
+                resize_keyboard: None,
+                one_time_keyboard: None,
+                input_field_placeholder: None,
+                selective: None,
+            },
+        }
+    }
+}
+
+impl ReplyKeyboard {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn row<B: Into<ReplyButton>>(&mut self, buttons: impl IntoIterator<Item = B>) -> &mut Self {
+        let row = buttons
+            .into_iter()
+            .map(|button| button.into().into())
+            .collect();
+        self.markup.keyboard.push(row);
+        self
+    }
+
+    /// Adds a button to the last row, starting a row if there is none
+    pub fn button(&mut self, button: impl Into<ReplyButton>) -> &mut Self {
+        let button = button.into().into();
+        match self.markup.keyboard.last_mut() {
+            Some(row) => row.push(button),
+            None => self.markup.keyboard.push(vec![button]),
+        }
+        self
+    }
+
+    /// Adds the buttons in rows of at most `columns` buttons
+    pub fn grid<B: Into<ReplyButton>>(
+        &mut self,
+        buttons: impl IntoIterator<Item = B>,
+        columns: usize,
+    ) -> &mut Self {
+        for row in grid(buttons, columns) {
+            self.row(row);
+        }
+        self
+    }
+
+    /// Always shows the keyboard when the regular keyboard is hidden
+    pub fn persistent(&mut self) -> &mut Self {
+        self.markup.is_persistent = Some(true);
+        self
+    }
+
+    /// Fits the keyboard height to its rows
+    pub fn resize(&mut self) -> &mut Self {
+        self.markup.resize_keyboard = Some(true);
+        self
+    }
+
+    /// Hides the keyboard as soon as it's been used
+    pub fn one_time(&mut self) -> &mut Self {
+        self.markup.one_time_keyboard = Some(true);
+        self
+    }
+
+    /// The placeholder shown in the input field while the keyboard is active; 1-64 characters
+    pub fn placeholder(&mut self, placeholder: &str) -> &mut Self {
+        self.markup.input_field_placeholder = Some(placeholder.to_string());
+        self
+    }
+
+    /// Shows the keyboard only to the users mentioned in the message and the sender of the replied message
+    pub fn selective(&mut self) -> &mut Self {
+        self.markup.selective = Some(true);
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.markup.keyboard.iter().all(Vec::is_empty)
+    }
+
+    pub fn build(&self) -> ReplyKeyboardMarkup {
+        self.markup.clone()
+    }
+}
+
+impl From<&mut InlineKeyboard> for ReplyMarkup {
+    fn from(keyboard: &mut InlineKeyboard) -> Self {
+        keyboard.build().into()
+    }
+}
+
+impl From<&mut ReplyKeyboard> for ReplyMarkup {
+    fn from(keyboard: &mut ReplyKeyboard) -> Self {
+        keyboard.build().into()
+    }
+}
+
+impl From<&mut InlineKeyboard> for InlineKeyboardMarkup {
+    fn from(keyboard: &mut InlineKeyboard) -> Self {
+        keyboard.build()
+    }
+}
+
+impl ReplyMarkup {
+    /// Removes the current custom keyboard
+    pub fn remove_keyboard() -> Self {
+        ReplyKeyboardRemove {
+            remove_keyboard: true,
+            selective: None,
+        }
+        .into()
+    }
+
+    /// Shows the reply interface, as if the user selected the bot's message and tapped 'Reply'
+    pub fn force_reply() -> Self {
+        ForceReply {
+            force_reply: true,
+            input_field_placeholder: None,
+            selective: None,
+        }
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn inline_keyboard_rows_and_grid() {
+        let markup = InlineKeyboard::new()
+            .row([
+                btn::callback("Yes", "y"),
+                btn::url("Docs", "https://core.telegram.org/bots/api"),
+            ])
+            .grid(
+                (1..=5).map(|i| btn::callback(&i.to_string(), &i.to_string())),
+                2,
+            )
+            .build();
+        assert_eq!(
+            serde_json::to_value(ReplyMarkup::from(markup)).unwrap(),
+            json!({"inline_keyboard": [
+                [
+                    {"text": "Yes", "callback_data": "y"},
+                    {"text": "Docs", "url": "https://core.telegram.org/bots/api"}
+                ],
+                [{"text": "1", "callback_data": "1"}, {"text": "2", "callback_data": "2"}],
+                [{"text": "3", "callback_data": "3"}, {"text": "4", "callback_data": "4"}],
+                [{"text": "5", "callback_data": "5"}]
+            ]})
+        );
+    }
+
+    #[test]
+    fn reply_keyboard_options() {
+        let markup: ReplyMarkup = ReplyKeyboard::new()
+            .row([key::request_contact("Phone"), key::request_location("Here")])
+            .row(["Cancel"])
+            .resize()
+            .one_time()
+            .into();
+        assert_eq!(
+            serde_json::to_value(&markup).unwrap(),
+            json!({
+                "keyboard": [
+                    [
+                        {"text": "Phone", "request_contact": true},
+                        {"text": "Here", "request_location": true}
+                    ],
+                    [{"text": "Cancel"}]
+                ],
+                "resize_keyboard": true,
+                "one_time_keyboard": true
+            })
+        );
+    }
+
+    #[test]
+    fn remove_keyboard_and_force_reply() {
+        let markup: ReplyMarkup = serde_json::from_value(json!({"remove_keyboard": true})).unwrap();
+        assert!(matches!(markup, ReplyMarkup::ReplyKeyboardRemove(_)));
+        assert!(matches!(
+            ReplyMarkup::force_reply(),
+            ReplyMarkup::ForceReply(_)
+        ));
+    }
+
+    #[test]
+    fn grid_rows() {
+        assert_eq!(grid(1..=7, 3), [vec![1, 2, 3], vec![4, 5, 6], vec![7]]);
+        assert!(grid(0..0, 3).is_empty());
+    }
+}
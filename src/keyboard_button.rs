@@ -2,6 +2,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::{
     games::CallbackGame,
+    poll::PollType,
     types::{
         Boolean, ChatAdministratorRights, Integer, LoginUrl, SwitchInlineQueryChosenChat,
         WebAppInfo,
@@ -97,8 +98,9 @@ pub struct KeyboardButtonRequestChat {
 /// This object represents type of a poll, which is allowed to be created and sent when the corresponding button is pressed.
 #[derive(Clone, Serialize, Deserialize, Debug)]
 pub struct KeyboardButtonPollType {
-    #[serde(rename = "type")]
-    pub kind: Option<String>,
+    /// Optional. If quiz is passed, the user will be allowed to create only polls in the quiz mode. If regular is passed, only regular polls will be allowed. Otherwise, the user will be allowed to create a poll of any type.
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub kind: Option<PollType>,
 }
 
 /// This object represents one button of an inline keyboard. You must use exactly one of the optional fields.
@@ -138,3 +140,21 @@ pub struct InlineKeyboardButton {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub pay: Option<Boolean>,
 }
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn poll_type_button() {
+        assert_eq!(
+            serde_json::to_value(KeyboardButtonPollType {
+                kind: Some(PollType::Quiz)
+            })
+            .unwrap(),
+            json!({"type": "quiz"})
+        );
+    }
+}
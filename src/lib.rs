@@ -5,23 +5,50 @@
 //#![allow(empty_line_after_outer_attr)]
 //#![allow(unused_attributes)]
 
+#[macro_use]
+mod macros;
+
+// The derives name the crate as `::rutel`, this lets them be used in its own tests
+#[cfg(test)]
+extern crate self as rutel;
+
 pub mod background;
 pub mod bot;
 pub mod bot_command;
+pub mod broadcast;
+pub mod bulk;
 pub mod business;
+pub mod callback_data;
 pub mod chat_boost;
+pub mod content;
+pub mod date;
+pub mod dialogue;
+pub mod dispatcher;
+pub mod entities;
 pub mod error;
 pub mod files;
+pub mod formatting;
 pub mod forum_topic;
 pub mod games;
 pub mod giveaway;
+pub mod ids;
 pub mod inline_mode;
 pub mod input_media;
+pub mod keyboard;
 pub mod keyboard_button;
+pub mod media_group;
 pub mod message;
+pub mod paginator;
 pub mod passport;
 pub mod payments;
 pub mod poll;
 pub mod reactions;
+pub mod shortcuts;
+pub mod split;
 pub mod stickers;
+#[cfg(test)]
+mod test_util;
+pub mod token;
 pub mod types;
+pub mod update;
+pub mod validate;
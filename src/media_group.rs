@@ -0,0 +1,185 @@
+use std::{
+    collections::HashMap,
+    time::{Duration, Instant},
+};
+
+use crate::{ids::ChatId, message::Message};
+
+/// Most messages an album has
+pub const MAX_ALBUM_SIZE: usize = 10;
+
+/// The messages of a media group, sent by the user as one album.
+#[derive(Clone, Debug)]
+pub struct Album {
+    pub chat_id: ChatId,
+    pub media_group_id: String,
+    /// The parts of the album in the order they were sent
+    pub messages: Vec<Message>,
+}
+
+impl Album {
+    /// The caption of the album, Telegram clients put it on one of the parts
+    pub fn caption(&self) -> Option<&str> {
+        self.messages
+            .iter()
+            .find_map(|message| message.caption.as_deref())
+    }
+}
+
+struct Pending {
+    messages: Vec<Message>,
+    last_part: Instant,
+}
+
+/// Collects the messages of media groups into albums.
+///
+/// The parts of an album arrive as separate messages, possibly in several batches of updates. An album is ready
+/// when no part arrived for the debounce time, or as soon as it has `MAX_ALBUM_SIZE` parts.
+pub struct MediaGroups {
+    debounce: Duration,
+    pending: HashMap<(ChatId, String), Pending>,
+}
+
+impl MediaGroups {
+    pub fn new(debounce: Duration) -> Self {
+        MediaGroups {
+            debounce,
+            pending: HashMap::new(),
+        }
+    }
+
+    pub fn debounce(&self) -> Duration {
+        self.debounce
+    }
+
+    /// Buffers a message received at `now` if it is part of a media group, other messages are returned
+    pub fn push(&mut self, message: Message, now: Instant) -> Option<Message> {
+        let Some(media_group_id) = message.media_group_id.clone() else {
+            return Some(message);
+        };
+        let pending = self
+            .pending
+            .entry((message.chat.id, media_group_id))
+            .or_insert_with(|| Pending {
+                messages: Vec::new(),
+                last_part: now,
+            });
+        pending.last_part = pending.last_part.max(now);
+        pending.messages.push(message);
+        None
+    }
+
+    /// Takes the albums that are ready at `now`
+    pub fn ready(&mut self, now: Instant) -> Vec<Album> {
+        let debounce = self.debounce;
+        self.take(|pending| {
+            pending.messages.len() >= MAX_ALBUM_SIZE
+                || now.saturating_duration_since(pending.last_part) >= debounce
+        })
+    }
+
+    /// Takes every album, ready or not
+    pub fn flush(&mut self) -> Vec<Album> {
+        self.take(|_| true)
+    }
+
+    /// When the next album will be ready if no more parts arrive
+    pub fn next_deadline(&self) -> Option<Instant> {
+        self.pending
+            .values()
+            .map(|pending| pending.last_part + self.debounce)
+            .min()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pending.is_empty()
+    }
+
+    fn take(&mut self, ready: impl Fn(&Pending) -> bool) -> Vec<Album> {
+        let keys: Vec<(ChatId, String)> = self
+            .pending
+            .iter()
+            .filter(|(_, pending)| ready(pending))
+            .map(|(key, _)| key.clone())
+            .collect();
+        let mut albums: Vec<Album> = keys
+            .into_iter()
+            .filter_map(|key| {
+                let pending = self.pending.remove(&key)?;
+                let (chat_id, media_group_id) = key;
+                let mut messages = pending.messages;
+                messages.sort_by_key(|message| message.message_id);
+                messages.dedup_by_key(|message| message.message_id);
+                Some(Album {
+                    chat_id,
+                    media_group_id,
+                    messages,
+                })
+            })
+            .collect();
+        albums.sort_by_key(|album| album.messages[0].message_id);
+        albums
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        test_util::{album_part, message_update},
+        types::Integer,
+    };
+
+    fn part(id: Integer, chat: Integer, group: &str) -> Message {
+        album_part(id, chat, group, None).message.unwrap()
+    }
+
+    fn ids(album: &Album) -> Vec<Integer> {
+        album.messages.iter().map(|m| m.message_id.0).collect()
+    }
+
+    #[test]
+    fn other_messages_pass_through() {
+        let mut groups = MediaGroups::new(Duration::from_millis(500));
+        let message = message_update(1, 10, "hi").message.unwrap();
+        assert!(groups.push(message, Instant::now()).is_some());
+        assert!(groups.is_empty());
+    }
+
+    #[test]
+    fn albums_are_ready_after_the_debounce() {
+        let start = Instant::now();
+        let at = |ms| start + Duration::from_millis(ms);
+        let mut groups = MediaGroups::new(Duration::from_millis(500));
+        assert!(groups.push(part(3, 10, "g"), at(0)).is_none());
+        assert!(groups.push(part(2, 10, "g"), at(100)).is_none());
+        assert!(groups.push(part(2, 20, "g"), at(200)).is_none());
+        assert!(groups.push(part(4, 10, "g"), at(300)).is_none());
+        assert_eq!(groups.next_deadline(), Some(at(700)));
+        assert!(groups.ready(at(699)).is_empty());
+        let mut albums = groups.ready(at(700));
+        assert_eq!(albums.len(), 1);
+        assert!(groups.ready(at(799)).is_empty());
+        albums.splice(0..0, groups.ready(at(800)));
+        assert_eq!(
+            albums
+                .iter()
+                .map(|album| (album.chat_id, ids(album)))
+                .collect::<Vec<_>>(),
+            vec![(ChatId(10), vec![2, 3, 4]), (ChatId(20), vec![2])]
+        );
+        assert!(groups.is_empty());
+    }
+
+    #[test]
+    fn full_album_is_ready_at_once() {
+        let now = Instant::now();
+        let mut groups = MediaGroups::new(Duration::from_millis(500));
+        for id in 1..=MAX_ALBUM_SIZE as Integer {
+            groups.push(part(id, 10, "full"), now);
+        }
+        groups.push(part(1, 10, "partial"), now);
+        assert_eq!(groups.ready(now)[0].messages.len(), MAX_ALBUM_SIZE);
+        assert_eq!(groups.flush()[0].media_group_id, "partial");
+    }
+}
@@ -1,13 +1,18 @@
-use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use serde::{de::Error as _, Deserialize, Deserializer, Serialize};
+use serde_json::{Map, Value};
 
 use crate::{
     files::{Animation, Audio, Document, PhotoSize, Video, VideoNote, Voice},
+    formatting::ParseMode,
     forum_topic::{
         ForumTopicClosed, ForumTopicCreated, ForumTopicEdited, ForumTopicReopened,
         GeneralForumTopicHidden, GeneralForumTopicUnhidden,
     },
     games::Game,
     giveaway::{Giveaway, GiveawayCompleted, GiveawayCreated, GiveawayWinners},
+    ids::{ChatId, MessageId},
     passport::PassportData,
     payments::{Invoice, PaidMediaInfo, RefundedPayment, SuccessfulPayment},
     poll::Poll,
@@ -25,7 +30,7 @@ use crate::{
 #[derive(Clone, Serialize, Deserialize, Debug)]
 pub struct Message {
     /// Unique message identifier inside this chat
-    pub message_id: Integer,
+    pub message_id: MessageId,
     /// Optional. Unique identifier of a message thread to which the message belongs; for supergroups only
     #[serde(skip_serializing_if = "Option::is_none")]
     pub message_thread_id: Option<Integer>,
@@ -188,10 +193,10 @@ pub struct Message {
     pub message_auto_delete_timer_changed: Option<MessageAutoDeleteTimerChanged>,
     /// Optional. The group has been migrated to a supergroup with the specified identifier. This number may be greater than 32 bits and some programming languages may have difficulty/silent defects in interpreting it. But it is smaller than 52 bits, so a signed 64 bit integer or double-precision float type are safe for storing this identifier.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub migrate_to_chat_id: Option<Integer>,
+    pub migrate_to_chat_id: Option<ChatId>,
     /// Optional. The supergroup has been migrated from a group with the specified identifier. This number may be greater than 32 bits and some programming languages may have difficulty/silent defects in interpreting it. But it is smaller than 52 bits, so a signed 64 bit integer or double-precision float type are safe for storing this identifier.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub migrate_from_chat_id: Option<Integer>,
+    pub migrate_from_chat_id: Option<ChatId>,
     /// Optional. Specified message was pinned. Note that the Message object in this field will not contain further reply_to_message fields even if it itself is a reply.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub pinned_message: Option<Box<MaybeInaccessibleMessage>>,
@@ -273,13 +278,9 @@ pub struct Message {
     /// Optional. Inline keyboard attached to the message. login_url buttons are represented as ordinary url buttons.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_markup: Option<InlineKeyboardMarkup>,
-}
-
-/// This object represents a unique message identifier.
-#[derive(Clone, Serialize, Deserialize, Debug)]
-pub struct MessageId {
-    /// Unique message identifier
-    pub message_id: Integer,
+    /// Fields not known to this version of the library
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
 }
 
 /// This object describes a message that was deleted or is otherwise inaccessible to the bot.
@@ -288,40 +289,248 @@ pub struct InaccessibleMessage {
     /// Chat the message belonged to
     pub chat: Chat,
     /// Unique message identifier inside the chat
-    pub message_id: Integer,
+    pub message_id: MessageId,
     /// Always 0. The field can be used to differentiate regular and inaccessible messages.
     pub date: Integer,
 }
 
 /// This object describes a message that can be inaccessible to the bot. It can be one of
-#[derive(Clone, Serialize, Deserialize, Debug)]
+///
+/// Both are sent as plain objects; an inaccessible message is told apart by its `date` of 0.
+#[derive(Clone, Serialize, Debug)]
+#[serde(untagged)]
 pub enum MaybeInaccessibleMessage {
     Message(Box<Message>),
     InaccessibleMessage(InaccessibleMessage),
 }
 
+impl<'de> Deserialize<'de> for MaybeInaccessibleMessage {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = Value::deserialize(deserializer)?;
+        if value.get("date").and_then(Value::as_i64) == Some(0) {
+            serde_json::from_value(value).map(MaybeInaccessibleMessage::InaccessibleMessage)
+        } else {
+            serde_json::from_value(value).map(MaybeInaccessibleMessage::Message)
+        }
+        .map_err(D::Error::custom)
+    }
+}
+
+impl MaybeInaccessibleMessage {
+    /// The message, if it is accessible
+    pub fn message(&self) -> Option<&Message> {
+        match self {
+            MaybeInaccessibleMessage::Message(message) => Some(message),
+            MaybeInaccessibleMessage::InaccessibleMessage(_) => None,
+        }
+    }
+
+    pub fn chat(&self) -> &Chat {
+        match self {
+            MaybeInaccessibleMessage::Message(message) => &message.chat,
+            MaybeInaccessibleMessage::InaccessibleMessage(message) => &message.chat,
+        }
+    }
+
+    pub fn message_id(&self) -> MessageId {
+        match self {
+            MaybeInaccessibleMessage::Message(message) => message.message_id,
+            MaybeInaccessibleMessage::InaccessibleMessage(message) => message.message_id,
+        }
+    }
+}
+
 /// This object represents one special entity in a text message. For example, hashtags, usernames, URLs, etc.
-#[derive(Clone, Serialize, Deserialize, Debug)]
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
+#[serde(try_from = "RawMessageEntity", into = "RawMessageEntity")]
 pub struct MessageEntity {
-    /// Type of the entity. Currently, can be “mention” (`@username`), “hashtag” (`#hashtag`), “cashtag” (`$USD`), “bot_command” (`/start@jobs_bot`), “url” (`https://telegram.org`), “email” (`do-not-reply@telegram.org`), “phone_number” (`+1-212-555-0123`), “bold” (**bold text**), “italic” (*italic text*), “underline” (<ins>underlined text</ins>), “strikethrough” (~~strikethrough text~~), “spoiler” (`spoiler message`), “code” (`monowidth string`), “pre” (`monowidth block`), “text_link” (`for clickable text URLs`), “text_mention” (`for users without usernames`)
-    #[serde(rename = "type")]
-    pub kind: String,
+    /// Type of the entity, with the data specific to it
+    pub kind: MessageEntityKind,
     /// Offset in UTF-16 code units to the start of the entity
     pub offset: Integer,
     /// Length of the entity in UTF-16 code units
     pub length: Integer,
-    /// Optional. For “text_link” only, url that will be opened after user taps on the text
+}
+
+impl MessageEntity {
+    pub fn new(kind: MessageEntityKind, offset: Integer, length: Integer) -> Self {
+        MessageEntity {
+            kind,
+            offset,
+            length,
+        }
+    }
+}
+
+/// Type of a message entity.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MessageEntityKind {
+    /// `@username`
+    Mention,
+    /// `#hashtag` or `#hashtag@chatusername`
+    Hashtag,
+    /// `$USD` or `$USD@chatusername`
+    Cashtag,
+    /// `/start@jobs_bot`
+    BotCommand,
+    /// `https://telegram.org`
+    Url,
+    /// `do-not-reply@telegram.org`
+    Email,
+    /// `+1-212-555-0123`
+    PhoneNumber,
+    Bold,
+    Italic,
+    Underline,
+    Strikethrough,
+    Spoiler,
+    Blockquote,
+    /// Collapsed-by-default block quotation
+    ExpandableBlockquote,
+    /// Monowidth string
+    Code,
+    /// Monowidth block
+    Pre {
+        /// The programming language of the entity text
+        language: Option<String>,
+    },
+    /// Clickable text URL
+    TextLink {
+        /// Url that will be opened after user taps on the text
+        url: String,
+    },
+    /// Mention of a user without a username
+    TextMention {
+        /// The mentioned user
+        user: User,
+    },
+    /// Inline custom emoji sticker
+    CustomEmoji {
+        /// Unique identifier of the custom emoji. Use getCustomEmojiStickers to get full information about the sticker
+        custom_emoji_id: String,
+    },
+    /// An entity type not known to this version of the library
+    Unknown(String),
+}
+
+impl MessageEntityKind {
+    /// Name of the type, as in the `type` field of the Bot API
+    pub fn name(&self) -> &str {
+        match self {
+            MessageEntityKind::Mention => "mention",
+            MessageEntityKind::Hashtag => "hashtag",
+            MessageEntityKind::Cashtag => "cashtag",
+            MessageEntityKind::BotCommand => "bot_command",
+            MessageEntityKind::Url => "url",
+            MessageEntityKind::Email => "email",
+            MessageEntityKind::PhoneNumber => "phone_number",
+            MessageEntityKind::Bold => "bold",
+            MessageEntityKind::Italic => "italic",
+            MessageEntityKind::Underline => "underline",
+            MessageEntityKind::Strikethrough => "strikethrough",
+            MessageEntityKind::Spoiler => "spoiler",
+            MessageEntityKind::Blockquote => "blockquote",
+            MessageEntityKind::ExpandableBlockquote => "expandable_blockquote",
+            MessageEntityKind::Code => "code",
+            MessageEntityKind::Pre { .. } => "pre",
+            MessageEntityKind::TextLink { .. } => "text_link",
+            MessageEntityKind::TextMention { .. } => "text_mention",
+            MessageEntityKind::CustomEmoji { .. } => "custom_emoji",
+            MessageEntityKind::Unknown(name) => name,
+        }
+    }
+}
+
+impl fmt::Display for MessageEntityKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// MessageEntity as sent by the Bot API, with the kind-specific fields side by side
+#[derive(Serialize, Deserialize)]
+struct RawMessageEntity {
+    #[serde(rename = "type")]
+    kind: String,
+    offset: Integer,
+    length: Integer,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub url: Option<String>,
-    /// Optional. For “text_mention” only, the mentioned user
+    url: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub user: Option<User>,
-    /// Optional. For “pre” only, the programming language of the entity text
+    user: Option<User>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub language: Option<String>,
-    /// Optional. For “custom_emoji” only, unique identifier of the custom emoji. Use getCustomEmojiStickers to get full information about the sticker
+    language: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub custom_emoji_id: Option<String>,
+    custom_emoji_id: Option<String>,
+}
+
+impl TryFrom<RawMessageEntity> for MessageEntity {
+    type Error = String;
+
+    fn try_from(raw: RawMessageEntity) -> Result<Self, Self::Error> {
+        let missing = |field: &str| format!("{} entity without {}", raw.kind, field);
+        let kind = match raw.kind.as_str() {
+            "mention" => MessageEntityKind::Mention,
+            "hashtag" => MessageEntityKind::Hashtag,
+            "cashtag" => MessageEntityKind::Cashtag,
+            "bot_command" => MessageEntityKind::BotCommand,
+            "url" => MessageEntityKind::Url,
+            "email" => MessageEntityKind::Email,
+            "phone_number" => MessageEntityKind::PhoneNumber,
+            "bold" => MessageEntityKind::Bold,
+            "italic" => MessageEntityKind::Italic,
+            "underline" => MessageEntityKind::Underline,
+            "strikethrough" => MessageEntityKind::Strikethrough,
+            "spoiler" => MessageEntityKind::Spoiler,
+            "blockquote" => MessageEntityKind::Blockquote,
+            "expandable_blockquote" => MessageEntityKind::ExpandableBlockquote,
+            "code" => MessageEntityKind::Code,
+            "pre" => MessageEntityKind::Pre {
+                language: raw.language,
+            },
+            "text_link" => MessageEntityKind::TextLink {
+                url: raw.url.ok_or_else(|| missing("url"))?,
+            },
+            "text_mention" => MessageEntityKind::TextMention {
+                user: raw.user.ok_or_else(|| missing("user"))?,
+            },
+            "custom_emoji" => MessageEntityKind::CustomEmoji {
+                custom_emoji_id: raw
+                    .custom_emoji_id
+                    .ok_or_else(|| missing("custom_emoji_id"))?,
+            },
+            _ => MessageEntityKind::Unknown(raw.kind),
+        };
+        Ok(MessageEntity {
+            kind,
+            offset: raw.offset,
+            length: raw.length,
+        })
+    }
+}
+
+impl From<MessageEntity> for RawMessageEntity {
+    fn from(entity: MessageEntity) -> Self {
+        let mut raw = RawMessageEntity {
+            kind: entity.kind.name().to_string(),
+            offset: entity.offset,
+            length: entity.length,
+            url: None,
+            user: None,
+            language: None,
+            custom_emoji_id: None,
+        };
+        match entity.kind {
+            MessageEntityKind::Pre { language } => raw.language = language,
+            MessageEntityKind::TextLink { url } => raw.url = Some(url),
+            MessageEntityKind::TextMention { user } => raw.user = Some(user),
+            MessageEntityKind::CustomEmoji { custom_emoji_id } => {
+                raw.custom_emoji_id = Some(custom_emoji_id)
+            }
+            _ => (),
+        }
+        raw
+    }
 }
 
 /// This object contains information about the quoted part of a message that is replied to by the given message.
@@ -349,7 +558,7 @@ pub struct ExternalReplyInfo {
     pub chat: Option<Chat>,
     /// Optional. Unique message identifier inside the original chat. Available only if the original chat is a supergroup or a channel.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub message_id: Option<Integer>,
+    pub message_id: Option<MessageId>,
     /// Optional. Options used for link preview generation for the original message, if it is a text message
     #[serde(skip_serializing_if = "Option::is_none")]
     pub link_preview_options: Option<LinkPreviewOptions>,
@@ -419,7 +628,7 @@ pub struct ExternalReplyInfo {
 #[derive(Clone, Serialize, Deserialize, Debug)]
 pub struct ReplyParameters {
     /// Identifier of the message that will be replied to in the current chat, or in the chat chat_id if it is specified
-    pub message_id: Integer,
+    pub message_id: MessageId,
     /// Optional. If the message to be replied to is from a different chat, unique identifier for the chat or username of the channel (in the format @channelusername)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub chat_id: Option<ChatID>,
@@ -431,7 +640,7 @@ pub struct ReplyParameters {
     pub quote: Option<String>,
     /// Optional. Mode for parsing entities in the quote. See formatting options for more details.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub quote_parse_mode: Option<String>,
+    pub quote_parse_mode: Option<ParseMode>,
     /// Optional. A JSON-serialized list of special entities that appear in the quote. It can be specified instead of quote_parse_mode.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub quote_entities: Option<Vec<MessageEntity>>,
@@ -440,21 +649,83 @@ pub struct ReplyParameters {
     pub quote_position: Option<Integer>,
 }
 
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn unknown_entity_kind_round_trip() {
+        let unknown: MessageEntity =
+            serde_json::from_value(json!({"type": "date_time", "offset": 0, "length": 1})).unwrap();
+        assert_eq!(
+            unknown.kind,
+            MessageEntityKind::Unknown("date_time".to_string())
+        );
+        assert_eq!(
+            serde_json::to_value(&unknown).unwrap(),
+            json!({"type": "date_time", "offset": 0, "length": 1})
+        );
+    }
+
+    #[test]
+    fn entity_kind_fields_are_flattened() {
+        let link = MessageEntity::new(
+            MessageEntityKind::TextLink {
+                url: "https://t.me".to_string(),
+            },
+            0,
+            2,
+        );
+        assert_eq!(
+            serde_json::to_value(&link).unwrap(),
+            json!({"type": "text_link", "offset": 0, "length": 2, "url": "https://t.me"})
+        );
+    }
+
+    #[test]
+    fn entity_kind_requires_its_fields() {
+        assert!(serde_json::from_value::<MessageEntity>(
+            json!({"type": "text_link", "offset": 0, "length": 1})
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn message_origin_tagged_by_type() {
+        let origin: MessageOrigin = serde_json::from_value(
+            json!({"type": "hidden_user", "date": 1, "sender_user_name": "B"}),
+        )
+        .unwrap();
+        assert!(
+            matches!(origin, MessageOrigin::MessageOriginHiddenUser(o) if o.sender_user_name == "B")
+        );
+    }
+}
+
+// The types below are generated from spec/bot_api.json by `cargo run -p rutel_codegen`, edit the spec instead.
+
 /// This object describes the origin of a message. It can be one of
 #[derive(Clone, Serialize, Deserialize, Debug)]
+#[serde(tag = "type")]
 pub enum MessageOrigin {
+    #[serde(rename = "user")]
     MessageOriginUser(MessageOriginUser),
+    #[serde(rename = "hidden_user")]
     MessageOriginHiddenUser(MessageOriginHiddenUser),
+    #[serde(rename = "chat")]
     MessageOriginChat(MessageOriginChat),
+    #[serde(rename = "channel")]
     MessageOriginChannel(MessageOriginChannel),
+    /// An object not known to this version of the library, or one that can't be decoded
+    #[serde(untagged)]
+    Unknown(Value),
 }
 
 /// The message was originally sent by a known user.
 #[derive(Clone, Serialize, Deserialize, Debug)]
 pub struct MessageOriginUser {
-    /// Type of the message origin, always “user”
-    #[serde(rename = "type")]
-    pub kind: String,
     /// Date the message was sent originally in Unix time
     pub date: Integer,
     /// User that sent the message originally
@@ -464,9 +735,6 @@ pub struct MessageOriginUser {
 /// The message was originally sent by an unknown user.
 #[derive(Clone, Serialize, Deserialize, Debug)]
 pub struct MessageOriginHiddenUser {
-    /// Type of the message origin, always “hidden_user”
-    #[serde(rename = "type")]
-    pub kind: String,
     /// Date the message was sent originally in Unix time
     pub date: Integer,
     /// Name of the user that sent the message originally
@@ -476,9 +744,6 @@ pub struct MessageOriginHiddenUser {
 /// The message was originally sent on behalf of a chat to a group chat.
 #[derive(Clone, Serialize, Deserialize, Debug)]
 pub struct MessageOriginChat {
-    /// Type of the message origin, always “chat”
-    #[serde(rename = "type")]
-    pub kind: String,
     /// Date the message was sent originally in Unix time
     pub date: Integer,
     /// Chat that sent the message originally
@@ -491,15 +756,12 @@ pub struct MessageOriginChat {
 /// The message was originally sent to a channel chat.
 #[derive(Clone, Serialize, Deserialize, Debug)]
 pub struct MessageOriginChannel {
-    /// Type of the message origin, always “channel”
-    #[serde(rename = "type")]
-    pub kind: String,
     /// Date the message was sent originally in Unix time
     pub date: Integer,
     /// Channel chat to which the message was originally sent
     pub chat: Chat,
     /// Unique message identifier inside the chat
-    pub message_id: Integer,
+    pub message_id: MessageId,
     /// Optional. Signature of the original post author
     #[serde(skip_serializing_if = "Option::is_none")]
     pub author_signature: Option<String>,
@@ -0,0 +1,225 @@
+use crate::{
+    bot::Bot,
+    callback_data::split_fields,
+    error::Result,
+    keyboard::{btn, grid, InlineButton},
+    shortcuts::MessageTarget,
+    types::{CallbackQuery, InlineKeyboardMarkup},
+};
+
+/// What a navigation button of a paginator asks for.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Navigation {
+    /// Show the page with this index, counting from 0
+    Page(usize),
+    /// The page counter was pressed, nothing to change
+    Current,
+}
+
+/// Renders a list of items as pages of inline buttons with ‹ › navigation.
+///
+/// The page is kept in the callback data of the navigation buttons as `prefix:page`, so a paginator needs no state of its
+/// own. The prefix tells the paginators of a bot apart, it must not be used by other callback data and must not contain
+/// ':' or '\'.
+#[derive(Clone, Debug)]
+pub struct Paginator {
+    prefix: String,
+    page_size: usize,
+    columns: usize,
+    previous: String,
+    next: String,
+}
+
+impl Paginator {
+    /// A paginator showing `page_size` items per page, one per row
+    pub fn new(prefix: &str, page_size: usize) -> Self {
+        Paginator {
+            prefix: prefix.to_string(),
+            page_size: page_size.max(1),
+            columns: 1,
+            previous: "‹".to_string(),
+            next: "›".to_string(),
+        }
+    }
+
+    /// Number of item buttons in a row
+    pub fn columns(&mut self, columns: usize) -> &mut Self {
+        self.columns = columns.max(1);
+        self
+    }
+
+    /// Labels of the buttons going to the previous and the next page
+    pub fn labels(&mut self, previous: &str, next: &str) -> &mut Self {
+        self.previous = previous.to_string();
+        self.next = next.to_string();
+        self
+    }
+
+    /// Number of pages needed for `len` items, at least 1
+    pub fn pages(&self, len: usize) -> usize {
+        len.div_ceil(self.page_size).max(1)
+    }
+
+    fn data(&self, page: Option<usize>) -> String {
+        match page {
+            Some(page) => format!("{}:{page}", self.prefix),
+            None => format!("{}:", self.prefix),
+        }
+    }
+
+    /// The navigation requested by callback data, None if the data doesn't belong to this paginator
+    pub fn navigation(&self, data: &str) -> Option<Navigation> {
+        let (prefix, fields) = split_fields(data);
+        match fields.as_slice() {
+            [page] if prefix == self.prefix && page.is_empty() => Some(Navigation::Current),
+            [page] if prefix == self.prefix => page.parse().ok().map(Navigation::Page),
+            _ => None,
+        }
+    }
+
+    /// The keyboard of a page: the buttons of its items, then a row with the page counter between the navigation buttons.
+    ///
+    /// Pages past the end show the last page. The navigation row is left out when everything fits one page.
+    pub fn render<T>(
+        &self,
+        items: &[T],
+        page: usize,
+        button: impl Fn(&T) -> InlineButton,
+    ) -> InlineKeyboardMarkup {
+        let pages = self.pages(items.len());
+        let page = page.min(pages - 1);
+        let start = page * self.page_size;
+        let end = (start + self.page_size).min(items.len());
+        let mut rows: Vec<Vec<_>> = grid(items[start..end].iter().map(button), self.columns)
+            .into_iter()
+            .map(|row| row.into_iter().map(Into::into).collect())
+            .collect();
+        if pages > 1 {
+            let mut navigation = Vec::new();
+            if page > 0 {
+                navigation.push(btn::callback(&self.previous, &self.data(Some(page - 1))));
+            }
+            navigation.push(btn::callback(
+                &format!("{}/{}", page + 1, pages),
+                &self.data(None),
+            ));
+            if page + 1 < pages {
+                navigation.push(btn::callback(&self.next, &self.data(Some(page + 1))));
+            }
+            rows.push(navigation.into_iter().map(Into::into).collect());
+        }
+        InlineKeyboardMarkup {
+            inline_keyboard: rows,
+        }
+    }
+
+    /// Handles a callback query from the navigation buttons: answers it and edits the message to show the requested page.
+    ///
+    /// The message text is replaced when `text` is given, otherwise only the keyboard changes. An edit that leaves the
+    /// message as it was is not an error. Returns false, without answering, for queries that don't belong to this
+    /// paginator.
+    pub async fn handle<T>(
+        &self,
+        bot: &mut Bot,
+        query: &CallbackQuery,
+        items: &[T],
+        text: Option<&str>,
+        button: impl Fn(&T) -> InlineButton,
+    ) -> Result<bool> {
+        let Some(navigation) = query.data.as_deref().and_then(|data| self.navigation(data)) else {
+            return Ok(false);
+        };
+        bot.answer(query).ack().await?;
+        let Navigation::Page(page) = navigation else {
+            return Ok(true);
+        };
+        let markup = self.render(items, page, button);
+        let target = MessageTarget::from(query);
+        let edited = match text {
+            Some(text) => {
+                let mut request = target.edit_text(text);
+                request.reply_markup(Some(markup));
+                bot.edit_message_text(&request).await
+            }
+            None => {
+                bot.edit_message_reply_markup(&target.edit_reply_markup(Some(markup)))
+                    .await
+            }
+        };
+        match edited {
+            Err(err) if !err.is_message_not_modified() => Err(err),
+            _ => Ok(true),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value};
+
+    use super::*;
+
+    fn items() -> Vec<u32> {
+        (1..=25).collect()
+    }
+
+    fn paginator() -> Paginator {
+        let mut paginator = Paginator::new("orders", 10);
+        paginator.columns(5);
+        paginator
+    }
+
+    fn render(items: &[u32], page: usize) -> Value {
+        let label = |i: &u32| btn::callback(&i.to_string(), &format!("order:{i}"));
+        serde_json::to_value(paginator().render(items, page, label)).unwrap()["inline_keyboard"]
+            .clone()
+    }
+
+    #[test]
+    fn page_count() {
+        assert_eq!(paginator().pages(items().len()), 3);
+        assert_eq!(paginator().pages(0), 1);
+    }
+
+    #[test]
+    fn render_middle_page() {
+        let rows = render(&items(), 1);
+        let rows = rows.as_array().unwrap();
+        assert_eq!(rows.len(), 3);
+        assert_eq!(rows[0][0]["text"], "11");
+        assert_eq!(
+            rows[2],
+            json!([
+                {"text": "‹", "callback_data": "orders:0"},
+                {"text": "2/3", "callback_data": "orders:"},
+                {"text": "›", "callback_data": "orders:2"}
+            ])
+        );
+    }
+
+    #[test]
+    fn past_the_end_shows_last_page() {
+        let rows = render(&items(), 9);
+        let rows = rows.as_array().unwrap();
+        assert_eq!(
+            rows[0],
+            json!([{"text": "21", "callback_data": "order:21"}, {"text": "22", "callback_data": "order:22"}, {"text": "23", "callback_data": "order:23"}, {"text": "24", "callback_data": "order:24"}, {"text": "25", "callback_data": "order:25"}])
+        );
+        assert_eq!(rows[1].as_array().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn single_page_has_no_navigation() {
+        assert_eq!(render(&items()[..3], 0).as_array().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn navigation_from_callback_data() {
+        let paginator = paginator();
+        assert_eq!(paginator.navigation("orders:2"), Some(Navigation::Page(2)));
+        assert_eq!(paginator.navigation("orders:"), Some(Navigation::Current));
+        assert_eq!(paginator.navigation("files:2"), None);
+        assert_eq!(paginator.navigation("order:2"), None);
+        assert_eq!(paginator.navigation("orders:x"), None);
+    }
+}
@@ -1,5 +1,6 @@
 use serde::{Deserialize, Serialize};
 
+use crate::ids::FileId;
 use crate::types::Integer;
 
 /// Contains information about Telegram Passport data shared with the bot by the user.
@@ -12,7 +13,7 @@ pub struct PassportData {
 /// This object represents a file uploaded to Telegram Passport. Currently all Telegram Passport files are in JPEG format when decrypted and don't exceed 10MB.
 #[derive(Clone, Serialize, Deserialize, Debug)]
 pub struct PassportFile {
-    pub file_id: String,
+    pub file_id: FileId,
     pub file_unique_id: String,
     pub file_size: Integer,
     pub file_date: Integer,
@@ -50,107 +51,138 @@ pub struct EncryptedCredentials {
     pub secret: String,
 }
 
+// The types below are generated from spec/bot_api.json by `cargo run -p rutel_codegen`, edit the spec instead.
+
 /// This object represents an error in the Telegram Passport element which was submitted that should be resolved by the user. It should be one of:
 #[derive(Clone, Serialize, Deserialize, Debug)]
+#[serde(tag = "source")]
 pub enum PassportElementError {
-    PassportElementErrorDataField,
-    PassportElementErrorFrontSide,
-    PassportElementErrorReverseSide,
-    PassportElementErrorSelfie,
-    PassportElementErrorFile,
-    PassportElementErrorFiles,
-    PassportElementErrorTranslationFile,
-    PassportElementErrorTranslationFiles,
-    PassportElementErrorUnspecified,
+    #[serde(rename = "data")]
+    PassportElementErrorDataField(PassportElementErrorDataField),
+    #[serde(rename = "front_side")]
+    PassportElementErrorFrontSide(PassportElementErrorFrontSide),
+    #[serde(rename = "reverse_side")]
+    PassportElementErrorReverseSide(PassportElementErrorReverseSide),
+    #[serde(rename = "selfie")]
+    PassportElementErrorSelfie(PassportElementErrorSelfie),
+    #[serde(rename = "file")]
+    PassportElementErrorFile(PassportElementErrorFile),
+    #[serde(rename = "files")]
+    PassportElementErrorFiles(PassportElementErrorFiles),
+    #[serde(rename = "translation_file")]
+    PassportElementErrorTranslationFile(PassportElementErrorTranslationFile),
+    #[serde(rename = "translation_files")]
+    PassportElementErrorTranslationFiles(PassportElementErrorTranslationFiles),
+    #[serde(rename = "unspecified")]
+    PassportElementErrorUnspecified(PassportElementErrorUnspecified),
 }
 
 /// Represents an issue in one of the data fields that was provided by the user. The error is considered resolved when the field's value changes.
 #[derive(Clone, Serialize, Deserialize, Debug)]
 pub struct PassportElementErrorDataField {
-    pub source: String,
+    /// The section of the user's Telegram Passport which has the error, one of “personal_details”, “passport”, “driver_license”, “identity_card”, “internal_passport”, “address”
     #[serde(rename = "type")]
     pub kind: String,
+    /// Name of the data field which has the error
     pub field_name: String,
+    /// Base64-encoded data hash
     pub data_hash: String,
+    /// Error message
     pub message: String,
 }
 
 /// Represents an issue with the front side of a document. The error is considered resolved when the file with the front side of the document changes.
 #[derive(Clone, Serialize, Deserialize, Debug)]
 pub struct PassportElementErrorFrontSide {
-    pub source: String,
+    /// The section of the user's Telegram Passport which has the issue, one of “passport”, “driver_license”, “identity_card”, “internal_passport”
     #[serde(rename = "type")]
     pub kind: String,
+    /// Base64-encoded hash of the file with the front side of the document
     pub file_hash: String,
+    /// Error message
     pub message: String,
 }
 
 /// Represents an issue with the reverse side of a document. The error is considered resolved when the file with reverse side of the document changes.
 #[derive(Clone, Serialize, Deserialize, Debug)]
 pub struct PassportElementErrorReverseSide {
-    pub source: String,
+    /// The section of the user's Telegram Passport which has the issue, one of “driver_license”, “identity_card”
     #[serde(rename = "type")]
     pub kind: String,
+    /// Base64-encoded hash of the file with the reverse side of the document
     pub file_hash: String,
+    /// Error message
     pub message: String,
 }
 
 /// Represents an issue with the selfie with a document. The error is considered resolved when the file with the selfie changes.
 #[derive(Clone, Serialize, Deserialize, Debug)]
 pub struct PassportElementErrorSelfie {
-    pub source: String,
+    /// The section of the user's Telegram Passport which has the issue, one of “passport”, “driver_license”, “identity_card”, “internal_passport”
     #[serde(rename = "type")]
     pub kind: String,
+    /// Base64-encoded hash of the file with the selfie
     pub file_hash: String,
+    /// Error message
     pub message: String,
 }
 
 /// Represents an issue with a document scan. The error is considered resolved when the file with the document scan changes.
 #[derive(Clone, Serialize, Deserialize, Debug)]
 pub struct PassportElementErrorFile {
-    pub source: String,
+    /// The section of the user's Telegram Passport which has the issue, one of “utility_bill”, “bank_statement”, “rental_agreement”, “passport_registration”, “temporary_registration”
     #[serde(rename = "type")]
     pub kind: String,
+    /// Base64-encoded file hash
     pub file_hash: String,
+    /// Error message
     pub message: String,
 }
 
 /// Represents an issue with a list of scans. The error is considered resolved when the list of files containing the scans changes.
 #[derive(Clone, Serialize, Deserialize, Debug)]
 pub struct PassportElementErrorFiles {
-    pub source: String,
+    /// The section of the user's Telegram Passport which has the issue, one of “utility_bill”, “bank_statement”, “rental_agreement”, “passport_registration”, “temporary_registration”
     #[serde(rename = "type")]
     pub kind: String,
-    pub file_hash: String,
+    /// List of base64-encoded file hashes
+    pub file_hashes: Vec<String>,
+    /// Error message
     pub message: String,
 }
 
 /// Represents an issue with one of the files that constitute the translation of a document. The error is considered resolved when the file changes.
 #[derive(Clone, Serialize, Deserialize, Debug)]
 pub struct PassportElementErrorTranslationFile {
-    pub source: String,
+    /// Type of element of the user's Telegram Passport which has the issue, one of “passport”, “driver_license”, “identity_card”, “internal_passport”, “utility_bill”, “bank_statement”, “rental_agreement”, “passport_registration”, “temporary_registration”
     #[serde(rename = "type")]
     pub kind: String,
+    /// Base64-encoded file hash
     pub file_hash: String,
+    /// Error message
     pub message: String,
 }
 
 /// Represents an issue with the translated version of a document. The error is considered resolved when a file with the document translation change.
 #[derive(Clone, Serialize, Deserialize, Debug)]
 pub struct PassportElementErrorTranslationFiles {
-    pub source: String,
+    /// Type of element of the user's Telegram Passport which has the issue, one of “passport”, “driver_license”, “identity_card”, “internal_passport”, “utility_bill”, “bank_statement”, “rental_agreement”, “passport_registration”, “temporary_registration”
     #[serde(rename = "type")]
     pub kind: String,
-    pub file_hash: String,
+    /// List of base64-encoded file hashes
+    pub file_hashes: Vec<String>,
+    /// Error message
     pub message: String,
 }
 
 /// Represents an issue in an unspecified place. The error is considered resolved when new data is added.
 #[derive(Clone, Serialize, Deserialize, Debug)]
 pub struct PassportElementErrorUnspecified {
-    pub source: String,
+    /// Type of element of the user's Telegram Passport which has the issue
     #[serde(rename = "type")]
     pub kind: String,
-    pub file_hash: String,
+    /// Base64-encoded element hash
+    pub element_hash: String,
+    /// Error message
     pub message: String,
 }
@@ -1,4 +1,5 @@
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 
 use crate::{
     files::{PhotoSize, Video},
@@ -107,28 +108,85 @@ pub struct PreCheckoutQuery {
     pub order_info: Option<OrderInfo>,
 }
 
+/// Describes a Telegram Star transaction.
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct StarTransaction {
+    /// Unique identifier of the transaction. Coincides with the identifer of the original transaction for refund transactions. Coincides with SuccessfulPayment.telegram_payment_charge_id for successful incoming payments from users.
+    pub id: String,
+    /// Number of Telegram Stars transferred by the transaction
+    pub amount: Integer,
+    /// Date the transaction was created in Unix time
+    pub date: Integer,
+    /// Optional. Source of an incoming transaction (e.g., a user purchasing goods or services, Fragment refunding a failed withdrawal). Only for incoming transactions
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<TransactionPartner>,
+    /// Optional. Receiver of an outgoing transaction (e.g., a user for a purchase refund, Fragment for a withdrawal). Only for outgoing transactions
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub receiver: Option<TransactionPartner>,
+}
+
+/// Contains a list of Telegram Star transactions.
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct StarTransactions {
+    /// The list of transactions
+    pub transactions: Vec<StarTransaction>,
+}
+
+/// Describes the paid media added to a message.
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct PaidMediaInfo {
+    /// The number of Telegram Stars that must be paid to buy access to the media
+    pub star_count: Integer,
+    /// Information about the paid media
+    pub paid_media: Vec<PaidMedia>,
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn transaction_partner_with_withdrawal_state() {
+        let partner: TransactionPartner = serde_json::from_value(json!({
+            "type": "fragment",
+            "withdrawal_state": {"type": "succeeded", "date": 1, "url": "https://x"}
+        }))
+        .unwrap();
+        assert!(matches!(
+            partner,
+            TransactionPartner::TransactionPartnerFragment(TransactionPartnerFragment {
+                withdrawal_state: Some(RevenueWithdrawalState::RevenueWithdrawalStateSucceeded(_))
+            })
+        ));
+    }
+}
+
+// The types below are generated from spec/bot_api.json by `cargo run -p rutel_codegen`, edit the spec instead.
+
 /// This object describes the state of a revenue withdrawal operation. Currently, it can be one of
 #[derive(Clone, Serialize, Deserialize, Debug)]
+#[serde(tag = "type")]
 pub enum RevenueWithdrawalState {
-    RevenueWithdrawalStatePending,
-    RevenueWithdrawalStateSucceeded,
-    RevenueWithdrawalStateFailed,
+    #[serde(rename = "pending")]
+    RevenueWithdrawalStatePending(RevenueWithdrawalStatePending),
+    #[serde(rename = "succeeded")]
+    RevenueWithdrawalStateSucceeded(RevenueWithdrawalStateSucceeded),
+    #[serde(rename = "failed")]
+    RevenueWithdrawalStateFailed(RevenueWithdrawalStateFailed),
+    /// An object not known to this version of the library, or one that can't be decoded
+    #[serde(untagged)]
+    Unknown(Value),
 }
 
 /// The withdrawal is in progress.
 #[derive(Clone, Serialize, Deserialize, Debug)]
-pub struct RevenueWithdrawalStatePending {
-    /// Type of the state, always “pending”
-    #[serde(rename = "type")]
-    pub kind: String,
-}
+pub struct RevenueWithdrawalStatePending {}
 
 /// The withdrawal succeeded.
 #[derive(Clone, Serialize, Deserialize, Debug)]
 pub struct RevenueWithdrawalStateSucceeded {
-    /// Type of the state, always “succeeded”
-    #[serde(rename = "type")]
-    pub kind: String,
     /// Date the withdrawal was completed in Unix time
     pub date: Integer,
     /// An HTTPS URL that can be used to see transaction details
@@ -137,27 +195,28 @@ pub struct RevenueWithdrawalStateSucceeded {
 
 /// The withdrawal failed and the transaction was refunded.
 #[derive(Clone, Serialize, Deserialize, Debug)]
-pub struct RevenueWithdrawalStateFailed {
-    /// Type of the state, always “failed”
-    #[serde(rename = "type")]
-    pub kind: String,
-}
+pub struct RevenueWithdrawalStateFailed {}
 
 /// This object describes the source of a transaction, or its recipient for outgoing transactions. Currently, it can be one of
 #[derive(Clone, Serialize, Deserialize, Debug)]
+#[serde(tag = "type")]
 pub enum TransactionPartner {
-    TransactionPartnerUser,
-    TransactionPartnerFragment,
-    TransactionPartnerTelegramAds,
-    TransactionPartnerOther,
+    #[serde(rename = "user")]
+    TransactionPartnerUser(TransactionPartnerUser),
+    #[serde(rename = "fragment")]
+    TransactionPartnerFragment(TransactionPartnerFragment),
+    #[serde(rename = "telegram_ads")]
+    TransactionPartnerTelegramAds(TransactionPartnerTelegramAds),
+    #[serde(rename = "other")]
+    TransactionPartnerOther(TransactionPartnerOther),
+    /// An object not known to this version of the library, or one that can't be decoded
+    #[serde(untagged)]
+    Unknown(Value),
 }
 
 /// Describes a transaction with a user.
 #[derive(Clone, Serialize, Deserialize, Debug)]
 pub struct TransactionPartnerUser {
-    /// Type of the transaction partner, always “user”
-    #[serde(rename = "type")]
-    pub kind: String,
     /// Information about the user
     pub user: User,
     /// Optional. Bot-specified invoice payload
@@ -168,9 +227,6 @@ pub struct TransactionPartnerUser {
 /// Describes a withdrawal transaction with Fragment.
 #[derive(Clone, Serialize, Deserialize, Debug)]
 pub struct TransactionPartnerFragment {
-    /// Type of the transaction partner, always “fragment”
-    #[serde(rename = "type")]
-    pub kind: String,
     /// Optional. State of the transaction if the transaction is outgoing
     #[serde(skip_serializing_if = "Option::is_none")]
     pub withdrawal_state: Option<RevenueWithdrawalState>,
@@ -178,67 +234,30 @@ pub struct TransactionPartnerFragment {
 
 /// Describes a withdrawal transaction to the Telegram Ads platform.
 #[derive(Clone, Serialize, Deserialize, Debug)]
-pub struct TransactionPartnerTelegramAds {
-    /// Type of the transaction partner, always “telegram_ads”
-    #[serde(rename = "type")]
-    pub kind: String,
-}
+pub struct TransactionPartnerTelegramAds {}
 
 /// Describes a transaction with an unknown source or recipient.
 #[derive(Clone, Serialize, Deserialize, Debug)]
-pub struct TransactionPartnerOther {
-    /// Type of the transaction partner, always “other”
-    #[serde(rename = "type")]
-    pub kind: String,
-}
-
-/// Describes a Telegram Star transaction.
-#[derive(Clone, Serialize, Deserialize, Debug)]
-pub struct StarTransaction {
-    /// Unique identifier of the transaction. Coincides with the identifer of the original transaction for refund transactions. Coincides with SuccessfulPayment.telegram_payment_charge_id for successful incoming payments from users.
-    pub id: String,
-    /// Number of Telegram Stars transferred by the transaction
-    pub amount: Integer,
-    /// Date the transaction was created in Unix time
-    pub date: Integer,
-    /// Optional. Source of an incoming transaction (e.g., a user purchasing goods or services, Fragment refunding a failed withdrawal). Only for incoming transactions
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub source: Option<TransactionPartner>,
-    /// Optional. Receiver of an outgoing transaction (e.g., a user for a purchase refund, Fragment for a withdrawal). Only for outgoing transactions
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub receiver: Option<TransactionPartner>,
-}
-
-/// Contains a list of Telegram Star transactions.
-#[derive(Clone, Serialize, Deserialize, Debug)]
-pub struct StarTransactions {
-    /// The list of transactions
-    pub transactions: Vec<StarTransaction>,
-}
-
-/// Describes the paid media added to a message.
-#[derive(Clone, Serialize, Deserialize, Debug)]
-pub struct PaidMediaInfo {
-    /// The number of Telegram Stars that must be paid to buy access to the media
-    pub star_count: Integer,
-    /// Information about the paid media
-    pub paid_media: Vec<PaidMedia>,
-}
+pub struct TransactionPartnerOther {}
 
 /// This object describes paid media. Currently, it can be one of
 #[derive(Clone, Serialize, Deserialize, Debug)]
+#[serde(tag = "type")]
 pub enum PaidMedia {
-    PaidMediaPreview,
-    PaidMediaPhoto,
-    PaidMediaVideo,
+    #[serde(rename = "preview")]
+    PaidMediaPreview(PaidMediaPreview),
+    #[serde(rename = "photo")]
+    PaidMediaPhoto(PaidMediaPhoto),
+    #[serde(rename = "video")]
+    PaidMediaVideo(PaidMediaVideo),
+    /// An object not known to this version of the library, or one that can't be decoded
+    #[serde(untagged)]
+    Unknown(Value),
 }
 
 /// The paid media isn't available before the payment.
 #[derive(Clone, Serialize, Deserialize, Debug)]
 pub struct PaidMediaPreview {
-    /// Type of the paid media, always “preview”
-    #[serde(rename = "type")]
-    pub kind: String,
     /// Optional. Media width as defined by the sender
     #[serde(skip_serializing_if = "Option::is_none")]
     pub width: Option<Integer>,
@@ -253,9 +272,6 @@ pub struct PaidMediaPreview {
 /// The paid media is a photo.
 #[derive(Clone, Serialize, Deserialize, Debug)]
 pub struct PaidMediaPhoto {
-    /// Type of the paid media, always “photo”
-    #[serde(rename = "type")]
-    pub kind: String,
     /// The photo
     pub photo: Vec<PhotoSize>,
 }
@@ -263,9 +279,6 @@ pub struct PaidMediaPhoto {
 /// The paid media is a video.
 #[derive(Clone, Serialize, Deserialize, Debug)]
 pub struct PaidMediaVideo {
-    /// Type of the paid media, always “video”
-    #[serde(rename = "type")]
-    pub kind: String,
     /// The video
     pub video: Video,
 }
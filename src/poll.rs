@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 use crate::{
+    formatting::ParseMode,
     message::MessageEntity,
     types::{Boolean, Chat, Integer, User},
 };
@@ -24,7 +25,7 @@ pub struct InputPollOption {
     pub text: String,
     /// Optional. Mode for parsing entities in the text. See formatting options for more details. Currently, only custom emoji entities are allowed
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub text_parse_mode: Option<String>,
+    pub text_parse_mode: Option<ParseMode>,
     /// Optional. A JSON-serialized list of special entities that appear in the poll option text. It can be specified instead of text_parse_mode
     #[serde(skip_serializing_if = "Option::is_none")]
     pub text_entities: Option<Vec<MessageEntity>>,
@@ -45,6 +46,14 @@ pub struct PollAnswer {
     pub option_ids: Vec<Integer>,
 }
 
+string_enum! {
+    /// Type of a poll
+    pub enum PollType {
+        Regular = "regular",
+        Quiz = "quiz",
+    }
+}
+
 /// This object contains information about a poll.
 #[derive(Clone, Serialize, Deserialize, Debug)]
 pub struct Poll {
@@ -65,7 +74,7 @@ pub struct Poll {
     pub is_anonymous: Boolean,
     /// Poll type, currently can be “regular” or “quiz”
     #[serde(rename = "type")]
-    pub kind: String,
+    pub kind: PollType,
     /// True, if the poll allows multiple answers
     pub allows_multiple_answers: Boolean,
     /// Optional. 0-based identifier of the correct answer option. Available only for polls in the quiz mode, which are closed, or was sent (not forwarded) by the bot or to the private chat with the bot.
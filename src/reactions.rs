@@ -1,32 +1,21 @@
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 
+use crate::ids::MessageId;
 use crate::types::{Chat, Integer, User};
 
-/// This object describes the type of a reaction. Currently, it can be one of
-#[derive(Clone, Serialize, Deserialize, Debug)]
-pub enum ReactionType {
-    ReactionTypeEmoji(ReactionTypeEmoji),
-    ReactionTypeCustomEmoji(ReactionTypeCustomEmoji),
-}
+impl ReactionType {
+    pub fn emoji(emoji: &str) -> Self {
+        ReactionType::ReactionTypeEmoji(ReactionTypeEmoji {
+            emoji: emoji.to_string(),
+        })
+    }
 
-/// The reaction is based on an emoji.
-#[derive(Clone, Serialize, Deserialize, Debug)]
-pub struct ReactionTypeEmoji {
-    /// Type of the reaction, always “emoji”
-    #[serde(rename = "type")]
-    pub kind: String,
-    /// Reaction emoji. Currently, it can be one of "👍", "👎", "❤", "🔥", "🥰", "👏", "😁", "🤔", "🤯", "😱", "🤬", "😢", "🎉", "🤩", "🤮", "💩", "🙏", "👌", "🕊", "🤡", "🥱", "🥴", "😍", "🐳", "❤‍🔥", "🌚", "🌭", "💯", "🤣", "⚡", "🍌", "🏆", "💔", "🤨", "😐", "🍓", "🍾", "💋", "🖕", "😈", "😴", "😭", "🤓", "👻", "👨‍💻", "👀", "🎃", "🙈", "😇", "😨", "🤝", "✍", "🤗", "🫡", "🎅", "🎄", "☃", "💅", "🤪", "🗿", "🆒", "💘", "🙉", "🦄", "😘", "💊", "🙊", "😎", "👾", "🤷‍♂", "🤷", "🤷‍♀", "😡"
-    pub emoji: String,
-}
-
-/// The reaction is based on a custom emoji.
-#[derive(Clone, Serialize, Deserialize, Debug)]
-pub struct ReactionTypeCustomEmoji {
-    /// Type of the reaction, always “custom_emoji”
-    #[serde(rename = "type")]
-    pub kind: String,
-    /// Custom emoji identifier
-    pub custom_emoji_id: String,
+    pub fn custom_emoji(custom_emoji_id: &str) -> Self {
+        ReactionType::ReactionTypeCustomEmoji(ReactionTypeCustomEmoji {
+            custom_emoji_id: custom_emoji_id.to_string(),
+        })
+    }
 }
 
 /// Represents a reaction added to a message along with the number of times it was added.
@@ -45,7 +34,7 @@ pub struct MessageReactionUpdated {
     /// The chat containing the message the user reacted to
     pub chat: Chat,
     /// Unique identifier of the message inside the chat
-    pub message_id: Integer,
+    pub message_id: MessageId,
     /// Optional. The user that changed the reaction, if the user isn't anonymous
     #[serde(skip_serializing_if = "Option::is_none")]
     pub user: Option<User>,
@@ -66,9 +55,55 @@ pub struct MessageReactionCountUpdated {
     /// The chat containing the message
     pub chat: Chat,
     /// Unique message identifier inside the chat
-    pub message_id: Integer,
+    pub message_id: MessageId,
     /// Date of the change in Unix time
     pub date: Integer,
     /// List of reactions that are present on the message
     pub reactions: Vec<ReactionCount>,
 }
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn emoji_reaction() {
+        let reaction: ReactionType =
+            serde_json::from_value(json!({"type": "emoji", "emoji": "👍"})).unwrap();
+        assert_eq!(
+            serde_json::to_value(reaction).unwrap(),
+            serde_json::to_value(ReactionType::emoji("👍")).unwrap()
+        );
+    }
+}
+
+// The types below are generated from spec/bot_api.json by `cargo run -p rutel_codegen`, edit the spec instead.
+
+/// This object describes the type of a reaction. Currently, it can be one of
+#[derive(Clone, Serialize, Deserialize, Debug)]
+#[serde(tag = "type")]
+pub enum ReactionType {
+    #[serde(rename = "emoji")]
+    ReactionTypeEmoji(ReactionTypeEmoji),
+    #[serde(rename = "custom_emoji")]
+    ReactionTypeCustomEmoji(ReactionTypeCustomEmoji),
+    /// An object not known to this version of the library, or one that can't be decoded
+    #[serde(untagged)]
+    Unknown(Value),
+}
+
+/// The reaction is based on an emoji.
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct ReactionTypeEmoji {
+    /// Reaction emoji. Currently, it can be one of "👍", "👎", "❤", "🔥", "🥰", "👏", "😁", "🤔", "🤯", "😱", "🤬", "😢", "🎉", "🤩", "🤮", "💩", "🙏", "👌", "🕊", "🤡", "🥱", "🥴", "😍", "🐳", "❤‍🔥", "🌚", "🌭", "💯", "🤣", "⚡", "🍌", "🏆", "💔", "🤨", "😐", "🍓", "🍾", "💋", "🖕", "😈", "😴", "😭", "🤓", "👻", "👨‍💻", "👀", "🎃", "🙈", "😇", "😨", "🤝", "✍", "🤗", "🫡", "🎅", "🎄", "☃", "💅", "🤪", "🗿", "🆒", "💘", "🙉", "🦄", "😘", "💊", "🙊", "😎", "👾", "🤷‍♂", "🤷", "🤷‍♀", "😡"
+    pub emoji: String,
+}
+
+/// The reaction is based on a custom emoji.
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct ReactionTypeCustomEmoji {
+    /// Custom emoji identifier
+    pub custom_emoji_id: String,
+}
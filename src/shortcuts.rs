@@ -0,0 +1,368 @@
+use crate::{
+    bot::{
+        AnswerCallbackQuery, Bot, DeleteMessage, EditMessageCaption, EditMessageReplyMarkup,
+        EditMessageText, SendMessage, SetMessageReaction,
+    },
+    error::Result,
+    ids::{ChatId, MessageId},
+    message::{InaccessibleMessage, MaybeInaccessibleMessage, Message, ReplyParameters},
+    reactions::ReactionType,
+    types::{Boolean, CallbackQuery, ChatID, InlineKeyboardMarkup, Integer, TrueMessage},
+};
+
+/// A message the bot received or sent, with what is needed to answer it in place.
+pub trait ChatMessage {
+    fn chat_id(&self) -> ChatId;
+
+    fn message_id(&self) -> MessageId;
+
+    /// The forum topic of the message
+    fn message_thread_id(&self) -> Option<Integer> {
+        None
+    }
+
+    /// The business connection the message was received through
+    fn business_connection_id(&self) -> Option<&str> {
+        None
+    }
+
+    /// Reply parameters quoting the message, the reply is sent even if the message has been deleted
+    fn reply_parameters(&self) -> ReplyParameters {
+        ReplyParameters {
+            message_id: self.message_id(),
+            chat_id: None,
+            allow_sending_without_reply: Some(true),
+            quote: None,
+            quote_parse_mode: None,
+            quote_entities: None,
+            quote_position: None,
+        }
+    }
+
+    /// A text message replying to the message, in the same topic and business connection
+    fn reply(&self, text: &str) -> SendMessage {
+        let mut message = SendMessage::new(self.chat_id().into(), text.to_string());
+        message.business_connection_id = self.business_connection_id().map(str::to_string);
+        message.message_thread_id = self.message_thread_id();
+        message.reply_parameters = Some(self.reply_parameters());
+        message
+    }
+}
+
+impl ChatMessage for Message {
+    fn chat_id(&self) -> ChatId {
+        self.chat.id
+    }
+
+    fn message_id(&self) -> MessageId {
+        self.message_id
+    }
+
+    fn message_thread_id(&self) -> Option<Integer> {
+        if self.is_topic_message == Some(true) {
+            self.message_thread_id
+        } else {
+            None
+        }
+    }
+
+    fn business_connection_id(&self) -> Option<&str> {
+        self.business_connection_id.as_deref()
+    }
+}
+
+impl ChatMessage for InaccessibleMessage {
+    fn chat_id(&self) -> ChatId {
+        self.chat.id
+    }
+
+    fn message_id(&self) -> MessageId {
+        self.message_id
+    }
+}
+
+impl ChatMessage for MaybeInaccessibleMessage {
+    fn chat_id(&self) -> ChatId {
+        self.chat().id
+    }
+
+    fn message_id(&self) -> MessageId {
+        MaybeInaccessibleMessage::message_id(self)
+    }
+
+    fn message_thread_id(&self) -> Option<Integer> {
+        ChatMessage::message_thread_id(self.message()?)
+    }
+
+    fn business_connection_id(&self) -> Option<&str> {
+        ChatMessage::business_connection_id(self.message()?)
+    }
+}
+
+/// A message to edit: a message in a chat, or a message sent via the bot in inline mode.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MessageTarget {
+    pub business_connection_id: Option<String>,
+    pub chat_id: Option<ChatId>,
+    pub message_id: Option<MessageId>,
+    pub inline_message_id: Option<String>,
+}
+
+impl MessageTarget {
+    /// A message sent via the bot in inline mode
+    pub fn inline(inline_message_id: &str) -> Self {
+        MessageTarget {
+            inline_message_id: Some(inline_message_id.to_string()),
+            ..Default::default()
+        }
+    }
+
+    pub fn edit_text(&self, text: &str) -> EditMessageText {
+        let mut request = EditMessageText::new(text.to_string());
+        request
+            .business_connection_id(self.business_connection_id.clone())
+            .chat_id(self.chat_id.map(ChatID::from))
+            .message_id(self.message_id)
+            .inline_message_id(self.inline_message_id.clone());
+        request
+    }
+
+    pub fn edit_caption(&self, caption: &str) -> EditMessageCaption {
+        let mut request = EditMessageCaption::new();
+        request
+            .business_connection_id(self.business_connection_id.clone())
+            .chat_id(self.chat_id.map(ChatID::from))
+            .message_id(self.message_id)
+            .inline_message_id(self.inline_message_id.clone())
+            .caption(Some(caption.to_string()));
+        request
+    }
+
+    pub fn edit_reply_markup(
+        &self,
+        reply_markup: Option<InlineKeyboardMarkup>,
+    ) -> EditMessageReplyMarkup {
+        let mut request = EditMessageReplyMarkup::new();
+        request
+            .business_connection_id(self.business_connection_id.clone())
+            .chat_id(self.chat_id.map(ChatID::from))
+            .message_id(self.message_id)
+            .inline_message_id(self.inline_message_id.clone())
+            .reply_markup(reply_markup);
+        request
+    }
+}
+
+impl<T: ChatMessage> From<&T> for MessageTarget {
+    fn from(message: &T) -> Self {
+        MessageTarget {
+            business_connection_id: message.business_connection_id().map(str::to_string),
+            chat_id: Some(message.chat_id()),
+            message_id: Some(message.message_id()),
+            inline_message_id: None,
+        }
+    }
+}
+
+/// The message with the button that originated the query, or its inline message
+impl From<&CallbackQuery> for MessageTarget {
+    fn from(query: &CallbackQuery) -> Self {
+        match &query.message {
+            Some(message) => message.into(),
+            None => MessageTarget {
+                inline_message_id: query.inline_message_id.clone(),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// Edits a message, made by [`Bot::edit`].
+pub struct Edit<'a> {
+    bot: &'a mut Bot,
+    target: MessageTarget,
+}
+
+impl Edit<'_> {
+    /// Replaces the text, removing the inline keyboard
+    pub async fn text(self, text: &str) -> Result<TrueMessage> {
+        self.bot
+            .edit_message_text(&self.target.edit_text(text))
+            .await
+    }
+
+    /// Replaces the caption, removing the inline keyboard
+    pub async fn caption(self, caption: &str) -> Result<TrueMessage> {
+        self.bot
+            .edit_message_caption(&self.target.edit_caption(caption))
+            .await
+    }
+
+    /// Replaces the inline keyboard
+    pub async fn reply_markup(
+        self,
+        reply_markup: impl Into<InlineKeyboardMarkup>,
+    ) -> Result<TrueMessage> {
+        let request = self.target.edit_reply_markup(Some(reply_markup.into()));
+        self.bot.edit_message_reply_markup(&request).await
+    }
+
+    pub async fn remove_reply_markup(self) -> Result<TrueMessage> {
+        let request = self.target.edit_reply_markup(None);
+        self.bot.edit_message_reply_markup(&request).await
+    }
+}
+
+/// Answers a callback query, made by [`Bot::answer`].
+pub struct Answer<'a> {
+    bot: &'a mut Bot,
+    request: AnswerCallbackQuery,
+}
+
+impl Answer<'_> {
+    /// Stops the progress indicator of the button without showing anything
+    pub async fn ack(self) -> Result<Boolean> {
+        self.bot.answer_callback_query(&self.request).await
+    }
+
+    /// Shows a notification at the top of the chat screen
+    pub async fn text(mut self, text: &str) -> Result<Boolean> {
+        self.request.text(Some(text.to_string()));
+        self.bot.answer_callback_query(&self.request).await
+    }
+
+    /// Shows an alert the user has to dismiss
+    pub async fn alert(mut self, text: &str) -> Result<Boolean> {
+        self.request
+            .text(Some(text.to_string()))
+            .show_alert(Some(true));
+        self.bot.answer_callback_query(&self.request).await
+    }
+
+    /// Opens a URL: a game URL for game buttons, or a t.me link that starts the bot with a parameter
+    pub async fn url(mut self, url: &str) -> Result<Boolean> {
+        self.request.url(Some(url.to_string()));
+        self.bot.answer_callback_query(&self.request).await
+    }
+}
+
+impl Bot {
+    /// Replies to a message with a text, in the same topic and business connection.
+    ///
+    /// Use [`ChatMessage::reply`] to set more parameters before sending.
+    pub async fn reply_to(&mut self, message: &impl ChatMessage, text: &str) -> Result<Message> {
+        self.send_message(&message.reply(text)).await
+    }
+
+    /// Edits a message: a received message, the message of a callback query or a [`MessageTarget`]
+    pub fn edit(&mut self, target: impl Into<MessageTarget>) -> Edit<'_> {
+        Edit {
+            bot: self,
+            target: target.into(),
+        }
+    }
+
+    pub async fn delete(&mut self, message: &impl ChatMessage) -> Result<Boolean> {
+        let request = DeleteMessage::new(message.chat_id().into(), message.message_id());
+        self.delete_message(&request).await
+    }
+
+    /// Sets the bot's reaction to a message to an emoji, replacing its previous reaction
+    pub async fn react(&mut self, message: &impl ChatMessage, emoji: &str) -> Result<Boolean> {
+        let mut request = SetMessageReaction::new(message.chat_id().into(), message.message_id());
+        request.reaction(Some(vec![ReactionType::emoji(emoji)]));
+        self.set_message_reaction(&request).await
+    }
+
+    /// Removes the bot's reaction to a message
+    pub async fn unreact(&mut self, message: &impl ChatMessage) -> Result<Boolean> {
+        let mut request = SetMessageReaction::new(message.chat_id().into(), message.message_id());
+        request.reaction(Some(Vec::new()));
+        self.set_message_reaction(&request).await
+    }
+
+    /// Answers a callback query; it must be answered even if nothing is shown
+    pub fn answer(&mut self, query: &CallbackQuery) -> Answer<'_> {
+        Answer {
+            bot: self,
+            request: AnswerCallbackQuery::new(query.id.clone()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value};
+
+    use super::*;
+
+    fn query(message: Value) -> CallbackQuery {
+        serde_json::from_value(json!({
+            "id": "q",
+            "from": {"id": 7, "is_bot": false, "first_name": "A"},
+            "message": message,
+            "chat_instance": "i",
+            "data": "x"
+        }))
+        .unwrap()
+    }
+
+    fn chat() -> Value {
+        json!({"id": -1001, "type": "supergroup", "is_forum": true})
+    }
+
+    fn accessible() -> CallbackQuery {
+        query(json!({
+            "message_id": 10, "date": 1700000000, "chat": chat(), "text": "menu",
+            "message_thread_id": 4, "is_topic_message": true, "business_connection_id": "b"
+        }))
+    }
+
+    #[test]
+    fn reply_in_topic_and_business_connection() {
+        let accessible = accessible();
+        let message = accessible.message.as_ref().unwrap();
+        assert_eq!(message.message().unwrap().text.as_deref(), Some("menu"));
+        assert_eq!(
+            serde_json::to_value(message).unwrap()["message_thread_id"],
+            4
+        );
+        assert_eq!(
+            message.reply("ok").to_string(),
+            r#"{"business_connection_id":"b","chat_id":-1001,"message_thread_id":4,"text":"ok","reply_parameters":{"message_id":10,"allow_sending_without_reply":true}}"#
+        );
+    }
+
+    #[test]
+    fn edit_callback_message() {
+        assert_eq!(
+            MessageTarget::from(&accessible())
+                .edit_text("new")
+                .to_string(),
+            r#"{"business_connection_id":"b","chat_id":-1001,"message_id":10,"text":"new"}"#
+        );
+    }
+
+    #[test]
+    fn inaccessible_message() {
+        let inaccessible = query(json!({"message_id": 11, "date": 0, "chat": chat()}));
+        let message = inaccessible.message.as_ref().unwrap();
+        assert!(message.message().is_none());
+        assert_eq!(message.message_id(), MessageId(11));
+        assert_eq!(message.chat().id, ChatId(-1001));
+        assert_eq!(message.reply("ok").message_thread_id, None);
+    }
+
+    #[test]
+    fn inline_message_target() {
+        let mut inline = query(json!(null));
+        inline.inline_message_id = Some("inl".to_string());
+        assert_eq!(MessageTarget::from(&inline), MessageTarget::inline("inl"));
+        assert_eq!(
+            MessageTarget::from(&inline)
+                .edit_reply_markup(None)
+                .to_string(),
+            r#"{"inline_message_id":"inl"}"#
+        );
+    }
+}
@@ -0,0 +1,366 @@
+use std::future::Future;
+
+use crate::{
+    bot::{
+        Bot, SendAnimation, SendAudio, SendDocument, SendMessage, SendPhoto, SendVideo, SendVoice,
+    },
+    error::Result,
+    formatting::{self, ParseMode},
+    message::{Message, MessageEntity, ReplyParameters},
+    types::{Integer, ReplyMarkup},
+};
+
+/// Maximum length of a message text in UTF-16 code units, after entities parsing
+pub const MESSAGE_TEXT_LIMIT: Integer = 4096;
+
+/// Maximum length of a media caption in UTF-16 code units, after entities parsing
+pub const CAPTION_LIMIT: Integer = 1024;
+
+/// A character of the text being split with its byte and UTF-16 offsets
+struct Char {
+    byte: usize,
+    offset: Integer,
+    c: char,
+}
+
+/// Splits a text into chunks of at most `limit` UTF-16 code units, each with its own entities.
+///
+/// Chunks end at the last paragraph break, line break or whitespace that fits, in that order of preference, and the
+/// whitespace between chunks is dropped. A chunk never ends inside an entity unless a single entity is longer than
+/// the limit, in which case it is split across chunks, again at a line break or whitespace if there is one. Entity
+/// offsets are re-based to the start of each chunk.
+pub fn split(
+    text: &str,
+    entities: &[MessageEntity],
+    limit: Integer,
+) -> Vec<(String, Vec<MessageEntity>)> {
+    split_with(text, entities, limit, limit)
+}
+
+/// Splits a caption into a first chunk that fits `CAPTION_LIMIT` and follow-up text chunks that fit `MESSAGE_TEXT_LIMIT`
+pub fn split_caption(text: &str, entities: &[MessageEntity]) -> Vec<(String, Vec<MessageEntity>)> {
+    split_with(text, entities, CAPTION_LIMIT, MESSAGE_TEXT_LIMIT)
+}
+
+fn split_with(
+    text: &str,
+    entities: &[MessageEntity],
+    first_limit: Integer,
+    limit: Integer,
+) -> Vec<(String, Vec<MessageEntity>)> {
+    let mut chars: Vec<Char> = Vec::with_capacity(text.len());
+    let mut offset = 0;
+    for (byte, c) in text.char_indices() {
+        chars.push(Char { byte, offset, c });
+        offset += c.len_utf16() as Integer;
+    }
+    chars.push(Char {
+        byte: text.len(),
+        offset,
+        c: '\0',
+    });
+    let end = chars.len() - 1;
+
+    let mut chunks = Vec::new();
+    let mut start = 0;
+    while start < end {
+        let limit = if chunks.is_empty() {
+            first_limit
+        } else {
+            limit
+        }
+        .max(1);
+        let cut = if chars[end].offset - chars[start].offset <= limit {
+            end
+        } else {
+            split_point(&chars, start, limit, entities)
+        };
+        chunks.push(chunk(text, entities, &chars[start], &chars[cut]));
+        start = cut;
+        while start < end && chars[start].c.is_whitespace() {
+            start += 1;
+        }
+    }
+    if chunks.is_empty() {
+        chunks.push((String::new(), Vec::new()));
+    }
+    chunks
+}
+
+/// Index of the character the chunk starting at `start` should end before
+fn split_point(chars: &[Char], start: usize, limit: Integer, entities: &[MessageEntity]) -> usize {
+    let max = chars[start].offset + limit;
+    let candidates: Vec<usize> = (start + 1..chars.len())
+        .take_while(|&i| chars[i].offset <= max)
+        .collect();
+    let allowed: Vec<usize> = candidates
+        .iter()
+        .copied()
+        .filter(|&i| {
+            let offset = chars[i].offset;
+            !entities
+                .iter()
+                .any(|e| e.offset < offset && offset < e.offset + e.length)
+        })
+        .collect();
+    preferred(chars, &allowed)
+        .or_else(|| allowed.last().copied())
+        .or_else(|| preferred(chars, &candidates))
+        .or_else(|| candidates.last().copied())
+        .unwrap_or(start + 1)
+}
+
+/// The last paragraph break, line break or whitespace among the points, in that order of preference
+fn preferred(chars: &[Char], points: &[usize]) -> Option<usize> {
+    let last = |at: &dyn Fn(usize) -> bool| points.iter().rev().copied().find(|&i| at(i));
+    last(&|i| chars[i].c == '\n' && chars.get(i + 1).is_some_and(|next| next.c == '\n'))
+        .or_else(|| last(&|i| chars[i].c == '\n'))
+        .or_else(|| last(&|i| chars[i].c.is_whitespace()))
+}
+
+/// The text between two characters with the parts of the entities covering it
+fn chunk(
+    text: &str,
+    entities: &[MessageEntity],
+    from: &Char,
+    to: &Char,
+) -> (String, Vec<MessageEntity>) {
+    let entities = entities
+        .iter()
+        .filter_map(|entity| {
+            let start = entity.offset.max(from.offset);
+            let end = (entity.offset + entity.length).min(to.offset);
+            (start < end).then(|| {
+                let mut entity = entity.clone();
+                entity.offset = start - from.offset;
+                entity.length = end - start;
+                entity
+            })
+        })
+        .collect();
+    (text[from.byte..to.byte].to_string(), entities)
+}
+
+/// Text with entities, parsing it locally when a parse mode is given so it can be split
+fn plain(
+    text: String,
+    parse_mode: Option<ParseMode>,
+    entities: Option<Vec<MessageEntity>>,
+) -> Result<(String, Vec<MessageEntity>)> {
+    match parse_mode {
+        Some(mode) => Ok(formatting::parse(&text, mode)?),
+        None => Ok((text, entities.unwrap_or_default())),
+    }
+}
+
+fn reply_to(message: &Message) -> ReplyParameters {
+    ReplyParameters {
+        message_id: message.message_id,
+        chat_id: None,
+        allow_sending_without_reply: None,
+        quote: None,
+        quote_parse_mode: None,
+        quote_entities: None,
+        quote_position: None,
+    }
+}
+
+/// A request sending a media file with a caption.
+pub trait Caption {
+    /// The caption, its parse mode and its entities
+    fn caption_mut(
+        &mut self,
+    ) -> (
+        &mut Option<String>,
+        &mut Option<ParseMode>,
+        &mut Option<Vec<MessageEntity>>,
+    );
+
+    /// A text message to the same chat, topic and business connection with the same notification settings
+    fn follow_up(&self, text: String) -> SendMessage;
+
+    fn send(&self, bot: &mut Bot) -> impl Future<Output = Result<Message>> + Send;
+}
+
+macro_rules! caption {
+    ($($request:ident => $method:ident),* $(,)?) => {$(
+        impl Caption for $request {
+            fn caption_mut(
+                &mut self,
+            ) -> (
+                &mut Option<String>,
+                &mut Option<ParseMode>,
+                &mut Option<Vec<MessageEntity>>,
+            ) {
+                (&mut self.caption, &mut self.parse_mode, &mut self.caption_entities)
+            }
+
+            fn follow_up(&self, text: String) -> SendMessage {
+                let mut message = SendMessage::new(self.chat_id.clone(), text);
+                message.business_connection_id = self.business_connection_id.clone();
+                message.message_thread_id = self.message_thread_id;
+                message.disable_notification = self.disable_notification;
+                message.protect_content = self.protect_content;
+                message
+            }
+
+            fn send(&self, bot: &mut Bot) -> impl Future<Output = Result<Message>> + Send {
+                bot.$method(self)
+            }
+        }
+    )*};
+}
+
+caption!(
+    SendPhoto => send_photo,
+    SendAudio => send_audio,
+    SendDocument => send_document,
+    SendVideo => send_video,
+    SendAnimation => send_animation,
+    SendVoice => send_voice,
+);
+
+impl Bot {
+    /// Sends a text of any length, split into messages that fit `MESSAGE_TEXT_LIMIT`.
+    ///
+    /// Each message after the first is a reply to the previous one, and the reply markup is attached to the last
+    /// message. Text with a parse mode is parsed locally and sent with entities.
+    pub async fn send_long_message(&mut self, mut message: SendMessage) -> Result<Vec<Message>> {
+        let (text, entities) = plain(
+            std::mem::take(&mut message.text),
+            message.parse_mode.take(),
+            message.entities.take(),
+        )?;
+        let chunks = split(&text, &entities, MESSAGE_TEXT_LIMIT);
+        let reply_markup = message.reply_markup.take();
+        self.send_chunks(message, chunks, reply_markup, Vec::new())
+            .await
+    }
+
+    /// Sends a media file with a caption of any length.
+    ///
+    /// The part of the caption that doesn't fit `CAPTION_LIMIT` is sent in text messages replying to the media
+    /// message, which keeps its reply markup.
+    pub async fn send_with_caption<R: Caption>(&mut self, mut request: R) -> Result<Vec<Message>> {
+        let (caption, parse_mode, caption_entities) = request.caption_mut();
+        let (text, entities) = plain(
+            caption.take().unwrap_or_default(),
+            parse_mode.take(),
+            caption_entities.take(),
+        )?;
+        let mut chunks = split_caption(&text, &entities);
+        let rest = chunks.split_off(1);
+        let (first, first_entities) = chunks.pop().unwrap_or_default();
+        let (caption, _, caption_entities) = request.caption_mut();
+        *caption = (!first.is_empty()).then_some(first);
+        *caption_entities = (!first_entities.is_empty()).then_some(first_entities);
+        let sent = request.send(self).await?;
+        let message = request.follow_up(String::new());
+        self.send_chunks(message, rest, None, vec![sent]).await
+    }
+
+    async fn send_chunks(
+        &mut self,
+        mut message: SendMessage,
+        chunks: Vec<(String, Vec<MessageEntity>)>,
+        reply_markup: Option<ReplyMarkup>,
+        mut sent: Vec<Message>,
+    ) -> Result<Vec<Message>> {
+        let count = chunks.len();
+        for (i, (text, entities)) in chunks.into_iter().enumerate() {
+            message.text = text;
+            message.entities = (!entities.is_empty()).then_some(entities);
+            if let Some(previous) = sent.last() {
+                message.reply_parameters = Some(reply_to(previous));
+            }
+            if i + 1 == count {
+                message.reply_markup = reply_markup.clone();
+            }
+            sent.push(self.send_message(&message).await?);
+        }
+        Ok(sent)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{formatting::RichText, message::MessageEntityKind};
+
+    fn texts(chunks: &[(String, Vec<MessageEntity>)]) -> Vec<&str> {
+        chunks.iter().map(|(text, _)| text.as_str()).collect()
+    }
+
+    #[test]
+    fn split_at_paragraphs_and_words() {
+        let (text, entities) = RichText::new()
+            .text("First paragraph.\n\nSecond ")
+            .bold("bold words")
+            .text(" end")
+            .build();
+        let chunks = split(&text, &entities, 20);
+        assert_eq!(
+            texts(&chunks),
+            ["First paragraph.", "Second bold words", "end"]
+        );
+        assert_eq!(
+            chunks[1].1,
+            [MessageEntity::new(MessageEntityKind::Bold, 7, 10)]
+        );
+        assert_eq!(split("short", &[], 4096).len(), 1);
+    }
+
+    #[test]
+    fn limits_in_utf16() {
+        let chunks = split(
+            "😀😀 😀😀",
+            &[MessageEntity::new(MessageEntityKind::Italic, 5, 4)],
+            4,
+        );
+        assert_eq!(chunks[0], ("😀😀".to_string(), vec![]));
+        assert_eq!(
+            chunks[1],
+            (
+                "😀😀".to_string(),
+                vec![MessageEntity::new(MessageEntityKind::Italic, 0, 4)]
+            )
+        );
+    }
+
+    #[test]
+    fn entity_longer_than_limit() {
+        let (text, entities) = RichText::new().code("abcdefghij").build();
+        let chunks = split(&text, &entities, 4);
+        assert_eq!(texts(&chunks), ["abcd", "efgh", "ij"]);
+        assert_eq!(
+            chunks[2].1,
+            [MessageEntity::new(MessageEntityKind::Code, 0, 2)]
+        );
+    }
+
+    #[test]
+    fn pre_longer_than_limit_split_at_lines() {
+        let code: String = (0..500).map(|i| format!("let x{i} = {i};\n")).collect();
+        let (text, entities) = RichText::new()
+            .text("Code:\n")
+            .pre(&code, Some("rust"))
+            .build();
+        let chunks = split(&text, &entities, MESSAGE_TEXT_LIMIT);
+        assert_eq!(texts(&chunks)[0], "Code:");
+        for (text, entities) in &chunks[1..] {
+            assert!(text.encode_utf16().count() as Integer <= MESSAGE_TEXT_LIMIT);
+            assert!(text.trim_end().ends_with(';'), "{text:?} is cut mid-line");
+            assert!(matches!(entities[0].kind, MessageEntityKind::Pre { .. }));
+        }
+        assert_eq!(chunks.len(), 3);
+    }
+
+    #[test]
+    fn split_long_caption() {
+        let caption = "word ".repeat(300);
+        let chunks = split_caption(&caption, &[]);
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(chunks[0].0, caption[..1024]);
+        assert_eq!(chunks[1].0, caption[1025..]);
+    }
+}
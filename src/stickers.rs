@@ -2,19 +2,41 @@ use serde::{Deserialize, Serialize};
 
 use crate::{
     files::{File, PhotoSize},
+    ids::FileId,
     types::{Boolean, Float, InputFileString, Integer},
 };
 
+string_enum! {
+    /// Type of a sticker or of the stickers in a set
+    pub enum StickerType {
+        Regular = "regular",
+        Mask = "mask",
+        CustomEmoji = "custom_emoji",
+    }
+}
+
+string_enum! {
+    /// Format of a sticker file
+    pub enum StickerFormat {
+        /// .WEBP or .PNG image
+        Static = "static",
+        /// .TGS animation
+        Animated = "animated",
+        /// WEBM video
+        Video = "video",
+    }
+}
+
 /// This object represents a sticker.
 #[derive(Clone, Serialize, Deserialize, Debug)]
 pub struct Sticker {
     /// Identifier for this file, which can be used to download or reuse the file
-    pub file_id: String,
+    pub file_id: FileId,
     /// Unique identifier for this file, which is supposed to be the same over time and for different bots. Can't be used to download or reuse the file.
     pub file_unique_id: String,
     /// Type of the sticker, currently one of “regular”, “mask”, “custom_emoji”. The type of the sticker is independent from its format, which is determined by the fields is_animated and is_video.
     #[serde(rename = "type")]
-    pub kind: String,
+    pub kind: StickerType,
     /// Sticker width
     pub width: Integer,
     /// Sticker height
@@ -54,7 +76,7 @@ pub struct StickerSet {
     /// Sticker set title
     pub title: String,
     /// Type of stickers in the set, currently one of “regular”, “mask”, “custom_emoji”
-    pub sticker_type: String,
+    pub sticker_type: StickerType,
     /// List of all set stickers
     pub stickers: Vec<Sticker>,
     /// Optional. Sticker set thumbnail in the .WEBP, .TGS, or .WEBM format
@@ -81,7 +103,7 @@ pub struct InputSticker {
     /// The added sticker. Pass a file_id as a String to send a file that already exists on the Telegram servers, pass an HTTP URL as a String for Telegram to get a file from the Internet, upload a new one using multipart/form-data, or pass “attach://<file_attach_name>” to upload a new one using multipart/form-data under <file_attach_name> name. Animated and video stickers can't be uploaded via HTTP URL. More information on Sending Files »
     pub sticker: InputFileString,
     /// Format of the added sticker, must be one of “static” for a .WEBP or .PNG image, “animated” for a .TGS animation, “video” for a WEBM video
-    pub format: String,
+    pub format: StickerFormat,
     /// List of 1-20 emoji associated with the sticker
     pub emoji_list: Vec<String>,
     /// Optional. Position where the mask should be placed on faces. For “mask” stickers only.
@@ -91,3 +113,14 @@ pub struct InputSticker {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub keywords: Option<Vec<String>>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sticker_enums_from_strings() {
+        assert_eq!(StickerType::from("custom_emoji"), StickerType::CustomEmoji);
+        assert_eq!(StickerFormat::Video.to_string(), "video");
+    }
+}
@@ -0,0 +1,88 @@
+use std::{
+    collections::VecDeque,
+    sync::{Arc, Mutex},
+};
+
+use serde_json::{json, Value};
+
+use crate::{
+    bot::Bot,
+    error::{Error, Result},
+    types::{Integer, Update},
+};
+
+/// Answers of the Bot API to a test bot, see [`mock_bot`]
+#[derive(Debug, Default)]
+pub(crate) struct Mock {
+    answers: Mutex<VecDeque<Result<Value>>>,
+    requests: Mutex<Vec<(&'static str, Value)>>,
+}
+
+impl Mock {
+    /// Records the request and returns the next answer
+    pub(crate) fn respond(&self, method: &'static str, values: &str) -> Result<Value> {
+        let params = serde_json::from_str(values).unwrap();
+        self.requests.lock().unwrap().push((method, params));
+        let answer = self.answers.lock().unwrap().pop_front();
+        answer.unwrap_or_else(|| panic!("no answer left for {method}"))
+    }
+
+    /// The methods and parameters of the requests sent so far
+    pub(crate) fn requests(&self) -> Vec<(&'static str, Value)> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+/// A bot which gets the answers in order instead of sending requests
+pub(crate) fn mock_bot(answers: impl IntoIterator<Item = Result<Value>>) -> (Bot, Arc<Mock>) {
+    let mock = Arc::new(Mock {
+        answers: Mutex::new(answers.into_iter().collect()),
+        requests: Mutex::default(),
+    });
+    let mut bot = Bot::new("123:abc").unwrap();
+    bot.mock = Some(mock.clone());
+    (bot, mock)
+}
+
+/// The error the Bot API returns when flood control is exceeded
+pub(crate) fn flood(retry_after: Integer) -> Error {
+    Error::Api {
+        code: 429,
+        description: format!("Too Many Requests: retry after {retry_after}"),
+        parameters: serde_json::from_value(json!({ "retry_after": retry_after })).unwrap(),
+    }
+}
+
+/// A private text message update, its message id is the update id
+pub(crate) fn message_update(update_id: Integer, chat_id: Integer, text: &str) -> Update {
+    serde_json::from_value(json!({
+        "update_id": update_id,
+        "message": {
+            "message_id": update_id,
+            "date": 0,
+            "chat": {"id": chat_id, "type": "private"},
+            "from": {"id": chat_id, "is_bot": false, "first_name": "A"},
+            "text": text
+        }
+    }))
+    .unwrap()
+}
+
+/// A photo message update that is a part of the media group `group`
+pub(crate) fn album_part(
+    message_id: Integer,
+    chat_id: Integer,
+    group: &str,
+    caption: Option<&str>,
+) -> Update {
+    let mut update = message_update(message_id, chat_id, "");
+    let mut message = serde_json::to_value(update.message.take().unwrap()).unwrap();
+    message.as_object_mut().unwrap().remove("text");
+    message["media_group_id"] = json!(group);
+    message["photo"] = json!([{"file_id": "f", "file_unique_id": "u", "width": 90, "height": 90}]);
+    if let Some(caption) = caption {
+        message["caption"] = json!(caption);
+    }
+    update.message = Some(serde_json::from_value(message).unwrap());
+    update
+}
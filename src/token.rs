@@ -0,0 +1,168 @@
+use std::{fmt, io, str::FromStr};
+
+use serde::de::Error as _;
+
+use crate::{
+    error::{Error, Result},
+    formatting::ParseError,
+    types::Integer,
+};
+
+const REDACTED: &str = "[REDACTED]";
+
+/// Bot API token in the `<id>:<secret>` format. The secret part is never printed by `Debug` or `Display`.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Token {
+    token: String,
+    id: Integer,
+}
+
+impl Token {
+    pub fn new(token: &str) -> Result<Self> {
+        let (id, secret) = token
+            .split_once(':')
+            .ok_or_else(|| Error::InvalidToken("missing ':' separator".to_string()))?;
+        if id.is_empty() || !id.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(Error::InvalidToken("bot id must be numeric".to_string()));
+        }
+        let id = id
+            .parse::<Integer>()
+            .ok()
+            .filter(|id| *id > 0)
+            .ok_or_else(|| Error::InvalidToken("bot id out of range".to_string()))?;
+        if secret.is_empty() {
+            return Err(Error::InvalidToken("empty secret".to_string()));
+        }
+        if !secret
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || b == b'_' || b == b'-')
+        {
+            return Err(Error::InvalidToken(
+                "secret contains invalid characters".to_string(),
+            ));
+        }
+        Ok(Token {
+            token: token.to_string(),
+            id,
+        })
+    }
+
+    /// Identifier of the bot, the numeric part of the token
+    pub fn id(&self) -> Integer {
+        self.id
+    }
+
+    /// Full token, as sent to the Bot API. Avoid logging the returned value.
+    pub fn expose_secret(&self) -> &str {
+        &self.token
+    }
+
+    fn secret(&self) -> &str {
+        self.token.split_once(':').map_or("", |(_, secret)| secret)
+    }
+
+    /// Replaces every occurrence of the token (or of its secret part) in `text` with a redacted form.
+    pub fn scrub(&self, text: &str) -> String {
+        text.replace(&self.token, &self.to_string())
+            .replace(self.secret(), REDACTED)
+    }
+
+    fn contains(&self, text: &str) -> bool {
+        text.contains(self.secret())
+    }
+
+    /// Removes the token from the message of `err`, keeping the error variant where possible.
+    pub fn scrub_error(&self, err: Error) -> Error {
+        if !self.contains(&err.to_string()) {
+            return err;
+        }
+        match err {
+            Error::Io(e) => Error::Io(self.scrub_io(e)),
+            Error::Json(e) => Error::Json(serde_json::Error::custom(self.scrub(&e.to_string()))),
+            Error::Parameters(s) => Error::Parameters(self.scrub(&s)),
+            Error::Description(s) => Error::Description(self.scrub(&s)),
+            Error::Api {
+                code,
+                description,
+                parameters,
+            } => Error::Api {
+                code,
+                description: self.scrub(&description),
+                parameters,
+            },
+            Error::InvalidToken(s) => Error::InvalidToken(self.scrub(&s)),
+            Error::Invalid { field, reason } => Error::Invalid {
+                field: self.scrub(&field),
+                reason: self.scrub(&reason),
+            },
+            Error::Update { update_id, source } => Error::Update {
+                update_id,
+                source: serde_json::Error::custom(self.scrub(&source.to_string())),
+            },
+            Error::Markup(e) => Error::Markup(ParseError {
+                offset: e.offset,
+                message: self.scrub(&e.message),
+            }),
+            Error::NetC(e) => self.scrub_netc(e),
+            Error::Redacted(s) => Error::Redacted(self.scrub(&s)),
+            err @ (Error::NoResult | Error::NoParameters | Error::NoDescription) => err,
+        }
+    }
+
+    fn scrub_io(&self, err: io::Error) -> io::Error {
+        io::Error::new(err.kind(), self.scrub(&err.to_string()))
+    }
+
+    /// Errors that can't be rebuilt with a scrubbed message become `Error::Redacted`
+    fn scrub_netc(&self, err: netc::error::Error) -> Error {
+        use netc::error::Error as NetC;
+
+        Error::NetC(match err {
+            NetC::UnsupportedScheme(s) => NetC::UnsupportedScheme(self.scrub(&s)),
+            NetC::UnsupportedVersion(s) => NetC::UnsupportedVersion(self.scrub(&s)),
+            NetC::UnsupportedProxyScheme(s) => NetC::UnsupportedProxyScheme(self.scrub(&s)),
+            NetC::InvalidDnsNameError(s) => NetC::InvalidDnsNameError(self.scrub(&s)),
+            NetC::UntoUri(s) => NetC::UntoUri(self.scrub(&s)),
+            NetC::Io(e) => NetC::Io(self.scrub_io(e)),
+            err => return Error::Redacted(self.scrub(&Error::NetC(err).to_string())),
+        })
+    }
+}
+
+impl fmt::Debug for Token {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Token")
+            .field(&format_args!("{self}"))
+            .finish()
+    }
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.id, REDACTED)
+    }
+}
+
+impl FromStr for Token {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Token::new(s)
+    }
+}
+
+impl TryFrom<&str> for Token {
+    type Error = Error;
+
+    fn try_from(s: &str) -> Result<Self> {
+        Token::new(s)
+    }
+}
+
+impl TryFrom<String> for Token {
+    type Error = Error;
+
+    fn try_from(s: String) -> Result<Self> {
+        Token::new(&s)
+    }
+}
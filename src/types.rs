@@ -5,16 +5,18 @@ use crate::{
     },
     chat_boost::{ChatBoostRemoved, ChatBoostUpdated},
     files::{File, PhotoSize},
+    ids::{ChatId, FileId, UserId},
     inline_mode::{ChosenInlineResult, InlineQuery},
     keyboard_button::{InlineKeyboardButton, KeyboardButton},
     message::{MaybeInaccessibleMessage, Message},
     payments::{PreCheckoutQuery, ShippingQuery},
     poll::{Poll, PollAnswer},
     reactions::{MessageReactionCountUpdated, MessageReactionUpdated, ReactionType},
+    update::AllowedUpdate,
 };
 
 use serde::{Deserialize, Serialize};
-use serde_json::Value;
+use serde_json::{Map, Value};
 
 pub type Integer = i64;
 pub type Float = f64;
@@ -51,13 +53,38 @@ impl From<i32> for ChatID {
     }
 }
 
-/// A JSON-serialized object
+/// A JSON-serialized object for an inline keyboard, custom reply keyboard, instructions to remove reply keyboard or to force a reply from the user
 #[derive(Clone, Serialize, Deserialize, Debug)]
+#[serde(untagged)]
 pub enum ReplyMarkup {
-    InlineKeyboardMarkup,
-    PubReplyKeyboardMarkup,
-    ReplyKeyboardRemove,
-    ForceReply,
+    InlineKeyboardMarkup(InlineKeyboardMarkup),
+    ReplyKeyboardMarkup(ReplyKeyboardMarkup),
+    ReplyKeyboardRemove(ReplyKeyboardRemove),
+    ForceReply(ForceReply),
+}
+
+impl From<InlineKeyboardMarkup> for ReplyMarkup {
+    fn from(markup: InlineKeyboardMarkup) -> Self {
+        ReplyMarkup::InlineKeyboardMarkup(markup)
+    }
+}
+
+impl From<ReplyKeyboardMarkup> for ReplyMarkup {
+    fn from(markup: ReplyKeyboardMarkup) -> Self {
+        ReplyMarkup::ReplyKeyboardMarkup(markup)
+    }
+}
+
+impl From<ReplyKeyboardRemove> for ReplyMarkup {
+    fn from(markup: ReplyKeyboardRemove) -> Self {
+        ReplyMarkup::ReplyKeyboardRemove(markup)
+    }
+}
+
+impl From<ForceReply> for ReplyMarkup {
+    fn from(markup: ForceReply) -> Self {
+        ReplyMarkup::ForceReply(markup)
+    }
 }
 
 #[derive(Clone, Serialize, Deserialize, Debug)]
@@ -104,48 +131,6 @@ pub struct InputFile {
     pub source: Vec<u8>,
 }
 
-/// This object describes the paid media to be sent. Currently, it can be one of
-#[derive(Clone, Serialize, Deserialize, Debug)]
-pub enum InputPaidMedia {
-    InputPaidMediaPhoto,
-    InputPaidMediaVideo,
-}
-
-/// The paid media to send is a photo.
-#[derive(Clone, Serialize, Deserialize, Debug)]
-pub struct InputPaidMediaPhoto {
-    /// Type of the media, must be photo
-    #[serde(rename = "type")]
-    pub kind: String,
-    /// File to send. Pass a file_id to send a file that exists on the Telegram servers (recommended), pass an HTTP URL for Telegram to get a file from the Internet, or pass “attach://<file_attach_name>” to upload a new one using multipart/form-data under <file_attach_name> name. More information on Sending Files »
-    pub media: String,
-}
-
-/// The paid media to send is a video.
-#[derive(Clone, Serialize, Deserialize, Debug)]
-pub struct InputPaidMediaVideo {
-    /// Type of the media, must be video
-    #[serde(rename = "type")]
-    pub kind: String,
-    /// File to send. Pass a file_id to send a file that exists on the Telegram servers (recommended), pass an HTTP URL for Telegram to get a file from the Internet, or pass “attach://<file_attach_name>” to upload a new one using multipart/form-data under <file_attach_name> name. More information on Sending Files »
-    pub media: String,
-    /// Optional. Thumbnail of the file sent; can be ignored if thumbnail generation for the file is supported server-side. The thumbnail should be in JPEG format and less than 200 kB in size. A thumbnail's width and height should not exceed 320. Ignored if the file is not uploaded using multipart/form-data. Thumbnails can't be reused and can be only uploaded as a new file, so you can pass “attach://<file_attach_name>” if the thumbnail was uploaded using multipart/form-data under <file_attach_name>. More information on Sending Files »
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub thumbnail: Option<InputFileString>,
-    /// Optional. Video width
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub width: Option<Integer>,
-    /// Optional. Video height
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub height: Option<Integer>,
-    /// Optional. Video duration in seconds
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub duration: Option<Integer>,
-    ///Optional. Pass True if the uploaded video is suitable for streaming
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub supports_streaming: Option<Boolean>,
-}
-
 #[derive(Clone, Serialize, Deserialize, Debug)]
 pub struct Response {
     pub ok: Boolean,
@@ -160,7 +145,7 @@ pub struct Response {
 }
 
 /// Update This object represents an incoming update. At most one of the optional parameters can be present in any given update.
-#[derive(Clone, Serialize, Deserialize, Debug)]
+#[derive(Clone, Serialize, Deserialize, Debug, Default)]
 pub struct Update {
     /// The update's unique identifier. Update identifiers start from a certain positive number and increase sequentially. This ID becomes especially handy if you're using Webhooks, since it allows you to ignore repeated updates or to restore the correct update sequence, should they get out of order. If there are no new updates for at least a week, then identifier of the next update will be chosen randomly instead of sequentially.
     pub update_id: Integer,
@@ -230,6 +215,9 @@ pub struct Update {
     /// Optional. A boost was removed from a chat. The bot must be an administrator in the chat to receive these updates.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub removed_chat_boost: Option<ChatBoostRemoved>,
+    /// Fields of update kinds not known to this version of the library
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
 }
 
 /// Contains information about the current status of a webhook.
@@ -258,14 +246,14 @@ pub struct WebhookInfo {
     pub max_connections: Option<Integer>,
     /// Optional. update types the bot is subscribed to. Defaults to all update types except chat_member
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub allowed_updates: Option<Vec<String>>,
+    pub allowed_updates: Option<Vec<AllowedUpdate>>,
 }
 
 /// This object represents a Telegram user or bot.
-#[derive(Clone, Serialize, Deserialize, Debug)]
+#[derive(Clone, Serialize, Deserialize, Debug, Default, PartialEq, Eq)]
 pub struct User {
     /// Unique identifier for this user or bot. This number may have more than 32 significant bits and some programming languages may have difficulty/silent defects in interpreting it. But it has at most 52 significant bits, so a 64-bit integer or double-precision float type are safe for storing this identifier.
-    pub id: Integer,
+    pub id: UserId,
     /// True, if this user is a bot
     pub is_bot: Boolean,
     /// User's or bot's first name
@@ -302,14 +290,24 @@ pub struct User {
     pub has_main_web_app: Option<Boolean>,
 }
 
+string_enum! {
+    /// Type of a chat
+    pub enum ChatType {
+        Private = "private",
+        Group = "group",
+        Supergroup = "supergroup",
+        Channel = "channel",
+    }
+}
+
 /// This object represents a chat.
 #[derive(Clone, Serialize, Deserialize, Debug)]
 pub struct Chat {
     /// Unique identifier for this chat. This number may have more than 32 significant bits and some programming languages may have difficulty/silent defects in interpreting it. But it has at most 52 significant bits, so a signed 64-bit integer or double-precision float type are safe for storing this identifier.
-    pub id: Integer,
+    pub id: ChatId,
     /// Type of the chat, can be either “private”, “group”, “supergroup” or “channel”
     #[serde(rename = "type")]
-    pub kind: String,
+    pub kind: ChatType,
     /// Optional. Title, for supergroups, channels and group chats
     #[serde(skip_serializing_if = "Option::is_none")]
     pub title: Option<String>,
@@ -327,14 +325,37 @@ pub struct Chat {
     pub is_forum: Option<Boolean>,
 }
 
+impl Chat {
+    pub fn is_private(&self) -> bool {
+        self.kind == ChatType::Private
+    }
+
+    pub fn is_group(&self) -> bool {
+        self.kind == ChatType::Group
+    }
+
+    pub fn is_supergroup(&self) -> bool {
+        self.kind == ChatType::Supergroup
+    }
+
+    pub fn is_channel(&self) -> bool {
+        self.kind == ChatType::Channel
+    }
+
+    /// True for supergroups with topics enabled
+    pub fn is_forum(&self) -> bool {
+        self.is_forum == Some(true)
+    }
+}
+
 /// This object contains full information about a chat.
 #[derive(Clone, Serialize, Deserialize, Debug)]
 pub struct ChatFullInfo {
     /// Unique identifier for this chat. This number may have more than 32 significant bits and some programming languages may have difficulty/silent defects in interpreting it. But it has at most 52 significant bits, so a signed 64-bit integer or double-precision float type are safe for storing this identifier.
-    pub id: Integer,
+    pub id: ChatId,
     /// Type of the chat, can be either “private”, “group”, “supergroup” or “channel”
     #[serde(rename = "type")]
-    pub kind: String,
+    pub kind: ChatType,
     /// Optional. Title, for supergroups, channels and group chats
     #[serde(skip_serializing_if = "Option::is_none")]
     pub title: Option<String>,
@@ -455,7 +476,7 @@ pub struct ChatFullInfo {
     pub custom_emoji_sticker_set_name: Option<String>,
     /// Optional. Unique identifier for the linked chat, i.e. the discussion group identifier for a channel and vice versa; for supergroups and channel chats. This identifier may be greater than 32 bits and some programming languages may have difficulty/silent defects in interpreting it. But it is smaller than 52 bits, so a signed 64 bit integer or double-precision float type are safe for storing this identifier.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub linked_chat_id: Option<Integer>,
+    pub linked_chat_id: Option<ChatId>,
     /// Optional. For supergroups, the location to which the supergroup is connected
     #[serde(skip_serializing_if = "Option::is_none")]
     pub location: Option<ChatLocation>,
@@ -478,16 +499,34 @@ pub struct Contact {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub last_name: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub user_id: Option<Integer>,
+    pub user_id: Option<UserId>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub vcard: Option<String>,
 }
 
+string_enum! {
+    /// Emoji on which a dice throw animation is based
+    pub enum DiceEmoji {
+        /// 🎲, values 1-6
+        Dice = "🎲",
+        /// 🎯, values 1-6
+        Darts = "🎯",
+        /// 🏀, values 1-5
+        Basketball = "🏀",
+        /// ⚽, values 1-5
+        Football = "⚽",
+        /// 🎳, values 1-6
+        Bowling = "🎳",
+        /// 🎰, values 1-64
+        SlotMachine = "🎰",
+    }
+}
+
 /// This object represents an animated emoji that displays a random value.
 #[derive(Clone, Serialize, Deserialize, Debug)]
 pub struct Dice {
     /// Emoji on which the dice throw animation is based
-    pub emoji: String,
+    pub emoji: DiceEmoji,
     /// Value of the dice, 1-6 for “🎲”, “🎯” and “🎳” base emoji, 1-5 for “🏀” and “⚽” base emoji, 1-64 for “🎰” base emoji
     pub value: Integer,
 }
@@ -568,7 +607,7 @@ pub struct ChatBoostAdded {
 #[derive(Clone, Serialize, Deserialize, Debug)]
 pub struct SharedUser {
     /// Identifier of the shared user. This number may have more than 32 significant bits and some programming languages may have difficulty/silent defects in interpreting it. But it has at most 52 significant bits, so 64-bit integers or double-precision float types are safe for storing these identifiers. The bot may not have access to the user and could be unable to use this identifier, unless the user is already known to the bot by some other means.
-    pub user_id: Integer,
+    pub user_id: UserId,
     /// Optional. First name of the user, if the name was requested by the bot
     #[serde(skip_serializing_if = "Option::is_none")]
     pub first_name: Option<String>,
@@ -598,7 +637,7 @@ pub struct ChatShared {
     /// Identifier of the request
     pub request_id: Integer,
     /// Identifier of the shared chat. This number may have more than 32 significant bits and some programming languages may have difficulty/silent defects in interpreting it. But it has at most 52 significant bits, so a 64-bit integer or double-precision float type are safe for storing this identifier. The bot may not have access to the chat and could be unable to use this identifier, unless the chat is already known to the bot by some other means.
-    pub chat_id: Integer,
+    pub chat_id: ChatId,
     /// Optional. Title of the chat, if the title was requested by the bot.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub title: Option<String>,
@@ -793,11 +832,11 @@ pub struct ForceReply {
 #[derive(Clone, Serialize, Deserialize, Debug)]
 pub struct ChatPhoto {
     /// File identifier of small (160x160) chat photo. This file_id can be used only for photo download and only for as long as the photo is not changed.
-    pub small_file_id: String,
+    pub small_file_id: FileId,
     /// Unique file identifier of small (160x160) chat photo, which is supposed to be the same over time and for different bots. Can't be used to download or reuse the file.
     pub small_file_unique_id: String,
     /// File identifier of big (640x640) chat photo. This file_id can be used only for photo download and only for as long as the photo is not changed.
-    pub big_file_id: String,
+    pub big_file_id: FileId,
     /// Unique file identifier of big (640x640) chat photo, which is supposed to be the same over time and for different bots. Can't be used to download or reuse the file.
     pub big_file_unique_id: String,
 }
@@ -871,151 +910,6 @@ pub struct ChatAdministratorRights {
     pub can_manage_topics: Option<Boolean>,
 }
 
-/// This object contains information about one member of a chat. Currently, the following 6 types of chat members are supported
-#[derive(Clone, Serialize, Deserialize, Debug)]
-pub enum ChatMember {
-    ChatMemberOwner,
-    ChatMemberAdministrator,
-    ChatMemberMember,
-    ChatMemberRestricted,
-    ChatMemberLeft,
-    ChatMemberBanned,
-}
-
-/// Represents a chat member that owns the chat and has all administrator privileges.
-#[derive(Clone, Serialize, Deserialize, Debug)]
-pub struct ChatMemberOwner {
-    /// The member's status in the chat, always “creator”
-    pub status: String,
-    /// Information about the user
-    pub user: User,
-    /// Custom title for this user
-    pub custom_title: String,
-    /// True, if the user's presence in the chat is hidden
-    pub is_anonymous: Boolean,
-}
-
-/// Represents a chat member that has some additional privileges.
-#[derive(Clone, Serialize, Deserialize, Debug)]
-pub struct ChatMemberAdministrator {
-    /// The member's status in the chat, always “administrator”
-    pub status: String,
-    /// Information about the user
-    pub user: User,
-    /// True, if the bot is allowed to edit administrator privileges of that user
-    pub can_be_edited: Boolean,
-    /// True, if the user's presence in the chat is hidden
-    pub is_anonymous: Boolean,
-    /// True, if the administrator can access the chat event log, chat statistics, message statistics in channels, see channel members, see anonymous administrators in supergroups and ignore slow mode. Implied by any other administrator privilege
-    pub can_manage_chat: Boolean,
-    /// True, if the administrator can delete messages of other users
-    pub can_delete_messages: Boolean,
-    /// True, if the administrator can manage video chats
-    pub can_manage_video_chats: Boolean,
-    /// True, if the administrator can restrict, ban or unban chat members
-    pub can_restrict_members: Boolean,
-    /// True, if the administrator can add new administrators with a subset of their own privileges or demote administrators that he has promoted, directly or indirectly (promoted by administrators that were appointed by the user)
-    pub can_promote_members: Boolean,
-    /// True, if the user is allowed to change the chat title, photo and other settings
-    pub can_change_info: Boolean,
-    /// True, if the user is allowed to invite new users to the chat
-    pub can_invite_users: Boolean,
-    /// Optional. True, if the administrator can post in the channel; channels only
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub can_post_messages: Option<Boolean>,
-    /// Optional. he administrator can edit messages of other users and can pin messages; channels only
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub can_edit_messages: Option<Boolean>,
-    /// Optional. True, if the user is allowed to pin messages; groups and supergroups only
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub can_pin_messages: Option<Boolean>,
-    /// Optional. True, if the administrator can post stories in the channel; channels only
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub can_post_stories: Option<Boolean>,
-    /// Optional. True, if the administrator can edit stories posted by other users; channels only
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub can_edit_stories: Option<Boolean>,
-    /// Optional. True, if the administrator can delete stories posted by other users; channels only
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub can_delete_stories: Option<Boolean>,
-    /// Optional. True, if the user is allowed to create, rename, close, and reopen forum topics; supergroups only
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub can_manage_topics: Option<Boolean>,
-    /// Optional. Custom title for this user
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub custom_title: Option<String>,
-}
-
-/// Represents a chat member that has no additional privileges or restrictions.
-#[derive(Clone, Serialize, Deserialize, Debug)]
-pub struct ChatMemberMember {
-    /// The member's status in the chat, always “member”
-    pub status: String,
-    /// Information about the user
-    pub user: User,
-}
-
-/// Represents a chat member that is under certain restrictions in the chat. Supergroups only.
-#[derive(Clone, Serialize, Deserialize, Debug)]
-pub struct ChatMemberRestricted {
-    /// The member's status in the chat, always “restricted”
-    pub status: String,
-    /// Information about the user
-    pub user: User,
-    /// True, if the user is a member of the chat at the moment of the request
-    pub is_member: Boolean,
-    /// True, if the user is allowed to send text messages, contacts, locations and venues
-    pub can_send_messages: Boolean,
-    /// True, if the user is allowed to send audios
-    pub can_send_audios: Boolean,
-    /// True, if the user is allowed to send documents
-    pub can_send_documents: Boolean,
-    /// True, if the user is allowed to send photos
-    pub can_send_photos: Boolean,
-    /// True, if the user is allowed to send videos
-    pub can_send_videos: Boolean,
-    /// True, if the user is allowed to send video notes
-    pub can_send_video_notes: Boolean,
-    /// True, if the user is allowed to send voice notes
-    pub can_send_voice_notes: Boolean,
-    /// True, if the user is allowed to send polls
-    pub can_send_polls: Boolean,
-    /// True, if the user is allowed to send animations, games, stickers and use inline bots
-    pub can_send_other_messages: Boolean,
-    /// True, if the user is allowed to add web page previews to their messages
-    pub can_add_web_page_previews: Boolean,
-    /// True, if the user is allowed to change the chat title, photo and other settings
-    pub can_change_info: Boolean,
-    /// True, if the user is allowed to invite new users to the chat
-    pub can_invite_users: Boolean,
-    /// True, if the user is allowed to pin messages
-    pub can_pin_messages: Boolean,
-    /// True, if the user is allowed to create forum topics
-    pub can_manage_topics: Boolean,
-    /// Date when restrictions will be lifted for this user; unix time. If 0, then the user is restricted forever
-    pub until_date: Integer,
-}
-
-/// Represents a chat member that isn't currently a member of the chat, but may join it themselves.
-#[derive(Clone, Serialize, Deserialize, Debug)]
-pub struct ChatMemberLeft {
-    /// The member's status in the chat, always “left”
-    pub status: String,
-    /// Information about the user
-    pub user: User,
-}
-
-/// Represents a chat member that was banned in the chat and can't return to the chat or view chat messages.
-#[derive(Clone, Serialize, Deserialize, Debug)]
-pub struct ChatMemberBanned {
-    /// The member's status in the chat, always “kicked”
-    pub status: String,
-    /// Information about the user
-    pub user: User,
-    /// Date when restrictions will be lifted for this user; unix time
-    pub until_date: Integer,
-}
-
 /// This object represents changes in the status of a chat member.
 #[derive(Clone, Serialize, Deserialize, Debug)]
 pub struct ChatMemberUpdated {
@@ -1048,7 +942,7 @@ pub struct ChatJoinRequest {
     /// User that sent the join request
     pub from: User,
     /// Identifier of a private chat with the user who sent the join request. This number may have more than 32 significant bits and some programming languages may have difficulty/silent defects in interpreting it. But it has at most 52 significant bits, so a 64-bit integer or double-precision float type are safe for storing this identifier. The bot can use this identifier for 24 hours to send messages until the join request is processed, assuming no other administrator contacted the user.
-    pub user_chat_id: Integer,
+    pub user_chat_id: ChatId,
     /// Date the request was sent in Unix time
     pub date: Integer,
     /// Optional. Bio of the user.
@@ -1162,29 +1056,266 @@ pub struct BotShortDescription {
     pub short_description: String,
 }
 
+/// Contains information about why a request was unsuccessful.
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct ResponseParameters {
+    /// Optional. The group has been migrated to a supergroup with the specified identifier. This number may have more than 32 significant bits and some programming languages may have difficulty/silent defects in interpreting it. But it has at most 52 significant bits, so a signed 64-bit integer or double-precision float type are safe for storing this identifier.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub migrate_to_chat_id: Option<ChatId>,
+    /// Optional. In case of exceeding flood control, the number of seconds left to wait before the request can be repeated
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retry_after: Option<Integer>,
+}
+
+string_enum! {
+    /// Type of action to broadcast with sendChatAction
+    pub enum ChatAction {
+        Typing = "typing",
+        UploadPhoto = "upload_photo",
+        RecordVideo = "record_video",
+        UploadVideo = "upload_video",
+        RecordVoice = "record_voice",
+        UploadVoice = "upload_voice",
+        UploadDocument = "upload_document",
+        ChooseSticker = "choose_sticker",
+        FindLocation = "find_location",
+        RecordVideoNote = "record_video_note",
+        UploadVideoNote = "upload_video_note",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::{bot::SendChatAction, dispatcher::Filter, test_util::message_update};
+
+    #[test]
+    fn chat_type() {
+        let chat: Chat =
+            serde_json::from_value(json!({"id": 1, "type": "supergroup", "is_forum": true}))
+                .unwrap();
+        assert_eq!(chat.kind, ChatType::Supergroup);
+        assert!(chat.is_supergroup() && chat.is_forum() && !chat.is_private());
+        assert!(!Filter::chat_type(ChatType::Channel).matches(&message_update(1, 3, "hi")));
+    }
+
+    #[test]
+    fn unknown_chat_type_is_kept() {
+        // Values added in later API versions are kept
+        let chat: Chat = serde_json::from_value(json!({"id": 1, "type": "secret"})).unwrap();
+        assert_eq!(chat.kind, ChatType::Unknown("secret".to_string()));
+        assert!(!chat.is_forum());
+        assert_eq!(serde_json::to_value(&chat).unwrap()["type"], "secret");
+    }
+
+    #[test]
+    fn chat_action_in_request() {
+        let mut action = SendChatAction::new(ChatID::from(1), ChatAction::UploadVideoNote);
+        action.message_thread_id(Some(2));
+        assert_eq!(
+            action.to_string(),
+            r#"{"chat_id":1,"message_thread_id":2,"action":"upload_video_note"}"#
+        );
+    }
+
+    #[test]
+    fn dice_emoji() {
+        let dice: Dice = serde_json::from_value(json!({"emoji": "🎯", "value": 6})).unwrap();
+        assert_eq!(dice.emoji, DiceEmoji::Darts);
+    }
+
+    #[test]
+    fn chat_member_tagged_by_status() {
+        let user = json!({"id": 1, "is_bot": false, "first_name": "A"});
+        let member: ChatMember =
+            serde_json::from_value(json!({"status": "kicked", "user": user, "until_date": 0}))
+                .unwrap();
+        assert!(matches!(member, ChatMember::ChatMemberBanned(_)));
+        let value = serde_json::to_value(&member).unwrap();
+        assert_eq!(value["status"], "kicked");
+    }
+
+    #[test]
+    fn menu_button_round_trip() {
+        let button: MenuButton = serde_json::from_value(json!({"type": "commands"})).unwrap();
+        assert!(matches!(button, MenuButton::MenuButtonCommands(_)));
+        assert_eq!(
+            serde_json::to_value(&button).unwrap(),
+            json!({"type": "commands"})
+        );
+    }
+}
+
+// The types below are generated from spec/bot_api.json by `cargo run -p rutel_codegen`, edit the spec instead.
+
+/// This object contains information about one member of a chat. Currently, the following 6 types of chat members are supported
+#[derive(Clone, Serialize, Deserialize, Debug)]
+#[serde(tag = "status")]
+pub enum ChatMember {
+    #[serde(rename = "creator")]
+    ChatMemberOwner(ChatMemberOwner),
+    #[serde(rename = "administrator")]
+    ChatMemberAdministrator(ChatMemberAdministrator),
+    #[serde(rename = "member")]
+    ChatMemberMember(ChatMemberMember),
+    #[serde(rename = "restricted")]
+    ChatMemberRestricted(ChatMemberRestricted),
+    #[serde(rename = "left")]
+    ChatMemberLeft(ChatMemberLeft),
+    #[serde(rename = "kicked")]
+    ChatMemberBanned(ChatMemberBanned),
+    /// An object not known to this version of the library, or one that can't be decoded
+    #[serde(untagged)]
+    Unknown(Value),
+}
+
+/// Represents a chat member that owns the chat and has all administrator privileges.
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct ChatMemberOwner {
+    /// Information about the user
+    pub user: User,
+    /// Custom title for this user
+    pub custom_title: String,
+    /// True, if the user's presence in the chat is hidden
+    pub is_anonymous: Boolean,
+}
+
+/// Represents a chat member that has some additional privileges.
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct ChatMemberAdministrator {
+    /// Information about the user
+    pub user: User,
+    /// True, if the bot is allowed to edit administrator privileges of that user
+    pub can_be_edited: Boolean,
+    /// True, if the user's presence in the chat is hidden
+    pub is_anonymous: Boolean,
+    /// True, if the administrator can access the chat event log, chat statistics, message statistics in channels, see channel members, see anonymous administrators in supergroups and ignore slow mode. Implied by any other administrator privilege
+    pub can_manage_chat: Boolean,
+    /// True, if the administrator can delete messages of other users
+    pub can_delete_messages: Boolean,
+    /// True, if the administrator can manage video chats
+    pub can_manage_video_chats: Boolean,
+    /// True, if the administrator can restrict, ban or unban chat members
+    pub can_restrict_members: Boolean,
+    /// True, if the administrator can add new administrators with a subset of their own privileges or demote administrators that he has promoted, directly or indirectly (promoted by administrators that were appointed by the user)
+    pub can_promote_members: Boolean,
+    /// True, if the user is allowed to change the chat title, photo and other settings
+    pub can_change_info: Boolean,
+    /// True, if the user is allowed to invite new users to the chat
+    pub can_invite_users: Boolean,
+    /// Optional. True, if the administrator can post in the channel; channels only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_post_messages: Option<Boolean>,
+    /// Optional. he administrator can edit messages of other users and can pin messages; channels only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_edit_messages: Option<Boolean>,
+    /// Optional. True, if the user is allowed to pin messages; groups and supergroups only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_pin_messages: Option<Boolean>,
+    /// Optional. True, if the administrator can post stories in the channel; channels only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_post_stories: Option<Boolean>,
+    /// Optional. True, if the administrator can edit stories posted by other users; channels only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_edit_stories: Option<Boolean>,
+    /// Optional. True, if the administrator can delete stories posted by other users; channels only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_delete_stories: Option<Boolean>,
+    /// Optional. True, if the user is allowed to create, rename, close, and reopen forum topics; supergroups only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_manage_topics: Option<Boolean>,
+    /// Optional. Custom title for this user
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_title: Option<String>,
+}
+
+/// Represents a chat member that has no additional privileges or restrictions.
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct ChatMemberMember {
+    /// Information about the user
+    pub user: User,
+}
+
+/// Represents a chat member that is under certain restrictions in the chat. Supergroups only.
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct ChatMemberRestricted {
+    /// Information about the user
+    pub user: User,
+    /// True, if the user is a member of the chat at the moment of the request
+    pub is_member: Boolean,
+    /// True, if the user is allowed to send text messages, contacts, locations and venues
+    pub can_send_messages: Boolean,
+    /// True, if the user is allowed to send audios
+    pub can_send_audios: Boolean,
+    /// True, if the user is allowed to send documents
+    pub can_send_documents: Boolean,
+    /// True, if the user is allowed to send photos
+    pub can_send_photos: Boolean,
+    /// True, if the user is allowed to send videos
+    pub can_send_videos: Boolean,
+    /// True, if the user is allowed to send video notes
+    pub can_send_video_notes: Boolean,
+    /// True, if the user is allowed to send voice notes
+    pub can_send_voice_notes: Boolean,
+    /// True, if the user is allowed to send polls
+    pub can_send_polls: Boolean,
+    /// True, if the user is allowed to send animations, games, stickers and use inline bots
+    pub can_send_other_messages: Boolean,
+    /// True, if the user is allowed to add web page previews to their messages
+    pub can_add_web_page_previews: Boolean,
+    /// True, if the user is allowed to change the chat title, photo and other settings
+    pub can_change_info: Boolean,
+    /// True, if the user is allowed to invite new users to the chat
+    pub can_invite_users: Boolean,
+    /// True, if the user is allowed to pin messages
+    pub can_pin_messages: Boolean,
+    /// True, if the user is allowed to create forum topics
+    pub can_manage_topics: Boolean,
+    /// Date when restrictions will be lifted for this user; unix time. If 0, then the user is restricted forever
+    pub until_date: Integer,
+}
+
+/// Represents a chat member that isn't currently a member of the chat, but may join it themselves.
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct ChatMemberLeft {
+    /// Information about the user
+    pub user: User,
+}
+
+/// Represents a chat member that was banned in the chat and can't return to the chat or view chat messages.
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct ChatMemberBanned {
+    /// Information about the user
+    pub user: User,
+    /// Date when restrictions will be lifted for this user; unix time
+    pub until_date: Integer,
+}
+
 /// This object describes the bot's menu button in a private chat. It should be one of
 /// If a menu button other than MenuButtonDefault is set for a private chat, then it is applied in the chat. Otherwise the default menu button is applied. By default, the menu button opens the list of bot commands.
 #[derive(Clone, Serialize, Deserialize, Debug)]
+#[serde(tag = "type")]
 pub enum MenuButton {
+    #[serde(rename = "commands")]
     MenuButtonCommands(MenuButtonCommands),
+    #[serde(rename = "web_app")]
     MenuButtonWebApp(MenuButtonWebApp),
+    #[serde(rename = "default")]
     MenuButtonDefault(MenuButtonDefault),
+    /// An object not known to this version of the library, or one that can't be decoded
+    #[serde(untagged)]
+    Unknown(Value),
 }
 
 /// Represents a menu button, which opens the bot's list of commands.
 #[derive(Clone, Serialize, Deserialize, Debug)]
-pub struct MenuButtonCommands {
-    /// Type of the button, must be commands
-    #[serde(rename = "type")]
-    pub kind: String,
-}
+pub struct MenuButtonCommands {}
 
 /// Represents a menu button, which launches a Web App.
 #[derive(Clone, Serialize, Deserialize, Debug)]
 pub struct MenuButtonWebApp {
-    /// Type of the button, must be web_app
-    #[serde(rename = "type")]
-    pub kind: String,
     /// Text on the button
     pub text: String,
     /// Description of the Web App that will be launched when the user presses the button. The Web App will be able to send an arbitrary message on behalf of the user using the method answerWebAppQuery.
@@ -1193,19 +1324,43 @@ pub struct MenuButtonWebApp {
 
 /// Describes that no specific value for the menu button was set.
 #[derive(Clone, Serialize, Deserialize, Debug)]
-pub struct MenuButtonDefault {
-    /// Type of the button, must be default
-    #[serde(rename = "type")]
-    pub kind: String,
+pub struct MenuButtonDefault {}
+
+/// This object describes the paid media to be sent. Currently, it can be one of
+#[derive(Clone, Serialize, Deserialize, Debug)]
+#[serde(tag = "type")]
+pub enum InputPaidMedia {
+    #[serde(rename = "photo")]
+    InputPaidMediaPhoto(InputPaidMediaPhoto),
+    #[serde(rename = "video")]
+    InputPaidMediaVideo(InputPaidMediaVideo),
 }
 
-/// Contains information about why a request was unsuccessful.
+/// The paid media to send is a photo.
 #[derive(Clone, Serialize, Deserialize, Debug)]
-pub struct ResponseParameters {
-    /// Optional. The group has been migrated to a supergroup with the specified identifier. This number may have more than 32 significant bits and some programming languages may have difficulty/silent defects in interpreting it. But it has at most 52 significant bits, so a signed 64-bit integer or double-precision float type are safe for storing this identifier.
+pub struct InputPaidMediaPhoto {
+    /// File to send. Pass a file_id to send a file that exists on the Telegram servers (recommended), pass an HTTP URL for Telegram to get a file from the Internet, or pass “attach://<file_attach_name>” to upload a new one using multipart/form-data under <file_attach_name> name. More information on Sending Files »
+    pub media: String,
+}
+
+/// The paid media to send is a video.
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct InputPaidMediaVideo {
+    /// File to send. Pass a file_id to send a file that exists on the Telegram servers (recommended), pass an HTTP URL for Telegram to get a file from the Internet, or pass “attach://<file_attach_name>” to upload a new one using multipart/form-data under <file_attach_name> name. More information on Sending Files »
+    pub media: String,
+    /// Optional. Thumbnail of the file sent; can be ignored if thumbnail generation for the file is supported server-side. The thumbnail should be in JPEG format and less than 200 kB in size. A thumbnail's width and height should not exceed 320. Ignored if the file is not uploaded using multipart/form-data. Thumbnails can't be reused and can be only uploaded as a new file, so you can pass “attach://<file_attach_name>” if the thumbnail was uploaded using multipart/form-data under <file_attach_name>. More information on Sending Files »
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub migrate_to_chat_id: Option<Integer>,
-    /// Optional. In case of exceeding flood control, the number of seconds left to wait before the request can be repeated
+    pub thumbnail: Option<InputFileString>,
+    /// Optional. Video width
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub retry_after: Option<Integer>,
+    pub width: Option<Integer>,
+    /// Optional. Video height
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub height: Option<Integer>,
+    /// Optional. Video duration in seconds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<Integer>,
+    /// Optional. Pass True if the uploaded video is suitable for streaming
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub supports_streaming: Option<Boolean>,
 }
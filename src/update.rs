@@ -0,0 +1,410 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::{
+    bot::{Bot, GetUpdates},
+    business::{BusinessConnection, BusinessMessagesDeleted},
+    chat_boost::{ChatBoostRemoved, ChatBoostUpdated},
+    error::{Error, Result},
+    inline_mode::{ChosenInlineResult, InlineQuery},
+    message::{MaybeInaccessibleMessage, Message},
+    payments::{PreCheckoutQuery, ShippingQuery},
+    poll::{Poll, PollAnswer},
+    reactions::{MessageReactionCountUpdated, MessageReactionUpdated},
+    types::{CallbackQuery, Chat, ChatJoinRequest, ChatMemberUpdated, Update, User},
+};
+
+/// Declares the update kinds from one table of `Variant(field: Type)` entries, where `field` is the field of
+/// `Update` carrying the payload and its allowed_updates value.
+macro_rules! update_kinds {
+    ($($variant:ident($field:ident: $ty:ty),)*) => {
+        /// The payload of an Update. Exactly one kind is present in any given update.
+        #[derive(Clone, Debug)]
+        pub enum UpdateKind {
+            $($variant($ty),)*
+            /// An update kind not supported by this version of the library, with all its fields except update_id
+            Unknown(Value),
+        }
+
+        /// The payload of an Update borrowed from it, see [`Update::kind`].
+        #[derive(Clone, Copy, Debug)]
+        pub enum UpdateKindRef<'a> {
+            $($variant(&'a $ty),)*
+            /// An update kind not supported by this version of the library, with all its fields except update_id
+            Unknown(&'a Map<String, Value>),
+        }
+
+        /// The types of updates the bot can subscribe to, used in allowed_updates.
+        #[derive(Clone, Serialize, Deserialize, Debug, PartialEq, Eq, Hash)]
+        #[serde(rename_all = "snake_case")]
+        pub enum AllowedUpdate {
+            $($variant,)*
+            /// An update type not known to this version of the library
+            #[serde(untagged)]
+            Unknown(String),
+        }
+
+        impl AllowedUpdate {
+            /// All update types, including those which are not sent by default (chat_member, message_reaction and message_reaction_count)
+            pub fn all() -> Vec<AllowedUpdate> {
+                vec![$(AllowedUpdate::$variant,)*]
+            }
+        }
+
+        impl UpdateKind {
+            /// The allowed_updates value which enables this kind of update
+            pub fn allowed_update(&self) -> AllowedUpdate {
+                match self {
+                    $(UpdateKind::$variant(_) => AllowedUpdate::$variant,)*
+                    UpdateKind::Unknown(value) => unknown(value.as_object()),
+                }
+            }
+        }
+
+        impl UpdateKindRef<'_> {
+            /// The allowed_updates value which enables this kind of update
+            pub fn allowed_update(&self) -> AllowedUpdate {
+                match self {
+                    $(UpdateKindRef::$variant(_) => AllowedUpdate::$variant,)*
+                    UpdateKindRef::Unknown(fields) => unknown(Some(fields)),
+                }
+            }
+        }
+
+        impl Update {
+            /// Converts the update into its single payload
+            pub fn into_kind(self) -> UpdateKind {
+                $(if let Some(payload) = self.$field {
+                    return UpdateKind::$variant(payload);
+                })*
+                UpdateKind::Unknown(Value::Object(self.extra))
+            }
+
+            /// The payload of the update
+            pub fn kind(&self) -> UpdateKindRef<'_> {
+                $(if let Some(payload) = &self.$field {
+                    return UpdateKindRef::$variant(payload);
+                })*
+                UpdateKindRef::Unknown(&self.extra)
+            }
+        }
+    };
+}
+
+update_kinds! {
+    Message(message: Message),
+    EditedMessage(edited_message: Message),
+    ChannelPost(channel_post: Message),
+    EditedChannelPost(edited_channel_post: Message),
+    BusinessConnection(business_connection: BusinessConnection),
+    BusinessMessage(business_message: Message),
+    EditedBusinessMessage(edited_business_message: Message),
+    DeletedBusinessMessages(deleted_business_messages: BusinessMessagesDeleted),
+    MessageReaction(message_reaction: MessageReactionUpdated),
+    MessageReactionCount(message_reaction_count: MessageReactionCountUpdated),
+    InlineQuery(inline_query: InlineQuery),
+    ChosenInlineResult(chosen_inline_result: ChosenInlineResult),
+    CallbackQuery(callback_query: CallbackQuery),
+    ShippingQuery(shipping_query: ShippingQuery),
+    PreCheckoutQuery(pre_checkout_query: PreCheckoutQuery),
+    Poll(poll: Poll),
+    PollAnswer(poll_answer: PollAnswer),
+    MyChatMember(my_chat_member: ChatMemberUpdated),
+    ChatMember(chat_member: ChatMemberUpdated),
+    ChatJoinRequest(chat_join_request: ChatJoinRequest),
+    ChatBoost(chat_boost: ChatBoostUpdated),
+    RemovedChatBoost(removed_chat_boost: ChatBoostRemoved),
+}
+
+/// The allowed_updates value of an unknown update kind, the name of its field
+fn unknown(fields: Option<&Map<String, Value>>) -> AllowedUpdate {
+    AllowedUpdate::Unknown(
+        fields
+            .and_then(|fields| fields.keys().next().cloned())
+            .unwrap_or_default(),
+    )
+}
+
+impl UpdateKind {
+    /// The message carried by message, channel post and business message updates, including edits
+    pub fn message(&self) -> Option<&Message> {
+        match self {
+            UpdateKind::Message(message)
+            | UpdateKind::EditedMessage(message)
+            | UpdateKind::ChannelPost(message)
+            | UpdateKind::EditedChannelPost(message)
+            | UpdateKind::BusinessMessage(message)
+            | UpdateKind::EditedBusinessMessage(message) => Some(message),
+            _ => None,
+        }
+    }
+}
+
+impl Update {
+    /// The allowed_updates value which enables this kind of update
+    pub fn allowed_update(&self) -> AllowedUpdate {
+        self.kind().allowed_update()
+    }
+
+    /// The message of a message, channel post or business message update, including edits
+    pub fn any_message(&self) -> Option<&Message> {
+        self.message
+            .as_ref()
+            .or(self.edited_message.as_ref())
+            .or(self.channel_post.as_ref())
+            .or(self.edited_channel_post.as_ref())
+            .or(self.business_message.as_ref())
+            .or(self.edited_business_message.as_ref())
+    }
+
+    /// The chat the update came from, if any
+    pub fn chat(&self) -> Option<&Chat> {
+        if let Some(message) = self.any_message() {
+            return Some(&message.chat);
+        }
+        if let Some(query) = &self.callback_query {
+            return query.message.as_ref().map(MaybeInaccessibleMessage::chat);
+        }
+        None.or(self.deleted_business_messages.as_ref().map(|d| &d.chat))
+            .or(self.message_reaction.as_ref().map(|r| &r.chat))
+            .or(self.message_reaction_count.as_ref().map(|r| &r.chat))
+            .or(self
+                .poll_answer
+                .as_ref()
+                .and_then(|a| a.voter_chat.as_ref()))
+            .or(self.my_chat_member.as_ref().map(|m| &m.chat))
+            .or(self.chat_member.as_ref().map(|m| &m.chat))
+            .or(self.chat_join_request.as_ref().map(|r| &r.chat))
+            .or(self.chat_boost.as_ref().map(|b| &b.chat))
+            .or(self.removed_chat_boost.as_ref().map(|b| &b.chat))
+    }
+
+    /// The user who caused the update, if any
+    pub fn user(&self) -> Option<&User> {
+        if let Some(message) = self.any_message() {
+            return message.from.as_ref();
+        }
+        None.or(self.business_connection.as_ref().map(|c| &c.user))
+            .or(self.message_reaction.as_ref().and_then(|r| r.user.as_ref()))
+            .or(self.inline_query.as_ref().map(|q| &q.from))
+            .or(self.chosen_inline_result.as_ref().map(|r| &r.from))
+            .or(self.callback_query.as_ref().map(|q| &q.from))
+            .or(self.shipping_query.as_ref().map(|q| &q.from))
+            .or(self.pre_checkout_query.as_ref().map(|q| &q.from))
+            .or(self.poll_answer.as_ref().and_then(|a| a.user.as_ref()))
+            .or(self.my_chat_member.as_ref().map(|m| &m.from))
+            .or(self.chat_member.as_ref().map(|m| &m.from))
+            .or(self.chat_join_request.as_ref().map(|r| &r.from))
+    }
+}
+
+/// Decodes a batch of updates one by one, so an update that can't be decoded doesn't fail the others.
+///
+/// An update that can't be decoded is returned as `Error::Update` with its id, so the offset of the next getUpdates
+/// call can move past it.
+pub fn decode_updates(batch: Value) -> Result<Vec<Result<Update>>> {
+    let batch: Vec<Value> = serde_json::from_value(batch)?;
+    Ok(batch.into_iter().map(decode_update).collect())
+}
+
+/// Decodes one update, returning `Error::Update` with its id if it can't be decoded
+pub fn decode_update(update: Value) -> Result<Update> {
+    let update_id = update.get("update_id").and_then(Value::as_i64);
+    serde_json::from_value(update).map_err(|source| match update_id {
+        Some(update_id) => Error::Update { update_id, source },
+        None => Error::Json(source),
+    })
+}
+
+impl Bot {
+    /// Like `get_updates`, but decodes each update on its own, see [`decode_updates`]
+    pub async fn get_updates_lenient(&mut self, v: &GetUpdates) -> Result<Vec<Result<Update>>> {
+        let batch = self.send(v).await?;
+        decode_updates(batch)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::{
+        content::MessageContent,
+        ids::{ChatId, MessageId, UserId},
+        message::MessageOrigin,
+        types::ChatMember,
+    };
+
+    fn batch() -> Value {
+        let message = json!({"message_id": 1, "date": 1, "chat": {"id": 5, "type": "private"}});
+        let mut future_message = message.clone();
+        future_message["story_reposted"] = json!({"story_id": 3});
+        json!([
+            {"update_id": 10, "message": message},
+            {"update_id": 11, "message": {"message_id": "one", "date": 1}},
+            {"update_id": 12, "channel_post": future_message},
+            {"update_id": 13, "purchased_stars": {"amount": 5}},
+            {"message": message}
+        ])
+    }
+
+    #[test]
+    fn one_bad_update_fails_the_batch() {
+        assert!(serde_json::from_value::<Vec<Update>>(batch()).is_err());
+    }
+
+    #[test]
+    fn decode_updates_one_by_one() {
+        let updates = decode_updates(batch()).unwrap();
+        assert_eq!(updates.len(), 5);
+        assert_eq!(updates[0].as_ref().unwrap().update_id, 10);
+        let err = updates[1].as_ref().unwrap_err();
+        assert_eq!(err.update_id(), Some(11));
+        assert!(err.to_string().starts_with("can't decode update 11: "));
+        assert!(matches!(updates[4], Err(Error::Json(_))));
+        assert!(decode_updates(json!({"ok": false})).is_err());
+    }
+
+    #[test]
+    fn unknown_fields_are_kept() {
+        let updates = decode_updates(batch()).unwrap();
+        let post = updates[2].as_ref().unwrap().channel_post.as_ref().unwrap();
+        assert!(matches!(post.content(), MessageContent::Unknown));
+        assert_eq!(post.extra["story_reposted"], json!({"story_id": 3}));
+        assert_eq!(
+            serde_json::to_value(post).unwrap()["story_reposted"]["story_id"],
+            3
+        );
+        assert_eq!(
+            updates[3].as_ref().unwrap().extra["purchased_stars"],
+            json!({"amount": 5})
+        );
+    }
+
+    #[test]
+    fn unknown_union_objects_are_kept() {
+        let chat = json!({"id": 5, "type": "private"});
+        let batch = json!([{
+            "update_id": 14,
+            "message": {
+                "message_id": 1, "date": 1, "chat": chat,
+                "forward_origin": {"type": "story", "date": 1, "story_id": 3}
+            }
+        }, {
+            "update_id": 15,
+            "chat_member": {
+                "chat": chat,
+                "from": {"id": 5, "is_bot": false, "first_name": "A"},
+                "date": 1,
+                "old_chat_member": {"status": "left", "user": {"id": 5, "is_bot": false, "first_name": "A"}},
+                "new_chat_member": {"status": "probation", "user": {"id": 5, "is_bot": false, "first_name": "A"}}
+            }
+        }]);
+        let updates: Vec<Update> = serde_json::from_value(batch).unwrap();
+        let Some(MessageOrigin::Unknown(origin)) =
+            &updates[0].message.as_ref().unwrap().forward_origin
+        else {
+            panic!("expected an unknown origin");
+        };
+        assert_eq!(origin["story_id"], 3);
+        let change = updates[1].chat_member.as_ref().unwrap();
+        assert!(
+            matches!(change.new_chat_member, ChatMember::Unknown(ref member) if member["status"] == "probation")
+        );
+        assert_eq!(
+            serde_json::to_value(&change.new_chat_member).unwrap()["status"],
+            "probation"
+        );
+    }
+
+    #[test]
+    fn forwarded_message_update() {
+        let update = json!({
+            "update_id": 873214662,
+            "message": {
+                "message_id": 1543,
+                "from": {
+                    "id": 412843015, "is_bot": false, "first_name": "Anna", "username": "anna_k",
+                    "language_code": "en", "is_premium": true
+                },
+                "chat": {
+                    "id": 412843015, "first_name": "Anna", "username": "anna_k", "type": "private"
+                },
+                "date": 1722240186,
+                "forward_origin": {
+                    "type": "channel",
+                    "chat": {
+                        "id": -1001006503122_i64, "title": "Telegram News", "username": "telegram",
+                        "type": "channel"
+                    },
+                    "message_id": 382,
+                    "date": 1722001505
+                },
+                "forward_from_chat": {
+                    "id": -1001006503122_i64, "title": "Telegram News", "username": "telegram",
+                    "type": "channel"
+                },
+                "forward_from_message_id": 382,
+                "forward_date": 1722001505,
+                "text": "Bot API 7.8",
+                "entities": [{"offset": 0, "length": 7, "type": "bold"}]
+            }
+        });
+        let update = decode_updates(json!([update])).unwrap().remove(0).unwrap();
+        let message = update.message.unwrap();
+        let Some(MessageOrigin::MessageOriginChannel(origin)) = message.forward_origin else {
+            panic!("expected a channel origin");
+        };
+        assert_eq!(origin.chat.id, ChatId(-1001006503122));
+        assert_eq!(origin.message_id, MessageId(382));
+        assert_eq!(origin.date, 1722001505);
+    }
+
+    #[test]
+    fn chat_member_update() {
+        let user =
+            json!({"id": 412843015, "is_bot": false, "first_name": "Anna", "username": "anna_k"});
+        let update = json!({
+            "update_id": 873214663,
+            "chat_member": {
+                "chat": {"id": -1001234567890_i64, "title": "Rust", "username": "rust", "type": "supergroup"},
+                "from": {"id": 100500, "is_bot": false, "first_name": "Admin"},
+                "date": 1722240300,
+                "old_chat_member": {"user": user, "status": "member"},
+                "new_chat_member": {
+                    "user": user,
+                    "status": "restricted",
+                    "until_date": 1722326700,
+                    "is_member": true,
+                    "can_send_messages": false,
+                    "can_send_audios": false,
+                    "can_send_documents": false,
+                    "can_send_photos": false,
+                    "can_send_videos": false,
+                    "can_send_video_notes": false,
+                    "can_send_voice_notes": false,
+                    "can_send_polls": false,
+                    "can_send_other_messages": false,
+                    "can_add_web_page_previews": false,
+                    "can_change_info": false,
+                    "can_invite_users": true,
+                    "can_pin_messages": false,
+                    "can_manage_topics": false
+                }
+            }
+        });
+        let update = decode_updates(json!([update])).unwrap().remove(0).unwrap();
+        assert_eq!(update.allowed_update(), AllowedUpdate::ChatMember);
+        let change = update.chat_member.unwrap();
+        assert!(matches!(
+            change.old_chat_member,
+            ChatMember::ChatMemberMember(_)
+        ));
+        let ChatMember::ChatMemberRestricted(member) = change.new_chat_member else {
+            panic!("expected a restricted member");
+        };
+        assert_eq!(member.user.id, UserId(412843015));
+        assert!(member.can_invite_users && !member.can_send_messages);
+    }
+}
@@ -0,0 +1,337 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    bot::LIMITS,
+    callback_data::CALLBACK_DATA_LIMIT,
+    error::{Error, Result},
+    formatting::{self, ParseMode},
+};
+
+/// A documented limit of a request parameter.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Limit {
+    /// Length of a text in UTF-16 code units after entities parsing, inclusive
+    Length(usize, usize),
+    /// Number of items of an array, inclusive
+    Items(usize, usize),
+    /// Range of an integer, inclusive
+    Range(i64, i64),
+    /// Items of an array must be strictly increasing
+    Increasing,
+    /// Callback data of the buttons of an inline keyboard, 1-64 bytes
+    InlineKeyboard,
+    /// Bot commands: names of 1-32 lowercase letters, digits and underscores, descriptions of 1-256 characters
+    Commands,
+}
+
+/// A request checked against the documented limits of its parameters.
+///
+/// Requests are checked before they are sent, so a request over a limit fails with `Error::Invalid` instead of a
+/// "Bad Request" from the Bot API.
+pub trait Validate: Serialize {
+    /// Name of the Bot API method
+    const METHOD: &'static str;
+
+    fn validate(&self) -> Result<()> {
+        check(Self::METHOD, &serde_json::to_value(self)?)
+    }
+}
+
+fn invalid(field: impl Into<String>, reason: impl Into<String>) -> Error {
+    Error::Invalid {
+        field: field.into(),
+        reason: reason.into(),
+    }
+}
+
+/// Checks the serialized parameters of a method against the limits of the method
+pub fn check(method: &str, params: &Value) -> Result<()> {
+    for (_, field, limit) in LIMITS.iter().filter(|(name, ..)| *name == method) {
+        if let Some(value) = params.get(field) {
+            limit.check(field, value, params)?;
+        }
+    }
+    Ok(())
+}
+
+/// Length of a text as counted by Telegram, after entities parsing if the parse mode of the field is set.
+///
+/// Text the local parser rejects is measured as it is, the Bot API decides whether it is valid.
+fn text_len(field: &str, text: &str, params: &Value) -> usize {
+    let parse_mode_field = match field {
+        "text" | "caption" => "parse_mode".to_string(),
+        _ => format!("{field}_parse_mode"),
+    };
+    let mode = params
+        .get(&parse_mode_field)
+        .and_then(|mode| ParseMode::deserialize(mode).ok());
+    match mode.map(|mode| formatting::parse(text, mode)) {
+        Some(Ok((plain, _))) => plain.encode_utf16().count(),
+        _ => text.encode_utf16().count(),
+    }
+}
+
+impl Limit {
+    fn check(&self, field: &str, value: &Value, params: &Value) -> Result<()> {
+        match *self {
+            Limit::Length(min, max) => {
+                let Some(text) = value.as_str() else {
+                    return Ok(());
+                };
+                let len = text_len(field, text, params);
+                if len < min || len > max {
+                    return Err(invalid(
+                        field,
+                        format!("length is {len}, must be {min}-{max}"),
+                    ));
+                }
+            }
+            Limit::Items(min, max) => {
+                let len = value.as_array().map_or(0, Vec::len);
+                if len < min || len > max {
+                    return Err(invalid(
+                        field,
+                        format!("has {len} items, must have {min}-{max}"),
+                    ));
+                }
+            }
+            Limit::Range(min, max) => {
+                if let Some(n) = value.as_i64().filter(|n| !(min..=max).contains(n)) {
+                    return Err(invalid(field, format!("is {n}, must be {min}-{max}")));
+                }
+            }
+            Limit::Increasing => {
+                let items = value.as_array().map(Vec::as_slice).unwrap_or_default();
+                for (i, pair) in items.windows(2).enumerate() {
+                    if pair[0].as_i64() >= pair[1].as_i64() {
+                        return Err(invalid(
+                            format!("{field}[{}]", i + 1),
+                            "must be greater than the previous item",
+                        ));
+                    }
+                }
+            }
+            Limit::InlineKeyboard => {
+                let rows = value.get("inline_keyboard").and_then(Value::as_array);
+                for (i, row) in rows.into_iter().flatten().enumerate() {
+                    for (j, button) in row.as_array().into_iter().flatten().enumerate() {
+                        let Some(data) = button.get("callback_data").and_then(Value::as_str) else {
+                            continue;
+                        };
+                        if data.is_empty() || data.len() > CALLBACK_DATA_LIMIT {
+                            return Err(invalid(
+                                format!("{field}.inline_keyboard[{i}][{j}].callback_data"),
+                                format!("is {} bytes long, must be 1-64", data.len()),
+                            ));
+                        }
+                    }
+                }
+            }
+            Limit::Commands => {
+                let commands = value.as_array().map(Vec::as_slice).unwrap_or_default();
+                for (i, command) in commands.iter().enumerate() {
+                    let name = command
+                        .get("command")
+                        .and_then(Value::as_str)
+                        .unwrap_or_default();
+                    let valid = name
+                        .chars()
+                        .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_');
+                    if name.is_empty() || name.len() > 32 || !valid {
+                        return Err(invalid(
+                            format!("{field}[{i}].command"),
+                            format!(
+                                "{name:?} must be 1-32 lowercase letters, digits and underscores"
+                            ),
+                        ));
+                    }
+                    let description = command
+                        .get("description")
+                        .and_then(Value::as_str)
+                        .unwrap_or_default()
+                        .chars()
+                        .count();
+                    if description == 0 || description > 256 {
+                        return Err(invalid(
+                            format!("{field}[{i}].description"),
+                            format!("length is {description}, must be 1-256"),
+                        ));
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::{
+        bot::{
+            AnswerCallbackQuery, Bot, ForwardMessages, GetUpdates, SendMessage, SendPoll,
+            SetMyCommands,
+        },
+        bot_command::BotCommand,
+        ids::MessageId,
+        keyboard::{btn, InlineKeyboard},
+        poll::InputPollOption,
+        types::ChatID,
+    };
+
+    fn failed(result: Result<()>) -> String {
+        match result {
+            Err(Error::Invalid { field, reason }) => format!("{field}: {reason}"),
+            other => panic!("expected Error::Invalid, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn text_length() {
+        let mut message = SendMessage::new(ChatID::from(1), "x".repeat(4096));
+        assert!(message.validate().is_ok());
+        message.text("x".repeat(4097));
+        assert_eq!(
+            failed(message.validate()),
+            "text: length is 4097, must be 1-4096"
+        );
+        message.text(String::new());
+        assert_eq!(
+            failed(message.validate()),
+            "text: length is 0, must be 1-4096"
+        );
+    }
+
+    #[test]
+    fn text_measured_after_parsing_in_utf16() {
+        let mut message = SendMessage::new(ChatID::from(1), format!("<b>{}</b>", "x".repeat(4096)));
+        message.parse_mode(Some(ParseMode::HTML));
+        assert!(message.validate().is_ok());
+        message.text("😀".repeat(2049)).parse_mode(None);
+        assert_eq!(
+            failed(message.validate()),
+            "text: length is 4098, must be 1-4096"
+        );
+    }
+
+    #[test]
+    fn callback_data_in_markup() {
+        let mut message = SendMessage::new(ChatID::from(1), "ok".to_string());
+        message.reply_markup(Some(
+            InlineKeyboard::new()
+                .row([btn::callback("A", "a"), btn::callback("B", &"b".repeat(65))])
+                .build()
+                .into(),
+        ));
+        assert_eq!(
+            failed(message.validate()),
+            "reply_markup.inline_keyboard[0][1].callback_data: is 65 bytes long, must be 1-64"
+        );
+    }
+
+    #[test]
+    fn message_id_lists() {
+        let ids = |ids: &[i64]| ids.iter().map(|&id| MessageId(id)).collect();
+        let mut forward = ForwardMessages::new(ChatID::from(1), ChatID::from(2), ids(&[1, 2, 5]));
+        assert!(forward.validate().is_ok());
+        forward.message_ids(ids(&[1, 5, 5]));
+        assert_eq!(
+            failed(forward.validate()),
+            "message_ids[2]: must be greater than the previous item"
+        );
+        forward.message_ids((1..=101).map(MessageId).collect());
+        assert_eq!(
+            failed(forward.validate()),
+            "message_ids: has 101 items, must have 1-100"
+        );
+        forward.message_ids(Vec::new());
+        assert_eq!(
+            failed(forward.validate()),
+            "message_ids: has 0 items, must have 1-100"
+        );
+    }
+
+    #[test]
+    fn poll_options() {
+        let option = |text: &str| InputPollOption {
+            text: text.to_string(),
+            text_parse_mode: None,
+            text_entities: None,
+        };
+        let mut poll = SendPoll::new(ChatID::from(1), "?".to_string(), vec![option("yes")]);
+        assert_eq!(
+            failed(poll.validate()),
+            "options: has 1 items, must have 2-10"
+        );
+        poll.options(vec![option("yes"), option("no")]);
+        assert!(poll.validate().is_ok());
+    }
+
+    #[test]
+    fn integer_range() {
+        let mut updates = GetUpdates::new();
+        updates.limit(Some(0));
+        assert_eq!(failed(updates.validate()), "limit: is 0, must be 1-100");
+    }
+
+    #[test]
+    fn optional_text_length() {
+        let mut answer = AnswerCallbackQuery::new("q".to_string());
+        answer.text(Some("x".repeat(201)));
+        assert_eq!(
+            failed(answer.validate()),
+            "text: length is 201, must be 0-200"
+        );
+    }
+
+    #[test]
+    fn bot_commands() {
+        let command = |command: &str| BotCommand {
+            command: command.to_string(),
+            description: "Starts the bot".to_string(),
+        };
+        let mut commands = SetMyCommands::new(vec![command("start"), command("Help")]);
+        assert_eq!(
+            failed(commands.validate()),
+            r#"commands[1].command: "Help" must be 1-32 lowercase letters, digits and underscores"#
+        );
+        commands.commands((0..101).map(|i| command(&format!("c{i}"))).collect());
+        assert_eq!(
+            failed(commands.validate()),
+            "commands: has 101 items, must have 0-100"
+        );
+    }
+
+    #[test]
+    fn check_by_method_name() {
+        assert_eq!(
+            failed(check(
+                "sendPhoto",
+                &json!({"chat_id": 1, "caption": "x".repeat(1025)})
+            )),
+            "caption: length is 1025, must be 0-1024"
+        );
+        assert!(check("sendPhoto", &json!({"chat_id": 1, "photo": "id"})).is_ok());
+    }
+
+    #[test]
+    fn invalid_error_message() {
+        assert_eq!(
+            invalid("text", "length is 0, must be 1-4096").to_string(),
+            "invalid text: length is 0, must be 1-4096"
+        );
+    }
+
+    #[tokio::test]
+    async fn bot_methods_validate_before_sending() {
+        let mut bot = Bot::new("123:abc").unwrap();
+        let message = SendMessage::new(ChatID::from(1), String::new());
+        assert_eq!(
+            failed(bot.send_message(&message).await.map(drop)),
+            "text: length is 0, must be 1-4096"
+        );
+    }
+}
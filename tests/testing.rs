@@ -1,12 +1,19 @@
-use dotenvy;
+use std::sync::Mutex;
+
 use rutel::bot;
-use rutel::types::{ChatID, InputFileString};
+use rutel::dispatcher::{Dispatcher, Filter};
+use rutel::error::Error;
+use rutel::ids::{ChatId, MessageId, UserId};
+use rutel::token::Token;
+use rutel::types::{ChatID, DiceEmoji, InputFileString, Update};
+use rutel::update::{AllowedUpdate, UpdateKind, UpdateKindRef};
+use serde_json::json;
 
 fn get_tt() -> Option<(bot::Bot, String)> {
     if let (Ok(token), Ok(target)) = (dotenvy::var("TG_TOKEN"), dotenvy::var("TARGET")) {
-        return Some((bot::Bot::new(&token), target));
+        Some((bot::Bot::new(&token).unwrap(), target))
     } else {
-        return None;
+        None
     }
 }
 
@@ -18,6 +25,20 @@ async fn test_get_me() {
     }
 }
 
+fn message_update(update_id: i64, chat_id: i64, text: &str) -> Update {
+    serde_json::from_value(json!({
+        "update_id": update_id,
+        "message": {
+            "message_id": update_id,
+            "date": 0,
+            "chat": {"id": chat_id, "type": "private"},
+            "from": {"id": chat_id, "is_bot": false, "first_name": "A"},
+            "text": text
+        }
+    }))
+    .unwrap()
+}
+
 #[test]
 fn chat_id() {
     let i: ChatID = ChatID::from(-1001102759484i64);
@@ -28,6 +49,144 @@ fn chat_id() {
     assert_eq!(c, -1001102759484i64);
 }
 
+#[test]
+fn token_redacted() {
+    let secret = "AAHdqTcvCH1vGWJxfSeofSAs0K5PALDsaw";
+    let token = Token::new(&format!("123456789:{secret}")).unwrap();
+    assert_eq!(token.id(), 123456789);
+    assert!(!format!("{token:?}").contains(secret));
+    assert!(!token.to_string().contains(secret));
+
+    let bot = bot::Bot::new(token.expose_secret()).unwrap();
+    assert!(!format!("{bot:?}").contains(secret));
+
+    let err = token.scrub_error(Error::Description(bot.build_uri("getMe")));
+    assert!(!err.to_string().contains(secret));
+    assert!(err.to_string().contains("123456789"));
+
+    let err = token.scrub_error(Error::NetC(netc::error::Error::UnsupportedScheme(
+        bot.build_uri("getMe"),
+    )));
+    assert!(matches!(
+        err,
+        Error::NetC(netc::error::Error::UnsupportedScheme(_))
+    ));
+    assert!(!err.to_string().contains(secret));
+    let err = token.scrub_error(Error::Invalid {
+        field: "url".to_string(),
+        reason: bot.build_uri("getMe"),
+    });
+    assert!(matches!(err, Error::Invalid { .. }));
+    assert!(!err.to_string().contains(secret));
+
+    assert!(Token::new("123456789").is_err());
+    assert!(Token::new("bot:secret").is_err());
+    assert!(Token::new("123456789:").is_err());
+    assert!(Token::new("123456789:se/cret").is_err());
+}
+
+#[test]
+fn update_kind() {
+    let update: Update = serde_json::from_value(json!({
+        "update_id": 1,
+        "message": {
+            "message_id": 2,
+            "date": 0,
+            "chat": {"id": 3, "type": "private"},
+            "from": {"id": 4, "is_bot": false, "first_name": "A"},
+            "text": "hi"
+        }
+    }))
+    .unwrap();
+    assert_eq!(update.chat().map(|c| c.id), Some(ChatId(3)));
+    assert_eq!(update.user().map(|u| u.id), Some(UserId(4)));
+    let kind = update.kind();
+    assert_eq!(kind.allowed_update(), AllowedUpdate::Message);
+    assert!(matches!(kind, UpdateKindRef::Message(m) if m.text.as_deref() == Some("hi")));
+
+    let update: Update =
+        serde_json::from_value(json!({"update_id": 5, "purchased_stars": {"amount": 1}})).unwrap();
+    let kind = update.into_kind();
+    assert_eq!(
+        kind.allowed_update(),
+        AllowedUpdate::Unknown("purchased_stars".to_string())
+    );
+    assert!(matches!(kind, UpdateKind::Unknown(v) if v["purchased_stars"]["amount"] == 1));
+}
+
+#[test]
+fn allowed_updates() {
+    let allowed = vec![
+        AllowedUpdate::Message,
+        AllowedUpdate::CallbackQuery,
+        AllowedUpdate::Unknown("purchased_stars".to_string()),
+    ];
+    let value = serde_json::to_value(&allowed).unwrap();
+    assert_eq!(
+        value,
+        json!(["message", "callback_query", "purchased_stars"])
+    );
+    let parsed: Vec<AllowedUpdate> = serde_json::from_value(value).unwrap();
+    assert_eq!(parsed, allowed);
+    assert_eq!(AllowedUpdate::all().len(), 22);
+
+    let mut get_updates = bot::GetUpdates::new();
+    get_updates.allowed_updates(Some(vec![AllowedUpdate::ChatMember]));
+    assert_eq!(
+        get_updates.to_string(),
+        r#"{"allowed_updates":["chat_member"]}"#
+    );
+}
+
+#[tokio::test]
+async fn dispatcher_routing() {
+    let bot = bot::Bot::new("123:abc").unwrap();
+    let mut dispatcher = Dispatcher::new(bot, Mutex::new(Vec::new()));
+    dispatcher
+        .concurrency(4)
+        .on_message(
+            Filter::command_for("start", "my_bot"),
+            |cx, msg| async move {
+                cx.state
+                    .lock()
+                    .unwrap()
+                    .push(format!("start {}", msg.message_id));
+                Ok(())
+            },
+        )
+        .on_message(
+            Filter::text(|t| t.starts_with("echo")),
+            |cx, msg| async move {
+                tokio::time::sleep(std::time::Duration::from_millis(
+                    10 - msg.message_id.0 as u64,
+                ))
+                .await;
+                let text = msg.text.unwrap_or_default();
+                cx.state.lock().unwrap().push(text);
+                Ok(())
+            },
+        );
+
+    let handles: Vec<_> = [
+        (1, "/start"),
+        (2, "/start@other_bot"),
+        (3, "echo 1"),
+        (4, "echo 2"),
+        (5, "/start@my_bot arg"),
+    ]
+    .into_iter()
+    .map(|(id, text)| dispatcher.dispatch(message_update(id, 10, text)))
+    .collect();
+    for handle in handles {
+        handle.await.unwrap();
+    }
+
+    assert_eq!(
+        *dispatcher.state().lock().unwrap(),
+        vec!["start 1", "echo 1", "echo 2", "start 5"]
+    );
+}
+
 #[tokio::test]
 async fn test_get_updates() {
     if let Some((mut bot, _target)) = get_tt() {
@@ -60,7 +219,7 @@ async fn test_forward_message() {
                 .forward_message(&bot::ForwardMessage::new(
                     ChatID::from(&target),
                     ChatID::from(&target),
-                    msg_id.parse::<i64>().unwrap(),
+                    msg_id.parse::<MessageId>().unwrap(),
                 ))
                 .await
                 .unwrap();
@@ -77,11 +236,11 @@ async fn test_copy_message() {
                 .copy_message(&bot::CopyMessage::new(
                     ChatID::from(&target),
                     ChatID::from(&target),
-                    msg_id.parse::<i64>().unwrap(),
+                    msg_id.parse::<MessageId>().unwrap(),
                 ))
                 .await
                 .unwrap();
-            assert!(message_id.message_id > 0);
+            assert!(message_id.0 > 0);
         }
     }
 }
@@ -139,7 +298,9 @@ async fn test_send_audio() {
 async fn test_send_dice() {
     if let Some((mut bot, target)) = get_tt() {
         let message = bot
-            .send_dice(&bot::SendDice::new(ChatID::from(&target)).emoji(Some("🎰".to_string())))
+            .send_dice(
+                bot::SendDice::new(ChatID::from(&target)).emoji(Some(DiceEmoji::SlotMachine)),
+            )
             .await
             .unwrap();
         assert!(message.dice.is_some());
@@ -156,6 +317,6 @@ async fn test_get_chat() {
             .await
             .unwrap();
         dbg!(&chat);
-        assert_eq!(chat.id, target.parse::<i64>().unwrap());
+        assert_eq!(chat.id, target.parse::<ChatId>().unwrap());
     }
 }